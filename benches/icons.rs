@@ -0,0 +1,93 @@
+//! Benchmarks of the generated code, run with `cargo bench --features nerd` and
+//! `cargo bench --features "nerd advanced_text"` for the `advanced_text` functions.
+//!
+//! Widgets are laid out with the headless `()` renderer, which measures no text, so the numbers
+//! are what the widgets and the generated functions cost, not the text shaping of a real renderer.
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use iced::advanced::layout::Limits;
+use iced::advanced::widget::Tree;
+use iced::widget::Row;
+use iced::{Element, Size, Theme};
+use iced_fonts::nerd::{self, Icon};
+
+/// The sizes of the grids, the Nerd Font has enough icons for the largest.
+const GRIDS: [usize; 2] = [1_000, 10_000];
+
+/// A grid of the first `count` icons, wrapping at the width of a window.
+fn grid(count: usize) -> Element<'static, (), Theme, ()> {
+    Row::with_children(Icon::ALL.iter().take(count).map(|icon| icon.text().into()))
+        .wrap()
+        .into()
+}
+
+fn widgets(c: &mut Criterion) {
+    let mut group = c.benchmark_group("widgets");
+    for count in GRIDS {
+        group.throughput(Throughput::Elements(count as u64));
+
+        group.bench_with_input(BenchmarkId::new("construct", count), &count, |b, &count| {
+            b.iter(|| grid(black_box(count)));
+        });
+
+        group.bench_with_input(BenchmarkId::new("layout", count), &count, |b, &count| {
+            let mut grid = grid(count);
+            let mut tree = Tree::new(&grid);
+            let limits = Limits::new(Size::ZERO, Size::new(1280.0, f32::INFINITY));
+
+            b.iter(|| grid.as_widget_mut().layout(&mut tree, &(), &limits));
+        });
+    }
+    group.finish();
+}
+
+fn functions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("functions");
+    group.throughput(Throughput::Elements(GRIDS[1] as u64));
+
+    group.bench_function("text", |b| {
+        b.iter(|| {
+            for _ in 0..GRIDS[1] {
+                let _: iced::widget::Text<'_, Theme, ()> = black_box(nerd::md_home());
+            }
+        });
+    });
+
+    #[cfg(feature = "advanced_text")]
+    group.bench_function("advanced_text", |b| {
+        b.iter(|| {
+            for _ in 0..GRIDS[1] {
+                black_box(nerd::advanced_text::md_home());
+            }
+        });
+    });
+    group.finish();
+}
+
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lookup");
+    let names: Vec<&str> = Icon::ALL.iter().map(|icon| icon.name()).collect();
+
+    group.throughput(Throughput::Elements(names.len() as u64));
+    group.bench_function("from_name", |b| {
+        b.iter(|| {
+            for name in &names {
+                black_box(Icon::from_name(black_box(name)));
+            }
+        });
+    });
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("from_name_missing", |b| {
+        b.iter(|| Icon::from_name(black_box("no_such_icon")));
+    });
+
+    for query in ["arrow", "md_file_document", "x"] {
+        group.bench_with_input(BenchmarkId::new("search", query), query, |b, query| {
+            b.iter(|| Icon::search(black_box(query)).count());
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, widgets, functions, lookup);
+criterion_main!(benches);
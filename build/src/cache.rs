@@ -0,0 +1,229 @@
+//! Reading every glyph name and metrics out of a font is the slowest part of expanding the macros,
+//! so the resulting table is kept around. Within one compilation it lives in memory, which covers
+//! fonts used by several invocations. If the invoking crate has a build script we also store it in its
+//! `OUT_DIR`, so the next build of that crate can skip the font entirely.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use ttf_parser::{Face, GlyphId, PlatformId, cmap::Subtable};
+
+/// A codepoint of the font with the name and metrics of the glyph it maps to.
+pub(crate) struct Glyph {
+    pub(crate) codepoint: char,
+    /// `None` when the font has no name for the glyph.
+    pub(crate) name: Option<String>,
+    pub(crate) metrics: Metrics,
+    /// Whether the glyph is drawn in color, with `COLR` layers or an image in `CBDT`, `sbix` or
+    /// `SVG`, like the glyphs of emoji fonts.
+    pub(crate) color: bool,
+}
+
+/// The metrics of a glyph in ems, with y pointing up from the baseline like in the font.
+#[derive(Clone, Copy)]
+pub(crate) struct Metrics {
+    pub(crate) advance: f32,
+    /// `x_min`, `y_min`, `x_max` and `y_max` of the outline, all zero for glyphs without one.
+    pub(crate) bounds: [f32; 4],
+    /// The ascender of the font, the distance from the top of the line to the baseline.
+    pub(crate) ascender: f32,
+}
+
+impl Metrics {
+    fn read(face: &Face<'_>, glyph_id: GlyphId) -> Self {
+        let em = f32::from(face.units_per_em());
+        let bounds = face.glyph_bounding_box(glyph_id).map_or([0.0; 4], |rect| {
+            [rect.x_min, rect.y_min, rect.x_max, rect.y_max].map(|v| f32::from(v) / em)
+        });
+
+        Self {
+            advance: f32::from(face.glyph_hor_advance(glyph_id).unwrap_or_default()) / em,
+            bounds,
+            ascender: f32::from(face.ascender()) / em,
+        }
+    }
+}
+
+/// Whether the glyph has colors of its own, which iced draws instead of the text color. Images
+/// of `EBDT` and `bdat` are left out, they are mostly monochrome bitmaps of small sizes.
+fn is_color(face: &Face<'_>, glyph_id: GlyphId) -> bool {
+    let tables = face.tables();
+
+    face.is_color_glyph(glyph_id)
+        || face.glyph_svg_image(glyph_id).is_some()
+        || ((tables.sbix.is_some() || tables.cbdt.is_some())
+            && face.glyph_raster_image(glyph_id, u16::MAX).is_some())
+}
+
+/// Bumped whenever what ends up in the table changes, so stale caches on disk are ignored.
+const CACHE_VERSION: u32 = 5;
+
+static GLYPHS: OnceLock<Mutex<HashMap<u64, Arc<[Glyph]>>>> = OnceLock::new();
+
+/// Returns the glyph table of `face`, the face at `face_index` of the file contents `font_data`.
+pub(crate) fn glyphs(
+    font_data: &[u8],
+    face_index: u32,
+    face: &Face<'_>,
+) -> Result<Arc<[Glyph]>, String> {
+    let key = hash(font_data) ^ u64::from(face_index);
+    let memory = GLYPHS.get_or_init(Default::default);
+
+    if let Some(glyphs) = memory.lock().unwrap().get(&key) {
+        return Ok(glyphs.clone());
+    }
+
+    let glyphs: Arc<[Glyph]> = match read_from_disk(key) {
+        Some(glyphs) => glyphs.into(),
+        None => {
+            let glyphs = read_from_face(face)?;
+            write_to_disk(key, &glyphs);
+            glyphs.into()
+        }
+    };
+
+    memory.lock().unwrap().insert(key, glyphs.clone());
+    Ok(glyphs)
+}
+
+fn read_from_face(face: &Face<'_>) -> Result<Vec<Glyph>, String> {
+    let cmap = face
+        .tables()
+        .cmap
+        .ok_or("the font has no `cmap` table, so its icons can't be looked up")?;
+
+    // Fonts can split their Unicode mapping over several subtables, e.g. one for the BMP and one
+    // for the supplementary planes. Older symbol fonts only ship a Windows symbol (3, 0) subtable.
+    let mut subtables: Vec<Subtable<'_>> = cmap
+        .subtables
+        .into_iter()
+        .filter(|s| s.is_unicode())
+        .collect();
+    if subtables.is_empty() {
+        subtables.extend(
+            cmap.subtables
+                .into_iter()
+                .filter(|s| s.platform_id == PlatformId::Windows && s.encoding_id == 0),
+        );
+    }
+
+    if subtables.is_empty() {
+        let available: Vec<String> = cmap
+            .subtables
+            .into_iter()
+            .map(|s| format!("({:?}, {})", s.platform_id, s.encoding_id))
+            .collect();
+        return Err(format!(
+            "the font has no Unicode or Windows symbol `cmap` subtable, it only has {}",
+            available.join(", ")
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    let mut glyphs = Vec::new();
+    for subtable in &subtables {
+        subtable.codepoints(|c| {
+            let Some(codepoint) = char::from_u32(c) else {
+                return;
+            };
+            if !seen.insert(codepoint) {
+                return;
+            }
+
+            if let Some(glyph_id) = subtable.glyph_index(c) {
+                glyphs.push(Glyph {
+                    codepoint,
+                    name: face.glyph_name(glyph_id).map(String::from),
+                    metrics: Metrics::read(face, glyph_id),
+                    color: is_color(face, glyph_id),
+                });
+            }
+        });
+    }
+
+    // Subtables list their codepoints in whatever order they are stored in, sorting keeps the
+    // generated code and the numbering of duplicates the same for every build.
+    glyphs.sort_unstable_by_key(|glyph| glyph.codepoint);
+
+    Ok(glyphs)
+}
+
+/// 64 bit FNV-1a. The hash ends up in file names, so unlike `DefaultHasher` it has to stay the
+/// same across compiler versions.
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn disk_path(key: u64) -> Option<PathBuf> {
+    let out_dir = std::env::var_os("OUT_DIR")?;
+    Some(PathBuf::from(out_dir).join(format!(
+        "iced_fonts-{}-{CACHE_VERSION}-{key:016x}.glyphs",
+        env!("CARGO_PKG_VERSION")
+    )))
+}
+
+/// Every line is the codepoint in hex, the glyph name which is empty when unnamed, the six
+/// metrics separated by spaces, and `color` for color glyphs or nothing, all separated by tabs.
+fn read_from_disk(key: u64) -> Option<Vec<Glyph>> {
+    let contents = std::fs::read_to_string(disk_path(key)?).ok()?;
+
+    contents
+        .lines()
+        .map(|line| {
+            let mut fields = line.split('\t');
+            let (codepoint, name, metrics, color) = (
+                fields.next()?,
+                fields.next()?,
+                fields.next()?,
+                fields.next()?,
+            );
+            let metrics = metrics
+                .split(' ')
+                .map(|value| value.parse().ok())
+                .collect::<Option<Vec<f32>>>()?;
+            let [advance, x_min, y_min, x_max, y_max, ascender] = metrics[..] else {
+                return None;
+            };
+
+            Some(Glyph {
+                codepoint: char::from_u32(u32::from_str_radix(codepoint, 16).ok()?)?,
+                name: (!name.is_empty()).then(|| name.to_string()),
+                metrics: Metrics {
+                    advance,
+                    bounds: [x_min, y_min, x_max, y_max],
+                    ascender,
+                },
+                color: color == "color",
+            })
+        })
+        .collect()
+}
+
+fn write_to_disk(key: u64, glyphs: &[Glyph]) {
+    let Some(path) = disk_path(key) else {
+        return;
+    };
+
+    let contents: String = glyphs
+        .iter()
+        .map(|glyph| {
+            let Metrics {
+                advance,
+                bounds: [x_min, y_min, x_max, y_max],
+                ascender,
+            } = glyph.metrics;
+            format!(
+                "{:x}\t{}\t{advance} {x_min} {y_min} {x_max} {y_max} {ascender}\t{}\n",
+                u32::from(glyph.codepoint),
+                glyph.name.as_deref().unwrap_or_default(),
+                if glyph.color { "color" } else { "" }
+            )
+        })
+        .collect();
+
+    // The cache is only an optimization, failing to write it is fine.
+    let _ = std::fs::write(path, contents);
+}
@@ -0,0 +1,99 @@
+//! The `changes` option, how the icons changed with the last update of the font, for apps checking
+//! that upgrading is safe.
+//!
+//! A changes file has a line per change, its kind and fields separated by tabs:
+//! `added`, `removed` with the codepoint in hex and the name, `renamed` with the codepoint, the old
+//! and the new name, and `moved` with the name, the old and the new codepoint. `cargo xtask
+//! changes` writes them from the snapshots, lines starting with `#` are comments.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+use crate::{include_bytes, resolve_path};
+
+/// Returns the `Change` enum and the `CHANGES` constant of the file at `path`, empty without it.
+pub(crate) fn tokens(path: Option<&LitStr>) -> syn::Result<TokenStream> {
+    let (changes, track) = match path {
+        Some(path) => {
+            let full_path = resolve_path(&path.value());
+            let contents = std::fs::read_to_string(&full_path).map_err(|e| {
+                syn::Error::new(
+                    path.span(),
+                    format!("failed to read `{}`: {e}", path.value()),
+                )
+            })?;
+            let changes = contents
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+                .map(|(number, line)| {
+                    change(line).ok_or_else(|| {
+                        syn::Error::new(
+                            path.span(),
+                            format!(
+                                "line {} of `{}` isn't a change: `{line}`",
+                                number + 1,
+                                path.value()
+                            ),
+                        )
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            // Makes cargo rebuild the invoking crate when the file changes.
+            let include = include_bytes(&path.value());
+            (changes, quote! { const _: &[u8] = #include; })
+        }
+        None => (Vec::new(), quote! {}),
+    };
+
+    Ok(quote! {
+        #track
+
+        /// How an icon changed with the last update of the font. Codepoints are the characters
+        /// drawing the icon.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[allow(missing_docs)]
+        pub enum Change {
+            /// A new icon.
+            Added { name: &'static str, codepoint: char },
+            /// An icon that is gone, code using it no longer compiles.
+            Removed { name: &'static str, codepoint: char },
+            /// An icon whose function has a new name.
+            Renamed { from: &'static str, to: &'static str, codepoint: char },
+            /// An icon with a new codepoint, text and stored ids with the old character show
+            /// something else now.
+            Moved { name: &'static str, from: char, to: char },
+        }
+
+        /// The icons that changed with the last update of the font, from the `changes` option.
+        /// Upgrading is safe when none was removed, renamed or moved.
+        pub const CHANGES: &[Change] = &[#(#changes),*];
+    })
+}
+
+/// A line of a changes file as an expression of the `Change` enum.
+fn change(line: &str) -> Option<TokenStream> {
+    let codepoint = |hex: &str| char::from_u32(u32::from_str_radix(hex, 16).ok()?);
+
+    Some(match line.split('\t').collect::<Vec<_>>()[..] {
+        ["added", hex, name] => {
+            let codepoint = codepoint(hex)?;
+            quote! { Change::Added { name: #name, codepoint: #codepoint } }
+        }
+        ["removed", hex, name] => {
+            let codepoint = codepoint(hex)?;
+            quote! { Change::Removed { name: #name, codepoint: #codepoint } }
+        }
+        ["renamed", hex, from, to] => {
+            let codepoint = codepoint(hex)?;
+            quote! { Change::Renamed { from: #from, to: #to, codepoint: #codepoint } }
+        }
+        ["moved", name, from, to] => {
+            let (from, to) = (codepoint(from)?, codepoint(to)?);
+            quote! { Change::Moved { name: #name, from: #from, to: #to } }
+        }
+        _ => return None,
+    })
+}
@@ -0,0 +1,87 @@
+//! The `deprecated` option, functions renamed by a font update that keep their old name for a
+//! while, pointing to the new one.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::{Context, Output};
+
+/// Returns the deprecated functions and their `advanced_text` functions, none with `data_only`.
+pub(crate) fn tokens(context: &Context<'_>) -> syn::Result<(TokenStream, TokenStream)> {
+    let Context {
+        shaping_parameter,
+        shaping_argument,
+        ..
+    } = context;
+
+    let mut functions = TokenStream::new();
+    let mut advanced_functions = TokenStream::new();
+    for deprecated in &context.options.deprecated {
+        let is_icon = |name: &str| context.icons.iter().any(|icon| icon.name == name);
+        if !is_icon(&deprecated.to) {
+            return Err(context.no_icon(deprecated.span, &deprecated.to));
+        }
+        if is_icon(&deprecated.from) {
+            return Err(syn::Error::new(
+                deprecated.span,
+                format!("`{}` is still the name of an icon", deprecated.from),
+            ));
+        }
+
+        let old = syn::parse_str::<Ident>(&deprecated.from).map_err(|_| {
+            syn::Error::new(
+                deprecated.span,
+                format!("`{}` is not a valid function name", deprecated.from),
+            )
+        })?;
+        let new = Ident::new_raw(&deprecated.to, Span::call_site());
+        let note = format!("renamed to `{}`", deprecated.to);
+        if context.data_only {
+            continue;
+        }
+
+        functions.extend(match context.options.output {
+            Output::Widget => quote! {
+                #[deprecated(note = #note)]
+                #[allow(non_snake_case)]
+                #[doc(hidden)]
+                #[inline]
+                #[must_use]
+                pub fn #old<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(#shaping_parameter) -> Text<'a, Theme, Renderer> {
+                    #new(#shaping_argument)
+                }
+            },
+            Output::Char => quote! {
+                #[deprecated(note = #note)]
+                #[allow(non_snake_case)]
+                #[doc(hidden)]
+                #[inline]
+                #[must_use]
+                pub const fn #old() -> char {
+                    #new()
+                }
+            },
+            Output::Str => quote! {
+                #[deprecated(note = #note)]
+                #[allow(non_snake_case)]
+                #[doc(hidden)]
+                #[inline]
+                #[must_use]
+                pub const fn #old() -> &'static str {
+                    #new()
+                }
+            },
+        });
+        advanced_functions.extend(quote! {
+            #[deprecated(note = #note)]
+            #[allow(non_snake_case)]
+            #[doc(hidden)]
+            #[must_use]
+            pub const fn #old(#shaping_parameter) -> (&'static str, Font, Shaping) {
+                #new(#shaping_argument)
+            }
+        });
+    }
+
+    Ok((functions, advanced_functions))
+}
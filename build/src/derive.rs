@@ -0,0 +1,149 @@
+//! `#[derive(HasIcon)]`, binding the variants of an enum to icon functions, and
+//! `#[derive(IconDefault)]`, giving `Icon` fields a default by name.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr, Path, Token, spanned::Spanned};
+
+use crate::{names, rename};
+
+pub(crate) fn has_icon(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let function = icon_attribute(&variant.attrs, variant.span())?;
+                    let ident = &variant.ident;
+                    let pattern = match &variant.fields {
+                        Fields::Named(_) => quote! { Self::#ident { .. } },
+                        Fields::Unnamed(_) => quote! { Self::#ident(..) },
+                        Fields::Unit => quote! { Self::#ident },
+                    };
+                    Ok(quote! { #pattern => #function() })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            // An empty enum has no values to match on.
+            if arms.is_empty() {
+                quote! { match *self {} }
+            } else {
+                quote! { match self { #(#arms,)* } }
+            }
+        }
+        Data::Struct(_) => {
+            let function = icon_attribute(&input.attrs, name.span())?;
+            quote! { #function() }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                name.span(),
+                "`HasIcon` can only be derived for enums and structs",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics iced_fonts::HasIcon for #name #type_generics #where_clause {
+            fn icon<
+                'a,
+                Theme: iced_widget::text::Catalog + 'a,
+                Renderer: iced_widget::core::text::Renderer<Font = iced_widget::core::Font>,
+            >(
+                &self,
+            ) -> iced_widget::text::Text<'a, Theme, Renderer> {
+                #body
+            }
+        }
+    })
+}
+
+/// The icon function of `#[icon(bootstrap::house)]`.
+fn icon_attribute(attributes: &[Attribute], span: proc_macro2::Span) -> syn::Result<Path> {
+    let mut icons = attributes.iter().filter(|a| a.path().is_ident("icon"));
+
+    let attribute = icons
+        .next()
+        .ok_or_else(|| syn::Error::new(span, "missing `#[icon(module::icon_name)]` attribute"))?;
+    if let Some(duplicate) = icons.next() {
+        return Err(syn::Error::new(
+            duplicate.span(),
+            "only one `#[icon(...)]` attribute is allowed",
+        ));
+    }
+
+    attribute.parse_args()
+}
+
+pub(crate) fn icon_default(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            name.span(),
+            "`IconDefault` can only be derived for structs",
+        ));
+    };
+
+    let values = data
+        .fields
+        .iter()
+        .map(|field| {
+            let default = field
+                .attrs
+                .iter()
+                .filter(|a| a.path().is_ident("icon"))
+                .map(|attribute| {
+                    attribute.parse_args_with(|input: syn::parse::ParseStream<'_>| {
+                        let key: Ident = input.parse()?;
+                        if key != "default" {
+                            return Err(syn::Error::new(
+                                key.span(),
+                                "expected `default = \"icon_name\"`",
+                            ));
+                        }
+                        let _: Token![=] = input.parse()?;
+                        input.parse::<LitStr>()
+                    })
+                })
+                .next()
+                .transpose()?;
+
+            let value = match default {
+                // The variant carries the span of the name, so a typo is reported right there
+                // along with the compiler's suggestions.
+                Some(icon) => {
+                    rename::validate(&icon.value(), icon.span())?;
+                    let variant = Ident::new(&names::variant(&icon.value()), icon.span());
+                    let ty = &field.ty;
+                    quote! { <#ty>::#variant }
+                }
+                None => quote! { ::core::default::Default::default() },
+            };
+
+            Ok(match &field.ident {
+                Some(ident) => quote! { #ident: #value },
+                None => value,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let body = match &data.fields {
+        Fields::Named(_) => quote! { Self { #(#values),* } },
+        Fields::Unnamed(_) => quote! { Self(#(#values),*) },
+        Fields::Unit => quote! { Self },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::default::Default for #name #type_generics #where_clause {
+            fn default() -> Self {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,254 @@
+//! The function of every icon, the bulk of a generated module.
+//!
+//! Tokens can only be made on the thread the compiler runs the macro on, so the functions are
+//! written as source text, a chunk of icons per thread, and the text is parsed once at the end.
+//! Parsing it in one go is also a lot cheaper than building the functions token by token, which
+//! for the Nerd Font took most of the expansion.
+//!
+//! Fonts with more than [`MODULE_SIZE`] icons get their functions in private inner modules of that
+//! many, re-exported with a glob so the paths stay the same. rustc's incremental compilation and
+//! rust-analyzer handle those a lot better than one module with thousands of functions.
+
+use std::fmt::Write;
+use std::thread;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::icons::Icon;
+use crate::{Context, Output, link};
+
+/// The most functions in one inner module.
+const MODULE_SIZE: usize = 500;
+
+/// The fewest inner modules worth a thread of their own.
+const MIN_MODULES_PER_THREAD: usize = 2;
+
+/// What the functions of every icon of a module have in common.
+pub(crate) struct Functions<'a> {
+    pub(crate) output: Output,
+    /// Whether the functions are documented, they aren't with `slim_docs` and for stubs.
+    pub(crate) docs: bool,
+    /// Whether the functions are stubs for rust-analyzer, without attributes and bodies.
+    pub(crate) stubs: bool,
+    pub(crate) link: Option<&'a link::Template>,
+    /// Whether the widgets record that they were used, for `usage_report`.
+    pub(crate) track_usage: bool,
+    pub(crate) advanced_text: bool,
+    /// The shaping parameter of the functions, empty when the shaping is fixed.
+    pub(crate) shaping_parameter: String,
+}
+
+impl Functions<'_> {
+    /// The functions of `icons` and their `advanced_text` functions.
+    pub(crate) fn tokens(&self, icons: &[Icon<'_>]) -> (TokenStream, TokenStream) {
+        let modules: Vec<(usize, &[Icon<'_>])> = icons.chunks(MODULE_SIZE).enumerate().collect();
+        let split = modules.len() > 1;
+        let threads = thread::available_parallelism().map_or(1, usize::from);
+        let per_thread = modules.len().div_ceil(threads).max(MIN_MODULES_PER_THREAD);
+
+        let chunks: Vec<(String, String)> = if modules.len() <= per_thread {
+            vec![self.write(&modules, split)]
+        } else {
+            thread::scope(|scope| {
+                let writers: Vec<_> = modules
+                    .chunks(per_thread)
+                    .map(|modules| scope.spawn(move || self.write(modules, split)))
+                    .collect();
+                writers
+                    .into_iter()
+                    .map(|writer| writer.join().expect("writing functions doesn't panic"))
+                    .collect()
+            })
+        };
+
+        let (functions, advanced_functions): (String, String) = chunks.into_iter().unzip();
+        let parse = |source: String| -> TokenStream {
+            source
+                .parse()
+                .expect("the generated functions are valid Rust")
+        };
+        (parse(functions), parse(advanced_functions))
+    }
+
+    /// Writes the functions of the icons of `modules` and their `advanced_text` functions, each
+    /// module in an inner module of its own when `split`.
+    fn write(&self, modules: &[(usize, &[Icon<'_>])], split: bool) -> (String, String) {
+        let mut functions = String::new();
+        let mut advanced_functions = String::new();
+        // Only the widgets need anything of the module, the characters and strings are literals.
+        let uses = match self.output {
+            Output::Widget => "use super::*;",
+            Output::Char | Output::Str => "",
+        };
+
+        for (index, icons) in modules {
+            if split {
+                let _ = write!(functions, "mod part_{index} {{ {uses}");
+                if self.advanced_text {
+                    let _ = write!(advanced_functions, "mod part_{index} {{ use super::*;");
+                }
+            }
+            self.write_module(
+                &mut functions,
+                &mut advanced_functions,
+                index * MODULE_SIZE,
+                icons,
+            );
+            if split {
+                let _ = write!(functions, "}} pub use part_{index}::*;");
+                if self.advanced_text {
+                    let _ = write!(advanced_functions, "}} pub use part_{index}::*;");
+                }
+            }
+        }
+
+        (functions, advanced_functions)
+    }
+
+    /// Writes the functions of `icons` and their `advanced_text` functions, `first` is the index
+    /// of the first icon in `Icon::ALL`.
+    fn write_module(
+        &self,
+        functions: &mut String,
+        advanced_functions: &mut String,
+        first: usize,
+        icons: &[Icon<'_>],
+    ) {
+        let shaping_parameter = &self.shaping_parameter;
+        let (shaping_argument, advanced_shaping) = if shaping_parameter.is_empty() {
+            ("", "SHAPING")
+        } else {
+            (", shaping", "shaping")
+        };
+        // Stubs only need what rust-analyzer looks at.
+        let (attributes, must_use) = if self.stubs {
+            ("", "")
+        } else {
+            ("#[inline] #[must_use]", "#[must_use]")
+        };
+
+        for (index, icon) in (first..).zip(icons) {
+            let Icon { name, source, .. } = icon;
+            let codepoint = u32::from(icon.codepoint);
+
+            if self.docs {
+                self.write_docs(functions, icon);
+            }
+            let signature = match self.output {
+                Output::Widget => format!(
+                    "fn r#{name}<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>\
+                     ({shaping_parameter}) -> Text<'a, Theme, Renderer>"
+                ),
+                Output::Char => format!("const fn r#{name}() -> char"),
+                Output::Str => format!("const fn r#{name}() -> &'static str"),
+            };
+            let body = match self.output {
+                _ if self.stubs => String::from("loop {}"),
+                Output::Widget => {
+                    let track = if self.track_usage {
+                        format!("track({index});")
+                    } else {
+                        String::new()
+                    };
+                    format!("{track} make_icon_{source}('\\u{{{codepoint:x}}}'{shaping_argument})")
+                }
+                Output::Char => format!("'\\u{{{codepoint:x}}}'"),
+                Output::Str => format!("\"\\u{{{codepoint:x}}}\""),
+            };
+            let _ = write!(functions, "{attributes} pub {signature} {{ {body} }}");
+
+            if self.advanced_text {
+                if self.docs {
+                    let doc = format!(
+                        " Returns the string of the {name} icon, its font and shaping for lower \
+                         level API's"
+                    );
+                    let _ = write!(advanced_functions, "#[doc = {doc:?}]");
+                }
+                let body = if self.stubs {
+                    String::from("loop {}")
+                } else {
+                    format!("(\"\\u{{{codepoint:x}}}\", FONTS[{source}], {advanced_shaping})")
+                };
+                let _ = write!(
+                    advanced_functions,
+                    "{must_use} pub const fn r#{name}({shaping_parameter}) \
+                     -> (&'static str, Font, Shaping) {{ {body} }}"
+                );
+            }
+        }
+    }
+
+    /// Writes the doc comment of the icon's function, with its link, preview and aliases.
+    fn write_docs(&self, functions: &mut String, icon: &Icon<'_>) {
+        let Icon {
+            name, codepoint, ..
+        } = icon;
+        let returned = match self.output {
+            Output::Widget => "an [`iced_widget::Text`] widget",
+            Output::Char => "the character",
+            Output::Str => "the string",
+        };
+        let link = self
+            .link
+            .and_then(|template| template.render(name, icon.raw_name, *codepoint));
+        let doc = match link {
+            Some(link) => format!(" Returns {returned} of the [{codepoint} {name}]({link}) icon."),
+            None => format!(" Returns {returned} of the {codepoint} {name} icon."),
+        };
+
+        let _ = write!(functions, "#[doc = {doc:?}]");
+        if let Some(uri) = icon.preview {
+            let image = format!(" ![{name}]({uri})");
+            let _ = write!(functions, "#[doc = \"\"] #[doc = {image:?}]");
+        }
+        for alias in &icon.aliases {
+            let _ = write!(functions, "#[doc(alias = {alias:?})]");
+        }
+    }
+}
+
+/// Returns the `advanced_text` module with the `advanced_text` functions of the icons, nothing
+/// when the module doesn't get one.
+pub(crate) fn advanced_text(context: &Context<'_>, functions: TokenStream) -> TokenStream {
+    if !context.advanced_text || context.data_only {
+        return quote! {};
+    }
+
+    // The functions index the font of their source in `FONTS` instead of naming it.
+    let source_count = proc_macro2::Literal::usize_unsuffixed(context.sources.len());
+    let fonts = context.sources.iter().map(|source| &source.advanced_font);
+    let shaping = &context.shaping;
+    let shaping_const = if context.shaping_argument.is_empty() {
+        quote! { const SHAPING: Shaping = #shaping; }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+      /// Every icon with helpers to use these icons in widgets.
+      ///
+      /// Usage
+      /// ```
+      /// let (content, font, shaping) = advanced_text::my_icon();
+      ///
+      /// advanced::Text {
+      ///     content,
+      ///     font,
+      ///     shaping,
+      ///     ...
+      /// }
+      /// ```
+      pub mod advanced_text {
+          use iced_widget::core::Font;
+          use iced_widget::text::{self, Shaping};
+
+          /// The fonts of the main font and the merged ones, in the order of their icons.
+          const FONTS: [Font; #source_count] = [#(#fonts),*];
+          #shaping_const
+
+          #functions
+      }
+    }
+}
@@ -0,0 +1,170 @@
+//! Generating modules from build scripts.
+
+use std::path::PathBuf;
+
+use proc_macro2::{TokenStream, TokenTree};
+
+use crate::{Input, Module, Shaping, generate, resolve_path};
+
+/// Generates the module of one font, with the arguments and options of
+/// `generate_icon_functions!`.
+pub struct Generator {
+    path: String,
+    module: String,
+    font: String,
+    link: Option<String>,
+    options: Vec<String>,
+}
+
+impl Generator {
+    /// The font at `path`, relative to the `Cargo.toml` of the crate, becomes the module `module`
+    /// drawing its icons with the `Font` constant `font` at the crate root.
+    pub fn new(
+        path: impl Into<String>,
+        module: impl Into<String>,
+        font: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            module: module.into(),
+            font: font.into(),
+            link: None,
+            options: Vec::new(),
+        }
+    }
+
+    /// Links the documentation of every icon, `{name}`, `{raw}` and `{codepoint:x}` are replaced
+    /// like in the macro's link argument.
+    #[must_use]
+    pub fn link(mut self, template: impl Into<String>) -> Self {
+        self.link = Some(template.into());
+        self
+    }
+
+    /// Adds a macro option as it is written in the macro, e.g. `"strip_prefix = \"icon-\""`.
+    #[must_use]
+    pub fn option(mut self, option: impl Into<String>) -> Self {
+        self.options.push(option.into());
+        self
+    }
+
+    /// The source of the module, a `mod` item.
+    pub fn generate(&self) -> Result<String, Error> {
+        self.module().map(|module| module.item().to_string())
+    }
+
+    /// The source of the module without the `mod` around it, for a file of its own that the
+    /// crate declares with `mod name;`.
+    pub fn generate_file(&self) -> Result<String, Error> {
+        self.module().map(|module| module.file().to_string())
+    }
+
+    /// The manifest of the module's icons as JSON, with the name, function name, codepoint,
+    /// categories and aliases of every icon.
+    pub fn generate_manifest(&self) -> Result<String, Error> {
+        self.module().map(|module| module.manifest())
+    }
+
+    /// A message for every glyph the module leaves out because no function name can be made of
+    /// its name, the glyphs of its `SKIPPED` constant.
+    pub fn warnings(&self) -> Result<Vec<String>, Error> {
+        self.module().map(|module| module.warnings())
+    }
+
+    fn module(&self) -> Result<Module, Error> {
+        let mut arguments = format!("{:?}, {}, {}", self.path, self.module, self.font);
+        if let Some(link) = &self.link {
+            arguments.push_str(&format!(", {link:?}"));
+        }
+        for option in &self.options {
+            arguments.push_str(", ");
+            arguments.push_str(option);
+        }
+
+        syn::parse_str::<Input>(&arguments)
+            .and_then(|input| generate(input, Shaping::Basic))
+            .map_err(|e| Error(format!("failed to generate `{}`: {e}", self.module)))
+    }
+
+    /// Writes the module to `file_name` in `OUT_DIR`, to be included with
+    /// `include!(concat!(env!("OUT_DIR"), "/file_name.rs"))`. Cargo reruns the build script when
+    /// the font or a file named in the options changes, and shows the [`warnings`](Self::warnings).
+    pub fn write(&self, file_name: &str) -> Result<PathBuf, Error> {
+        let path = out_dir()?.join(file_name);
+        let module = self.module()?;
+
+        for warning in module.warnings() {
+            println!("cargo:warning={warning}");
+        }
+        std::fs::write(&path, module.item().to_string())
+            .map_err(|e| Error(format!("failed to write `{}`: {e}", path.display())))?;
+        self.rerun_if_changed();
+
+        Ok(path)
+    }
+
+    /// Writes the manifest of the module's icons to `file_name` in `OUT_DIR`, see
+    /// [`generate_manifest`](Self::generate_manifest).
+    pub fn write_manifest(&self, file_name: &str) -> Result<PathBuf, Error> {
+        let path = out_dir()?.join(file_name);
+
+        std::fs::write(&path, self.generate_manifest()?)
+            .map_err(|e| Error(format!("failed to write `{}`: {e}", path.display())))?;
+        self.rerun_if_changed();
+
+        Ok(path)
+    }
+
+    /// Tells Cargo to rerun the build script when the font or a file named in the options
+    /// changes.
+    fn rerun_if_changed(&self) {
+        for file in std::iter::once(self.path.clone()).chain(self.files_in_options()) {
+            println!("cargo:rerun-if-changed={}", resolve_path(&file).display());
+        }
+    }
+
+    /// The string literals of the options that name a file, like `names = "tags.json"`.
+    fn files_in_options(&self) -> Vec<String> {
+        fn literals(tokens: TokenStream, files: &mut Vec<String>) {
+            for token in tokens {
+                match token {
+                    TokenTree::Group(group) => literals(group.stream(), files),
+                    TokenTree::Literal(literal) => {
+                        if let Ok(string) = syn::parse_str::<syn::LitStr>(&literal.to_string())
+                            && resolve_path(&string.value()).is_file()
+                        {
+                            files.push(string.value());
+                        }
+                    }
+                    TokenTree::Ident(_) | TokenTree::Punct(_) => {}
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        for option in &self.options {
+            if let Ok(tokens) = option.parse() {
+                literals(tokens, &mut files);
+            }
+        }
+        files
+    }
+}
+
+fn out_dir() -> Result<PathBuf, Error> {
+    std::env::var_os("OUT_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| Error("`OUT_DIR` isn't set, writing only works in build scripts".into()))
+}
+
+/// Why a module couldn't be generated, the same message the macro would fail to compile with.
+#[derive(Debug)]
+pub struct Error(String);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,43 @@
+//! `icon!("bootstrap/house")`, an icon by name that is checked at compile time.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::LitStr;
+
+use crate::names::{self, Digits};
+
+/// Turns `"module/icon_name"` into a call of `module::icon_name()`. The identifiers carry the span
+/// of the string, so an unknown module or icon is reported on it, with the compiler suggesting
+/// similar names.
+pub(crate) fn icon(name: &LitStr) -> syn::Result<TokenStream> {
+    let value = name.value();
+    let Some((module, icon)) = value.rsplit_once('/') else {
+        return Err(syn::Error::new(
+            name.span(),
+            "expected `\"module/icon_name\"`, like `\"bootstrap/house\"`",
+        ));
+    };
+
+    let modules = module
+        .split('/')
+        .map(|module| {
+            syn::parse_str::<Ident>(module)
+                .map(|module| Ident::new(&module.to_string(), name.span()))
+                .map_err(|_| {
+                    syn::Error::new(name.span(), format!("`{module}` is not a module name"))
+                })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // Glyph names like `house-door` work too, they get the name of their function.
+    let function = if crate::rename::validate(icon, name.span()).is_ok() {
+        icon.to_string()
+    } else {
+        names::sanitize(icon, Digits::Spell).ok_or_else(|| {
+            syn::Error::new(name.span(), format!("`{icon}` can't be an icon name"))
+        })?
+    };
+    let function = Ident::new_raw(&function, name.span());
+
+    Ok(quote! { #(#modules::)*#function() })
+}
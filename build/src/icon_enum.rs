@@ -0,0 +1,551 @@
+//! The `Icon` enum with a variant for every icon, and the tables of the icons its methods look up,
+//! indexed by `Icon::index`.
+
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::{Context, char_literal, names, phf, shortcodes, widgets};
+
+/// Returns the `Icon` enum, its methods and `Metrics`.
+pub(crate) fn tokens(context: &Context<'_>) -> syn::Result<TokenStream> {
+    let icons = context.icons;
+    let mut variants: HashMap<String, &str> = HashMap::new();
+    let mut idents = Vec::with_capacity(icons.len());
+    for icon in icons {
+        let name = &icon.name;
+        let variant = names::variant(name);
+        if let Some(other) = variants.insert(variant.clone(), name) {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!("`{other}` and `{name}` both become `Icon::{variant}`, rename one of them"),
+            ));
+        }
+        idents.push(Ident::new(&variant, Span::call_site()));
+    }
+
+    let name_lookup = if context.stubs {
+        quote! {
+            let _ = name;
+            None
+        }
+    } else {
+        phf::Map::new(
+            &icons
+                .iter()
+                .map(|icon| icon.name.as_str())
+                .collect::<Vec<_>>(),
+        )
+        .lookup()
+    };
+    // The value of an icon is its codepoint. Merged fonts can have an icon at a codepoint an
+    // earlier font has one at too, those get values past `char::MAX` in their order.
+    let mut taken = HashSet::new();
+    let mut shared = u32::from(char::MAX);
+    let values: Vec<u32> = icons
+        .iter()
+        .map(|icon| {
+            if taken.insert(icon.codepoint) {
+                u32::from(icon.codepoint)
+            } else {
+                shared += 1;
+                shared
+            }
+        })
+        .collect();
+    let discriminants = values
+        .iter()
+        .map(|&value| proc_macro2::Literal::u32_unsuffixed(value));
+    // The icons by value for `index` and `from_repr`.
+    let mut by_value: Vec<u32> = (0..icons.len() as u32).collect();
+    by_value.sort_by_key(|&i| values[i as usize]);
+    // The icons by character for `from_char`, the first one of a character first.
+    let mut by_char: Vec<u32> = (0..icons.len() as u32).collect();
+    by_char.sort_by_key(|&i| icons[i as usize].codepoint);
+    let chars = icons.iter().map(|icon| char_literal(icon.codepoint));
+    // The icons by id for `from_id`. Only merged fonts whose ids collide can share one.
+    let mut by_id: Vec<u32> = (0..icons.len() as u32).collect();
+    by_id.sort_by_key(|&i| icons[i as usize].id);
+    if let Some(pair) = by_id
+        .windows(2)
+        .find(|pair| icons[pair[0] as usize].id == icons[pair[1] as usize].id)
+    {
+        let [first, second] = [pair[0], pair[1]].map(|i| &icons[i as usize].name);
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "`{first}` and `{second}` have the same id, their fonts' families hash alike, \
+                 leave one of the fonts unmerged"
+            ),
+        ));
+    }
+    let ids = icons.iter().map(|icon| icon.id);
+    let shortcodes = shortcodes::table(icons)
+        .into_iter()
+        .map(|(shortcode, index)| {
+            let ident = &idents[index];
+            quote! { (#shortcode, Icon::#ident) }
+        });
+    let names = icons.iter().map(|icon| &icon.name);
+    // The name and aliases of every icon the way `Icon::search` compares them, apart by spaces
+    // that normalized queries never contain.
+    let search_index = icons.iter().map(|icon| {
+        std::iter::once(icon.name.as_str())
+            .chain(icon.aliases.iter().copied())
+            .map(names::normalize_search)
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+    let aliases = icons.iter().map(|icon| {
+        let aliases = &icon.aliases;
+        quote! { &[#(#aliases),*] }
+    });
+    // Only the icons with a fallback get an arm, the others fall back to their name.
+    let fallback_arms = idents
+        .iter()
+        .zip(icons)
+        .filter_map(|(ident, icon)| {
+            let fallback = icon.fallback?;
+            Some(quote! { Icon::#ident => #fallback, })
+        })
+        .collect::<Vec<_>>();
+    let fallback_text = match fallback_arms.len() {
+        0 => quote! { self.name() },
+        arms if arms == idents.len() => quote! {
+            match self {
+                #(#fallback_arms)*
+            }
+        },
+        _ => quote! {
+            match self {
+                #(#fallback_arms)*
+                _ => self.name(),
+            }
+        },
+    };
+    // Icons without a translation have an empty name.
+    let display_names = display_names(context)?
+        .into_iter()
+        .map(|(locale, names)| quote! { (#locale, [#(#names),*]) });
+    // Rounded, more digits only make the tables bigger.
+    let metrics = icons.iter().map(|icon| {
+        let metrics = icon.metrics;
+        let [x_min, y_min, x_max, y_max] = metrics.bounds;
+        let values = [
+            metrics.advance,
+            x_min,
+            -y_max,
+            x_max - x_min,
+            y_max - y_min,
+            metrics.ascender,
+        ]
+        .map(|value| proc_macro2::Literal::f32_unsuffixed((value * 10_000.0).round() / 10_000.0));
+        quote! { [#(#values),*] }
+    });
+
+    // Stubs get tables of the right types without their contents.
+    let tables = if context.stubs {
+        quote! {
+            const CHARS: [char; COUNT] = ['\0'; COUNT];
+            const BY_CHAR: [u32; COUNT] = [0; COUNT];
+            const BY_VALUE: [u32; COUNT] = [0; COUNT];
+            const IDS: [u32; COUNT] = [0; COUNT];
+            const BY_ID: [u32; COUNT] = [0; COUNT];
+            const SHORTCODES: &'static [(&'static str, Icon)] = &[];
+            const NAMES: [&'static str; COUNT] = [""; COUNT];
+            const ALIASES: [&'static [&'static str]; COUNT] = [&[]; COUNT];
+            const SEARCH_INDEX: [&'static str; COUNT] = [""; COUNT];
+            const DISPLAY_NAMES: &'static [(&'static str, [&'static str; COUNT])] = &[];
+            const METRICS: [[f32; 6]; COUNT] = [[0.0; 6]; COUNT];
+        }
+    } else {
+        quote! {
+            const CHARS: [char; COUNT] = [#(#chars),*];
+            const BY_CHAR: [u32; COUNT] = [#(#by_char),*];
+            const BY_VALUE: [u32; COUNT] = [#(#by_value),*];
+            const IDS: [u32; COUNT] = [#(#ids),*];
+            const BY_ID: [u32; COUNT] = [#(#by_id),*];
+            const SHORTCODES: &'static [(&'static str, Icon)] = &[#(#shortcodes),*];
+            const NAMES: [&'static str; COUNT] = [#(#names),*];
+            const ALIASES: [&'static [&'static str]; COUNT] = [#(#aliases),*];
+            const SEARCH_INDEX: [&'static str; COUNT] = [#(#search_index),*];
+            const DISPLAY_NAMES: &'static [(&'static str, [&'static str; COUNT])] =
+                &[#(#display_names),*];
+            // Some values happen to be close to constants like `FRAC_PI_8`.
+            #[allow(clippy::approx_constant)]
+            const METRICS: [[f32; 6]; COUNT] = [#(#metrics),*];
+        }
+    };
+
+    // Fonts without color glyphs, all but emoji fonts, don't get a table.
+    let (colors_table, is_color) = if icons.iter().any(|icon| icon.color) {
+        let colors = icons.iter().map(|icon| icon.color);
+        (
+            quote! { const COLORS: [bool; COUNT] = [#(#colors),*]; },
+            quote! { Self::COLORS[self.index()] },
+        )
+    } else {
+        (quote! {}, quote! { false })
+    };
+
+    // iced 0.12 has a single clipboard, later versions also the primary selection.
+    let clipboard_write = if cfg!(feature = "iced_0_12") {
+        quote! { clipboard.write(self.char().to_string()); }
+    } else {
+        quote! {
+            clipboard.write(
+                iced_widget::core::clipboard::Kind::Standard,
+                self.char().to_string(),
+            );
+        }
+    };
+
+    let font_of_icon = font_of_icon(context);
+    // Modules leaving the shaping to the callers answer advanced, which draws every icon.
+    let shaping = if context.shaping_argument.is_empty() {
+        let shaping = &context.shaping;
+        quote! {
+            use iced_widget::core::text;
+            #shaping
+        }
+    } else {
+        quote! { iced_widget::core::text::Shaping::Advanced }
+    };
+    let widget_methods = widgets::methods(context);
+
+    Ok(quote! {
+        /// Every icon of the font, for icons picked at runtime. The variants are the function
+        /// names in CamelCase.
+        ///
+        /// An icon is a `u32` whose value is its codepoint, `Icon::Heart as u32 == '\u{f004}' as
+        /// u32`, so `Option<Icon>` is four bytes too and big tables of icons stay small. `icon as
+        /// u32` and [`Icon::from_repr`] convert it, and the value stays the same as long as the
+        /// font keeps the icon at its codepoint, whatever icons are added, removed or renamed
+        /// around it. Only icons of merged fonts at a codepoint an earlier font has an icon at
+        /// too get values past `char::MAX`, which depend on their order. Store icons by
+        /// [`Icon::id`] when the data has to tell the merged fonts apart.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[allow(non_camel_case_types, missing_docs)]
+        #[repr(u32)]
+        pub enum Icon {
+            #(#idents = #discriminants,)*
+        }
+
+        impl Icon {
+            /// Every icon, in the order of their codepoints.
+            pub const ALL: [Icon; COUNT] = [#(Icon::#idents),*];
+
+            #tables
+            #colors_table
+
+            /// The index of the icon in [`Icon::ALL`].
+            #[must_use]
+            pub const fn index(self) -> usize {
+                // The first index whose value isn't below the icon's.
+                let (mut low, mut high) = (0, COUNT);
+                while low < high {
+                    let middle = low + (high - low) / 2;
+                    if (Self::ALL[Self::BY_VALUE[middle] as usize] as u32) < self as u32 {
+                        low = middle + 1;
+                    } else {
+                        high = middle;
+                    }
+                }
+
+                Self::BY_VALUE[low] as usize
+            }
+
+            /// The icon whose value is `value`, the inverse of `icon as u32`.
+            #[must_use]
+            pub const fn from_repr(value: u32) -> Option<Icon> {
+                // The first index whose value isn't below `value`.
+                let (mut low, mut high) = (0, COUNT);
+                while low < high {
+                    let middle = low + (high - low) / 2;
+                    if (Self::ALL[Self::BY_VALUE[middle] as usize] as u32) < value {
+                        low = middle + 1;
+                    } else {
+                        high = middle;
+                    }
+                }
+
+                if low < COUNT && Self::ALL[Self::BY_VALUE[low] as usize] as u32 == value {
+                    Some(Self::ALL[Self::BY_VALUE[low] as usize])
+                } else {
+                    None
+                }
+            }
+
+            /// The icon drawn by the character `c`, the first one when merged fonts share it.
+            /// It's a `const fn`, so tables of icons can be built from codepoints at compile time.
+            #[must_use]
+            pub const fn from_char(c: char) -> Option<Icon> {
+                // The first index whose character isn't below `c`.
+                let (mut low, mut high) = (0, COUNT);
+                while low < high {
+                    let middle = low + (high - low) / 2;
+                    if (Self::CHARS[Self::BY_CHAR[middle] as usize] as u32) < c as u32 {
+                        low = middle + 1;
+                    } else {
+                        high = middle;
+                    }
+                }
+
+                if low < COUNT && Self::CHARS[Self::BY_CHAR[low] as usize] as u32 == c as u32 {
+                    Some(Self::ALL[Self::BY_CHAR[low] as usize])
+                } else {
+                    None
+                }
+            }
+
+            /// The stable id of the icon, for databases and settings storing icons people chose.
+            /// The lower 21 bits are the codepoint, the upper 11 a hash of the font's family name,
+            /// so unlike `icon as u32` it tells apart icons of merged fonts at the same codepoint.
+            /// It stays the same across releases, unless the font drops or moves the icon.
+            #[must_use]
+            pub const fn id(self) -> u32 {
+                Self::IDS[self.index()]
+            }
+
+            /// The icon whose stable id is `id`, the inverse of [`Icon::id`].
+            #[must_use]
+            pub const fn from_id(id: u32) -> Option<Icon> {
+                // The first index whose id isn't below `id`.
+                let (mut low, mut high) = (0, COUNT);
+                while low < high {
+                    let middle = low + (high - low) / 2;
+                    if Self::IDS[Self::BY_ID[middle] as usize] < id {
+                        low = middle + 1;
+                    } else {
+                        high = middle;
+                    }
+                }
+
+                if low < COUNT && Self::IDS[Self::BY_ID[low] as usize] == id {
+                    Some(Self::ALL[Self::BY_ID[low] as usize])
+                } else {
+                    None
+                }
+            }
+
+            /// The character of the icon.
+            #[must_use]
+            pub const fn char(self) -> char {
+                Self::CHARS[self.index()]
+            }
+
+            /// The name of the icon's function.
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                Self::NAMES[self.index()]
+            }
+
+            /// The icon's character as a Rust escape like `\u{f101}`, for source code and logs
+            /// where the font isn't around to show it.
+            #[must_use]
+            pub fn to_unicode_escape(self) -> String {
+                format!("\\u{{{:x}}}", self.char() as u32)
+            }
+
+            /// Writes the icon's character to the clipboard, for custom widgets. Apps copy it with
+            /// `iced::clipboard::write(icon.to_string())`.
+            pub fn copy_to_clipboard(self, clipboard: &mut dyn iced_widget::core::Clipboard) {
+                #clipboard_write
+            }
+
+            /// The icon of a GitHub style shortcode like `:house:`. That's GitHub's shortcode of
+            /// the emoji at the icon's codepoint, like `:rocket:` for U+1F680 of emoji fonts, or
+            /// else the icon's function name between colons. Dashes in function names work like
+            /// underscores, so `:arrow-up:` is `Icon::ArrowUp`.
+            #[must_use]
+            pub fn from_shortcode(shortcode: &str) -> Option<Icon> {
+                let name = shortcode.strip_prefix(':')?.strip_suffix(':')?;
+                if let Ok(index) = Self::SHORTCODES.binary_search_by_key(&name, |(code, _)| *code)
+                {
+                    return Some(Self::SHORTCODES[index].1);
+                }
+                if name.contains('-') {
+                    Self::from_name(&name.replace('-', "_"))
+                } else {
+                    Self::from_name(name)
+                }
+            }
+
+            /// The icon whose function is called `name`, like `Icon::from_name("house")`, for
+            /// names from config files and user input. It's a perfect hash lookup, as fast for the
+            /// thousands of icons of the big fonts as for a handful.
+            #[must_use]
+            pub fn from_name(name: &str) -> Option<Icon> {
+                #name_lookup
+            }
+
+            /// The icons whose name or one of whose aliases contains `query`, ignoring case,
+            /// spaces, dashes and underscores, so `"Arrow Up"` finds `arrow_up`. The names are
+            /// normalized when the module is generated, so searching doesn't allocate, which keeps
+            /// icon pickers searching on every keystroke fast.
+            pub fn search(query: &str) -> impl Iterator<Item = Icon> + '_ {
+                let query = query
+                    .chars()
+                    .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_'))
+                    .flat_map(char::to_lowercase);
+
+                Self::ALL.into_iter().filter(move |icon| {
+                    let index = Self::SEARCH_INDEX[icon.index()];
+                    index.char_indices().any(|(start, _)| {
+                        let mut rest = index[start..].chars();
+                        query.clone().all(|c| rest.next() == Some(c))
+                    })
+                })
+            }
+
+            /// The search terms of the icon from the `aliases` option, empty without it.
+            #[must_use]
+            pub const fn aliases(self) -> &'static [&'static str] {
+                Self::ALIASES[self.index()]
+            }
+
+            /// Text standing in for the icon where its font can't be shown, like terminal UIs and
+            /// logs, from the `fallbacks` option. Icons without one fall back to their name.
+            #[must_use]
+            pub const fn fallback_text(self) -> &'static str {
+                #fallback_text
+            }
+
+            /// The name of the icon to show to people reading `locale`, like `"de"` or `"pt-BR"`,
+            /// from the `translations` option. Without a translation into `locale` the icon falls
+            /// back to its language without the region, then to English, then to its name.
+            #[must_use]
+            pub fn display_name(self, locale: &str) -> &'static str {
+                let locale = locale.replace('_', "-");
+                let language = locale.split('-').next().unwrap_or(&locale);
+                [&locale, language, "en"]
+                    .into_iter()
+                    .find_map(|locale| {
+                        Self::DISPLAY_NAMES
+                            .iter()
+                            .find(|(translated, _)| translated.eq_ignore_ascii_case(locale))
+                            .map(|(_, names)| names[self.index()])
+                            .filter(|name| !name.is_empty())
+                    })
+                    .unwrap_or(self.name())
+            }
+
+            /// The font the icon is drawn with.
+            #[must_use]
+            pub const fn font(self) -> iced_widget::core::Font {
+                #font_of_icon
+            }
+
+            /// The shaping the icon is drawn with, from the `shaping` option, for widgets drawing
+            /// icons of several fonts.
+            #[must_use]
+            pub const fn shaping(self) -> iced_widget::core::text::Shaping {
+                #shaping
+            }
+
+            #widget_methods
+
+            /// Whether the icon has colors of its own, like an emoji. iced draws it in them
+            /// rather than the text color. Renderers without color glyphs draw the monochrome
+            /// outline fonts with `COLR` layers have, icons that are only an image have none, their
+            /// [`Icon::metrics`] bounds are empty, so show [`Icon::fallback_text`] there.
+            #[must_use]
+            pub const fn is_color(self) -> bool {
+                #is_color
+            }
+
+            /// The size and position of the icon's glyph.
+            #[must_use]
+            pub const fn metrics(self) -> Metrics {
+                let [advance, x, y, width, height, baseline] = Self::METRICS[self.index()];
+                Metrics {
+                    advance,
+                    bounds: iced_widget::core::Rectangle {
+                        x,
+                        y,
+                        width,
+                        height,
+                    },
+                    baseline,
+                }
+            }
+        }
+
+        /// The icon's character, which draws the icon in its font.
+        impl std::fmt::Display for Icon {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Write::write_char(f, self.char())
+            }
+        }
+
+        /// The size and position of an icon's glyph in ems, multiply them by the text size to get
+        /// pixels. Custom widgets can lay icons out with them without parsing the font.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct Metrics {
+            /// How far the glyph moves the text after it, its width in a line of text.
+            pub advance: f32,
+            /// The bounding box of the outline relative to the glyph's origin on the baseline,
+            /// with y pointing down like in iced. Empty for glyphs without an outline.
+            pub bounds: iced_widget::core::Rectangle,
+            /// The distance from the top of the line to the baseline, the ascender of the font.
+            pub baseline: f32,
+        }
+    })
+}
+
+/// The names of the icons in other languages per locale from the `translations` option, empty
+/// for the icons without a translation. Translations are keyed by the names without the affixes,
+/// like `only`.
+fn display_names(context: &Context<'_>) -> syn::Result<Vec<(String, Vec<String>)>> {
+    let translations = &context.options.translations;
+    let mut display_names = Vec::with_capacity(translations.len());
+    for (locale, translations) in translations {
+        let mut names = vec![String::new(); context.icons.len()];
+        for translation in translations {
+            let Some(i) = context.position(&translation.from) else {
+                return Err(syn::Error::new(
+                    translation.span,
+                    format!(
+                        "there is no icon named `{}` in `{}` to translate into `{locale}`",
+                        translation.from, context.font_path
+                    ),
+                ));
+            };
+            names[i].clone_from(&translation.to);
+        }
+        display_names.push((locale.replace('_', "-"), names));
+    }
+
+    Ok(display_names)
+}
+
+/// The body of `Icon::font`. An icon's font is the one of the source its index falls into,
+/// sources without icons are left out.
+fn font_of_icon(context: &Context<'_>) -> TokenStream {
+    // Where the icons of every source end, they come one source after the other.
+    let mut ends: Vec<(usize, usize)> = Vec::new();
+    for (end, icon) in (1..).zip(context.icons) {
+        match ends.last_mut() {
+            Some((last_end, source)) if *source == icon.source => *last_end = end,
+            _ => ends.push((end, icon.source)),
+        }
+    }
+    let fonts: Vec<(usize, &TokenStream)> = ends
+        .into_iter()
+        .map(|(end, source)| (end, &context.sources[source].font))
+        .collect();
+
+    match fonts.split_last() {
+        Some(((_, last), [])) => quote! { #last },
+        Some(((_, last), rest)) => {
+            let (ends, fonts): (Vec<_>, Vec<_>) = rest.iter().copied().unzip();
+            quote! {
+                let index = self.index();
+                #(if index < #ends { return #fonts; })*
+                #last
+            }
+        }
+        None => quote! { match self {} },
+    }
+}
@@ -0,0 +1,474 @@
+//! The icons of a module, collected from the glyphs of its fonts with the options naming,
+//! filtering and describing them. Every table and function of the module is made of these.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use proc_macro2::TokenStream;
+use syn::LitStr;
+
+use crate::cache;
+use crate::input::Options;
+use crate::names;
+
+/// A font whose icons are in the module, the main one or one of the `merge` option.
+pub(crate) struct Source {
+    pub(crate) glyphs: Arc<[cache::Glyph]>,
+    /// The data URIs of the preview images by codepoint, with the `previews` option.
+    pub(crate) previews: HashMap<char, String>,
+    /// The font the icons are drawn with.
+    pub(crate) font: TokenStream,
+    /// The same font named from inside the `advanced_text` module.
+    pub(crate) advanced_font: TokenStream,
+    /// The font's part of the stable ids of its icons, see `font_id`.
+    pub(crate) id: u32,
+}
+
+/// An icon of the module.
+pub(crate) struct Icon<'a> {
+    /// The name of the function, with the prefix and suffix.
+    pub(crate) name: String,
+    /// The name without the prefix and suffix, which the options naming icons like `only`,
+    /// `fallbacks` or `number_badges` take and the usage report lists.
+    pub(crate) only_name: String,
+    /// The glyph name of the font or of the `names` file.
+    pub(crate) raw_name: Option<&'a str>,
+    pub(crate) codepoint: char,
+    /// The font the icon comes from, `0` for the main one and the merged ones after it.
+    pub(crate) source: usize,
+    /// The search terms from the `aliases` option.
+    pub(crate) aliases: Vec<&'a str>,
+    /// The categories from the `categories` option, only used in manifests.
+    pub(crate) categories: Vec<String>,
+    /// The text standing in for the icon from the `fallbacks` option.
+    pub(crate) fallback: Option<&'a str>,
+    /// The data URI of the preview image, with the `previews` option.
+    pub(crate) preview: Option<&'a str>,
+    pub(crate) metrics: cache::Metrics,
+    pub(crate) color: bool,
+    /// The stable id, the font's id in the upper 11 bits and the codepoint in the lower 21.
+    pub(crate) id: u32,
+}
+
+/// The icons of a module and the glyphs that didn't make it unchanged.
+pub(crate) struct Icons<'a> {
+    pub(crate) icons: Vec<Icon<'a>>,
+    /// The glyphs left without a function, see `SKIPPED`.
+    pub(crate) skipped: Vec<(char, String)>,
+    /// The original and numbered names of icons whose name was taken, see `DUPLICATES`.
+    pub(crate) duplicates: Vec<(String, String)>,
+}
+
+/// The icons of every glyph of `sources` the options keep, one source after the other. Options
+/// naming glyphs or icons that don't exist are an error.
+pub(crate) fn collect<'a>(
+    options: &'a Options,
+    sources: &'a [Source],
+    font_path: &str,
+) -> syn::Result<Icons<'a>> {
+    let mut icons: Vec<Icon<'a>> = Vec::new();
+    let mut taken = names::Taken::default();
+    let mut renamed_duplicates: Vec<(String, String)> = Vec::new();
+    let mut skipped: Vec<(char, String)> = Vec::new();
+    let mut used_renames = vec![false; options.rename.len()];
+    let mut used_only = vec![false; options.only.as_ref().map_or(0, Vec::len)];
+    let mut used_skip = vec![false; options.skip.len()];
+    let mut used_fallbacks = vec![false; options.fallbacks.len()];
+
+    for (source, glyph) in sources
+        .iter()
+        .enumerate()
+        .flat_map(|(index, source)| source.glyphs.iter().map(move |glyph| (index, glyph)))
+    {
+        let c = glyph.codepoint;
+        if !options.includes(c) {
+            continue;
+        }
+
+        // Names from a metadata file take precedence over the font's own.
+        let raw_name = options
+            .names
+            .get(&c)
+            .map(String::as_str)
+            .or(glyph.name.as_deref());
+
+        let rename =
+            raw_name.and_then(|raw_name| options.rename.iter().position(|r| r.from == raw_name));
+
+        // Glyphs without a name still get a function, named after their codepoint.
+        let mut processed_name = match (raw_name, rename) {
+            (Some(_), Some(i)) => {
+                used_renames[i] = true;
+                options.rename[i].to.clone()
+            }
+            (Some(raw_name), None) => match names::sanitize(
+                options
+                    .strip_prefix
+                    .iter()
+                    .find_map(|prefix| raw_name.strip_prefix(prefix.as_str()))
+                    .unwrap_or(raw_name),
+                options.digits,
+            ) {
+                Some(name) => name,
+                None => {
+                    skipped.push((c, raw_name.to_owned()));
+                    continue;
+                }
+            },
+            (None, _) => names::fallback(c),
+        };
+
+        // Repeated names get numbered, `house`, `house_2`, `house_3`...
+        let numbered = taken.take(&processed_name);
+        let original_name =
+            (numbered != processed_name).then(|| std::mem::replace(&mut processed_name, numbered));
+
+        // Filtered after numbering duplicates, so `house_2` stays `house_2` whatever is skipped.
+        let listed =
+            |names: &[LitStr]| names.iter().position(|name| name.value() == processed_name);
+        if let Some(only) = &options.only {
+            match listed(only) {
+                Some(i) => used_only[i] = true,
+                None => continue,
+            }
+        }
+        if let Some(i) = listed(&options.skip) {
+            used_skip[i] = true;
+            continue;
+        }
+
+        let affix = |name: &str| format!("{}{name}{}", options.prefix, options.suffix);
+        if let Some(original_name) = original_name {
+            renamed_duplicates.push((affix(&original_name), affix(&processed_name)));
+        }
+        let name = affix(&processed_name);
+
+        // Search terms let rustdoc, editors and `Icon::aliases` find icons by their synonyms.
+        // rustdoc rejects aliases with quotes or line breaks and ones equal to the name.
+        let aliases = raw_name
+            .and_then(|raw_name| options.aliases.get(raw_name))
+            .into_iter()
+            .flatten()
+            .map(|alias| alias.trim())
+            .filter(|alias| {
+                !alias.is_empty()
+                    && *alias != name
+                    && !alias
+                        .chars()
+                        .any(|c| matches!(c, '"' | '\'') || (c.is_whitespace() && c != ' '))
+            })
+            .fold(Vec::new(), |mut aliases, alias| {
+                if !aliases.contains(&alias) {
+                    aliases.push(alias);
+                }
+                aliases
+            });
+        let categories = raw_name
+            .and_then(|raw_name| options.categories.get(raw_name))
+            .into_iter()
+            .flatten()
+            .map(|category| category.trim().to_string())
+            .fold(Vec::new(), |mut categories, category| {
+                if !category.is_empty() && !categories.contains(&category) {
+                    categories.push(category);
+                }
+                categories
+            });
+
+        // Fallbacks are keyed by the names without the affixes, like `only`.
+        let fallback = options
+            .fallbacks
+            .iter()
+            .position(|f| f.from == processed_name);
+        if let Some(i) = fallback {
+            used_fallbacks[i] = true;
+        }
+
+        icons.push(Icon {
+            name,
+            only_name: processed_name,
+            raw_name,
+            codepoint: c,
+            source,
+            aliases,
+            categories,
+            fallback: fallback.map(|i| options.fallbacks[i].to.as_str()),
+            preview: sources[source].previews.get(&c).map(String::as_str),
+            metrics: glyph.metrics,
+            color: glyph.color,
+            id: sources[source].id << 21 | c as u32,
+        });
+    }
+
+    // A rename that matches nothing is most likely a typo, or the glyph was renamed upstream.
+    if let Some(i) = used_renames.iter().position(|used| !used) {
+        let rename = &options.rename[i];
+        return Err(syn::Error::new(
+            rename.span,
+            format!("there is no glyph named `{}` in `{font_path}`", rename.from),
+        ));
+    }
+
+    if let Some(i) = used_fallbacks.iter().position(|used| !used) {
+        let fallback = &options.fallbacks[i];
+        return Err(syn::Error::new(
+            fallback.span,
+            format!(
+                "there is no icon named `{}` in `{font_path}`",
+                fallback.from
+            ),
+        ));
+    }
+
+    let unknown = options
+        .only
+        .iter()
+        .flatten()
+        .zip(&used_only)
+        .chain(options.skip.iter().zip(&used_skip))
+        .find(|(_, used)| !**used);
+    if let Some((name, _)) = unknown {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("there is no icon named `{}` in `{font_path}`", name.value()),
+        ));
+    }
+
+    // Fonts get updated, this catches icons silently lost to names that can't be used.
+    if let Some(expect) = &options.expect {
+        let expected: usize = expect.base10_parse()?;
+        if icons.len() < expected {
+            return Err(syn::Error::new(
+                expect.span(),
+                format!(
+                    "expected at least {expected} icons in `{font_path}`, only {} were generated",
+                    icons.len()
+                ),
+            ));
+        }
+    }
+
+    Ok(Icons {
+        icons,
+        skipped,
+        duplicates: renamed_duplicates,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use proc_macro2::TokenStream;
+
+    use super::{Source, collect};
+    use crate::cache::{Glyph, Metrics};
+    use crate::input::Input;
+
+    /// What a module made of the fixture has, the function names, `DUPLICATES` and the glyph
+    /// names of `SKIPPED`.
+    struct Fixture {
+        names: Vec<String>,
+        duplicates: Vec<(String, String)>,
+        skipped: Vec<String>,
+    }
+
+    /// The icons of a font with the glyph names `glyphs`, the first at U+E000 and the others
+    /// after it, with the macro options `options`.
+    fn collected(options: &str, glyphs: &[&str]) -> syn::Result<Fixture> {
+        let input: Input = syn::parse_str(&format!(
+            "\"fixture.ttf\", fixture, FIXTURE_FONT, {options}"
+        ))?;
+        let glyphs = glyphs
+            .iter()
+            .zip('\u{e000}'..)
+            .map(|(name, codepoint)| Glyph {
+                codepoint,
+                name: Some((*name).to_owned()),
+                metrics: Metrics {
+                    advance: 1.0,
+                    bounds: [0.0; 4],
+                    ascender: 1.0,
+                },
+                color: false,
+            })
+            .collect();
+        let sources = [Source {
+            glyphs,
+            previews: HashMap::new(),
+            font: TokenStream::new(),
+            advanced_font: TokenStream::new(),
+            id: 0,
+        }];
+
+        let icons = collect(&input.options, &sources, "fixture.ttf")?;
+        Ok(Fixture {
+            names: icons.icons.into_iter().map(|icon| icon.name).collect(),
+            duplicates: icons.duplicates,
+            skipped: icons.skipped.into_iter().map(|(_, name)| name).collect(),
+        })
+    }
+
+    #[test]
+    fn duplicates_are_numbered() {
+        // With the digits kept, the glyph `house_2` wants the name the second `house` got.
+        let Fixture {
+            names, duplicates, ..
+        } = collected(
+            "digits = keep",
+            &["house", "house", "house_2", "house", "arrow-up", "arrow_up"],
+        )
+        .unwrap();
+
+        assert_eq!(
+            names,
+            [
+                "house",
+                "house_2",
+                "house_2_2",
+                "house_3",
+                "arrow_up",
+                "arrow_up_2"
+            ]
+        );
+        assert_eq!(
+            duplicates,
+            [
+                ("house", "house_2"),
+                ("house_2", "house_2_2"),
+                ("house", "house_3"),
+                ("arrow_up", "arrow_up_2"),
+            ]
+            .map(|(original, renamed)| (original.to_owned(), renamed.to_owned()))
+        );
+    }
+
+    #[test]
+    fn numbered_names_keep_the_affixes() {
+        let Fixture {
+            names, duplicates, ..
+        } = collected("prefix = \"bi_\"", &["house", "house"]).unwrap();
+
+        assert_eq!(names, ["bi_house", "bi_house_2"]);
+        assert_eq!(
+            duplicates,
+            [("bi_house".to_owned(), "bi_house_2".to_owned())]
+        );
+    }
+
+    #[test]
+    fn punctuation_is_replaced() {
+        let Fixture {
+            names,
+            duplicates,
+            skipped,
+        } = collected(
+            "",
+            &[
+                "c++",
+                "a&b",
+                "at@home",
+                "100%",
+                "arrow.left",
+                "file/pdf",
+                "!!",
+                "...",
+                "c_plus_plus",
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            names,
+            [
+                "c_plus_plus",
+                "a_and_b",
+                "at_at_home",
+                "onezerozero_percent",
+                "arrow_left",
+                "file_pdf",
+                "bang_bang",
+                "c_plus_plus_2",
+            ]
+        );
+        assert_eq!(
+            duplicates,
+            [("c_plus_plus".to_owned(), "c_plus_plus_2".to_owned())]
+        );
+        // Nothing is left of a name of only dots.
+        assert_eq!(skipped, ["..."]);
+    }
+
+    #[test]
+    fn ranges_pick_codepoints() {
+        let glyphs = ["house", "house", "a", "b", "c", "d"];
+        let Fixture { names, .. } = collected(
+            "include_ranges = [0xe001..=0xe003, 0xe005], exclude_ranges = [0xe002..0xe003]",
+            &glyphs,
+        )
+        .unwrap();
+        // The first `house` is left out before it could take the name.
+        assert_eq!(names, ["house", "b", "d"]);
+
+        let Fixture { names, .. } =
+            collected("exclude_ranges = [0xe000..=0xe001]", &glyphs).unwrap();
+        assert_eq!(names, ["a", "b", "c", "d"]);
+
+        for empty in [
+            "include_ranges = [0xe003..=0xe001]",
+            "exclude_ranges = [0xe001..0xe001]",
+        ] {
+            assert_eq!(
+                collected(empty, &glyphs)
+                    .err()
+                    .map(|e| e.to_string())
+                    .as_deref(),
+                Some("the range is empty")
+            );
+        }
+    }
+
+    #[test]
+    fn only_and_skip_pick_names() {
+        let glyphs = ["house", "house", "arrow-up", "x"];
+        let names = |options| collected(options, &glyphs).map(|fixture| fixture.names);
+
+        // Duplicates are numbered first, so `house_2` is the same icon whatever is left out.
+        assert_eq!(
+            names("only = [\"house_2\", \"x\"]").unwrap(),
+            ["house_2", "x"]
+        );
+        assert_eq!(
+            names("skip = [\"house\"]").unwrap(),
+            ["house_2", "arrow_up", "x"]
+        );
+        assert_eq!(
+            names("only = [\"house\", \"x\"], skip = [\"x\"]").unwrap(),
+            ["house"]
+        );
+        // They take the names without the affixes.
+        assert_eq!(
+            names("prefix = \"bi_\", only = [\"arrow_up\"]").unwrap(),
+            ["bi_arrow_up"]
+        );
+
+        for unknown in ["only = [\"arrow-up\"]", "skip = [\"house_3\"]"] {
+            let name = unknown.split('"').nth(1).unwrap();
+            assert_eq!(
+                names(unknown).err().map(|e| e.to_string()),
+                Some(format!("there is no icon named `{name}` in `fixture.ttf`"))
+            );
+        }
+    }
+
+    #[test]
+    fn prefixes_are_stripped_before_sanitizing() {
+        let Fixture { names, .. } = collected(
+            "strip_prefix = [\"nf-md-\", \"nf-\"]",
+            &["nf-md-home", "nf-fa-home", "house", "nfHome"],
+        )
+        .unwrap();
+        // The first prefix a name starts with goes, raw names with other spellings keep theirs.
+        assert_eq!(names, ["home", "fa_home", "house", "nf_home"]);
+    }
+}
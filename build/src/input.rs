@@ -0,0 +1,421 @@
+//! Parsing of the macro arguments.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use proc_macro2::Ident;
+use syn::{
+    LitInt, LitStr, Token, Visibility, braced, bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Comma,
+};
+
+use crate::link::Template;
+use crate::metadata;
+use crate::names::Digits;
+use crate::rename::{self, Rename};
+use crate::variation::Variation;
+use crate::{Output, Shaping};
+
+pub(crate) struct Input {
+    /// e.g. `"fonts/bootstrap-icons-new.ttf"`, relative to the invoking crate's `Cargo.toml`
+    pub(crate) font_path: LitStr,
+    /// e.g. `bootstrap`
+    pub(crate) module_name: Ident,
+    /// e.g. `"BOOTSTRAP_FONT"`
+    pub(crate) font_name: Ident,
+    /// e.g. `https://icons.getbootstrap.com/icons/{raw}`
+    pub(crate) doc_link: Option<Template>,
+    pub(crate) options: Options,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let font_path = input.parse()?;
+        let _: Comma = input.parse()?;
+        let module_name = input.parse()?;
+        let _: Comma = input.parse()?;
+        let font_name = input.parse()?;
+
+        // It is good-mannered to accept an optional trailing comma
+        let _: Option<Comma> = input.parse()?;
+        let doc_link = input
+            .parse::<Option<LitStr>>()?
+            .as_ref()
+            .map(Template::parse)
+            .transpose()?;
+        let _: Option<Comma> = input.parse()?;
+
+        let mut options = Options::default();
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            options.parse_option(&key, input)?;
+
+            if !input.is_empty() {
+                let _: Comma = input.parse()?;
+            }
+        }
+
+        Ok(Self {
+            font_path,
+            module_name,
+            font_name,
+            doc_link,
+            options,
+        })
+    }
+}
+
+/// The optional `key = value` arguments after the positional ones.
+#[derive(Default)]
+pub(crate) struct Options {
+    /// `digits = spell | keep`
+    pub(crate) digits: Digits,
+    /// `strip_prefix = "icon-"` or `strip_prefix = ["nf-md-", "nf-"]`, removed from glyph names
+    /// before they become function names, the first one that matches.
+    pub(crate) strip_prefix: Vec<String>,
+    /// `include_ranges = [0xe000..=0xf8ff]`, only codepoints in one of these get a function.
+    pub(crate) include_ranges: Vec<RangeInclusive<u32>>,
+    /// `exclude_ranges = [0x20..0x7f]`, codepoints in one of these never get a function.
+    pub(crate) exclude_ranges: Vec<RangeInclusive<u32>>,
+    /// `expect = 1548`, the least amount of icons the font has to produce.
+    pub(crate) expect: Option<LitInt>,
+    /// `snapshot = "snapshots/lucide.txt"`, a file the generated names are tested against.
+    pub(crate) snapshot: Option<LitStr>,
+    /// `changes = "changes/lucide.txt"`, how the icons changed with the last update of the font.
+    pub(crate) changes: Option<LitStr>,
+    /// `rename = { "glyph-name" = "fn_name" }` or `rename = "renames.toml"`
+    pub(crate) rename: Vec<Rename>,
+    /// `only = ["house", "gear"]`, the only functions to generate.
+    pub(crate) only: Option<Vec<LitStr>>,
+    /// `skip = ["house", "gear"]`, functions not to generate.
+    pub(crate) skip: Vec<LitStr>,
+    /// `prefix = "bs_"`, put in front of every function name.
+    pub(crate) prefix: String,
+    /// `suffix = "_icon"`, put after every function name.
+    pub(crate) suffix: String,
+    /// `visibility = pub | pub(crate) | pub(super) | private` of the generated module, `None`
+    /// for `pub`.
+    pub(crate) visibility: Option<Visibility>,
+    /// `shaping = basic | advanced | parameter`, `None` for the default of the macro.
+    pub(crate) shaping: Option<Shaping>,
+    /// `output = widget | char | str`
+    pub(crate) output: Output,
+    /// `names = "MaterialIcons.codepoints"`, icon names by codepoint read from a metadata file.
+    pub(crate) names: HashMap<char, String>,
+    /// `face = 2`, the index of the face to use in a font collection (`.ttc`/`.otc`).
+    pub(crate) face: Option<u32>,
+    /// `instance = "Bold"` or `axes = { wght = 700 }` of a variable font.
+    pub(crate) variation: Option<Variation>,
+    /// `weights = ["fonts/icons-bold.ttf"]`, other weights and styles of the font's family.
+    pub(crate) weights: Vec<LitStr>,
+    /// `merge = ["fonts/brands.otf" => BRANDS_FONT]`, more fonts whose icons join the module.
+    pub(crate) merge: Vec<(LitStr, Ident)>,
+    /// `monochrome = true`, the `monochrome` submodule with a font drawing the color glyphs of a
+    /// `COLR` font in the text color.
+    pub(crate) monochrome: Option<syn::LitBool>,
+    /// `aliases = "tags.json"`, search terms of the icons by their raw names.
+    pub(crate) aliases: HashMap<String, Vec<String>>,
+    /// `categories = "categories.json"`, categories of the icons by their raw names, only used
+    /// in manifests.
+    pub(crate) categories: HashMap<String, Vec<String>>,
+    /// `deprecated = "deprecated/lucide.toml"`, old function names and the names they were
+    /// renamed to, in the same format as `rename`.
+    pub(crate) deprecated: Vec<Rename>,
+    /// `fallbacks = "fallbacks/lucide.toml"`, text standing in for icons by their function names,
+    /// in the same format as `rename`.
+    pub(crate) fallbacks: Vec<Rename>,
+    /// `translations = { de = "translations/lucide.de.toml" }`, the names of the icons in other
+    /// languages by their function names, per locale.
+    pub(crate) translations: Vec<(String, Vec<Rename>)>,
+    /// `previews = true`, a picture of every icon in its documentation.
+    pub(crate) previews: bool,
+    /// `data_only = true`, only the `Icon` enum and its tables without a function per icon.
+    pub(crate) data_only: bool,
+    /// `advanced_text = true`, the `advanced_text` submodule, which otherwise follows the
+    /// `advanced_text` feature.
+    pub(crate) advanced_text: Option<bool>,
+    /// `iced_aw = true`, conversions of the icons into iced_aw's `TabLabel`.
+    pub(crate) iced_aw: bool,
+    /// `submodules = ["md", "fa"]`, groups of icons whose names start with `md_` or `fa_` that
+    /// are also in a submodule of their own, without the prefix.
+    pub(crate) submodules: Vec<LitStr>,
+    /// `min_size = 16`, the smallest size in pixels the font renders well at.
+    pub(crate) min_size: Option<LitInt>,
+    /// `number_badges = "{}_circle"`, the names of the icons of the digits, with `{}` where the
+    /// digit is.
+    pub(crate) number_badges: Option<LitStr>,
+    /// `arrows = "arrow_{}"`, the names of the arrows with `{}` where `up`, `down`, `left` or
+    /// `right` is.
+    pub(crate) arrows: Option<LitStr>,
+    /// `chevrons = "chevron_{}"`, like `arrows`.
+    pub(crate) chevrons: Option<LitStr>,
+    /// `selection = { checked = "check_square", unchecked = "square", ... }`, the icons of
+    /// checkboxes and radio buttons.
+    pub(crate) selection: Vec<Rename>,
+    /// `media = { play = "play", pause = "pause", ... }`, the icons of media player controls.
+    pub(crate) media: Vec<Rename>,
+    /// `vcs = { modified = "diff_modified", added = "diff_added", ... }`, the icons of file
+    /// statuses in version control.
+    pub(crate) vcs: Vec<Rename>,
+    /// `file_kinds = { file = "file", pdf = "file_pdf", ... }`, the icons of kinds of files.
+    pub(crate) file_kinds: Vec<Rename>,
+    /// `platforms = { linux = "linux", windows = "windows", ... }`, the logos of operating
+    /// systems.
+    pub(crate) platforms: Vec<Rename>,
+    /// `roles = "roles/lucide.toml"` or `{ close = "x", save = "floppy", ... }`, the icons of
+    /// common actions and states for icon themes.
+    pub(crate) roles: Vec<Rename>,
+    /// `spinners = { clock = ["clock_one", "clock_two"] }`, frame sequences of loading
+    /// indicators by the names of their icons.
+    pub(crate) spinners: Vec<(Ident, Vec<LitStr>)>,
+}
+
+impl Options {
+    fn parse_option(&mut self, key: &Ident, input: ParseStream<'_>) -> syn::Result<()> {
+        match key.to_string().as_str() {
+            "digits" => {
+                self.digits =
+                    parse_choice(input, &[("spell", Digits::Spell), ("keep", Digits::Keep)])?;
+            }
+            "strip_prefix" => {
+                let prefixes = if input.peek(LitStr) {
+                    vec![input.parse()?]
+                } else {
+                    parse_names(input)?
+                };
+                if let Some(prefix) = prefixes.iter().find(|prefix| prefix.value().is_empty()) {
+                    return Err(syn::Error::new(prefix.span(), "the prefix can't be empty"));
+                }
+                self.strip_prefix = prefixes.iter().map(LitStr::value).collect();
+            }
+            "include_ranges" => self.include_ranges = parse_ranges(input)?,
+            "exclude_ranges" => self.exclude_ranges = parse_ranges(input)?,
+            "expect" => {
+                let expect: LitInt = input.parse()?;
+                expect.base10_parse::<usize>()?;
+                self.expect = Some(expect);
+            }
+            "snapshot" => self.snapshot = Some(input.parse()?),
+            "changes" => self.changes = Some(input.parse()?),
+            "rename" => self.rename = rename::parse(input)?,
+            "deprecated" => self.deprecated = rename::parse(input)?,
+            "fallbacks" => self.fallbacks = rename::parse_fallbacks(input)?,
+            "translations" => self.translations = rename::parse_translations(input)?,
+            "previews" => self.previews = input.parse::<syn::LitBool>()?.value,
+            "data_only" => self.data_only = input.parse::<syn::LitBool>()?.value,
+            "advanced_text" => self.advanced_text = Some(input.parse::<syn::LitBool>()?.value),
+            "iced_aw" => self.iced_aw = input.parse::<syn::LitBool>()?.value,
+            "names" => self.names = metadata::read(&input.parse()?)?,
+            "aliases" => self.aliases = metadata::read_tags(&input.parse()?)?,
+            "categories" => self.categories = metadata::read_categories(&input.parse()?)?,
+            "instance" => self.variation = Some(Variation::Instance(input.parse()?)),
+            "axes" => self.variation = Some(Variation::parse_axes(input)?),
+            "weights" => self.weights = parse_names(input)?,
+            "merge" => {
+                let content;
+                bracketed!(content in input);
+                let fonts = Punctuated::<(LitStr, Ident), Comma>::parse_terminated_with(
+                    &content,
+                    |input| {
+                        let path = input.parse()?;
+                        let _: Token![=>] = input.parse()?;
+                        Ok((path, input.parse()?))
+                    },
+                )?;
+                self.merge = fonts.into_iter().collect();
+            }
+            "monochrome" => self.monochrome = Some(input.parse()?),
+            "face" => self.face = Some(input.parse::<LitInt>()?.base10_parse()?),
+            "only" => self.only = Some(parse_names(input)?),
+            "skip" => self.skip = parse_names(input)?,
+            "submodules" => self.submodules = parse_names(input)?,
+            "min_size" => {
+                let size: LitInt = input.parse()?;
+                size.base10_parse::<u16>()?;
+                self.min_size = Some(size);
+            }
+            "spinners" => {
+                let content;
+                braced!(content in input);
+                let spinners = Punctuated::<(Ident, Vec<LitStr>), Comma>::parse_terminated_with(
+                    &content,
+                    |input| {
+                        let name: Ident = input.parse()?;
+                        let _: Token![=] = input.parse()?;
+                        let frames = parse_names(input)?;
+                        if frames.is_empty() {
+                            return Err(syn::Error::new(name.span(), "a spinner needs frames"));
+                        }
+                        Ok((name, frames))
+                    },
+                )?;
+                self.spinners = spinners.into_iter().collect();
+            }
+            "number_badges" => self.number_badges = Some(parse_template(input)?),
+            "arrows" => self.arrows = Some(parse_template(input)?),
+            "chevrons" => self.chevrons = Some(parse_template(input)?),
+            "selection" => self.selection = rename::parse_roles(input)?,
+            "media" => self.media = rename::parse_roles(input)?,
+            "vcs" => self.vcs = rename::parse_roles(input)?,
+            "file_kinds" => self.file_kinds = rename::parse_roles(input)?,
+            "platforms" => self.platforms = rename::parse_roles(input)?,
+            "roles" => self.roles = rename::parse_roles(input)?,
+            "prefix" => {
+                let prefix: LitStr = input.parse()?;
+                if prefix.value().starts_with(|c: char| c.is_ascii_digit()) {
+                    return Err(syn::Error::new(
+                        prefix.span(),
+                        "function names can't start with a digit",
+                    ));
+                }
+                self.prefix = parse_affix(&prefix)?;
+            }
+            "suffix" => self.suffix = parse_affix(&input.parse()?)?,
+            "shaping" => {
+                self.shaping = Some(parse_choice(
+                    input,
+                    &[
+                        ("basic", Shaping::Basic),
+                        ("advanced", Shaping::Advanced),
+                        ("parameter", Shaping::Parameter),
+                    ],
+                )?);
+            }
+            "output" => {
+                self.output = parse_choice(
+                    input,
+                    &[
+                        ("widget", Output::Widget),
+                        ("char", Output::Char),
+                        ("str", Output::Str),
+                    ],
+                )?;
+            }
+            "visibility" => {
+                self.visibility = Some(if input.peek(syn::Ident) {
+                    let private: Ident = input.parse()?;
+                    if private != "private" {
+                        return Err(syn::Error::new(
+                            private.span(),
+                            "expected `pub`, `pub(crate)`, `pub(super)`, `pub(in path)` or `private`",
+                        ));
+                    }
+                    Visibility::Inherited
+                } else {
+                    input.parse()?
+                });
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("unknown option `{key}`"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the ranges let `codepoint` get a function.
+    pub(crate) fn includes(&self, codepoint: char) -> bool {
+        let codepoint = u32::from(codepoint);
+
+        (self.include_ranges.is_empty()
+            || self.include_ranges.iter().any(|r| r.contains(&codepoint)))
+            && !self.exclude_ranges.iter().any(|r| r.contains(&codepoint))
+    }
+}
+
+/// Checks that `affix` keeps function names lower snake_case.
+fn parse_affix(affix: &LitStr) -> syn::Result<String> {
+    let value = affix.value();
+    if value
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        Ok(value)
+    } else {
+        Err(syn::Error::new(
+            affix.span(),
+            "only lowercase letters, digits and `_` are allowed",
+        ))
+    }
+}
+
+/// Parses `["house", "gear"]`.
+fn parse_names(input: ParseStream<'_>) -> syn::Result<Vec<LitStr>> {
+    let content;
+    bracketed!(content in input);
+
+    let names = Punctuated::<LitStr, Comma>::parse_terminated(&content)?;
+    Ok(names.into_iter().collect())
+}
+
+/// Parses a name like `"{}_circle"` with one `{}` for the part that differs between icons.
+fn parse_template(input: ParseStream<'_>) -> syn::Result<LitStr> {
+    let template: LitStr = input.parse()?;
+    if template.value().matches("{}").count() != 1 {
+        return Err(syn::Error::new(
+            template.span(),
+            "the template needs one `{}` where the names differ",
+        ));
+    }
+
+    Ok(template)
+}
+
+/// Parses `[0xe000..=0xf8ff, 0x20..0x7f, 0x2630]`, single codepoints are ranges of one.
+fn parse_ranges(input: ParseStream<'_>) -> syn::Result<Vec<RangeInclusive<u32>>> {
+    let content;
+    bracketed!(content in input);
+
+    let ranges =
+        Punctuated::<RangeInclusive<u32>, Comma>::parse_terminated_with(&content, |input| {
+            let start: LitInt = input.parse()?;
+            let first = start.base10_parse::<u32>()?;
+
+            let last = if input.peek(Token![..=]) {
+                let _: Token![..=] = input.parse()?;
+                input.parse::<LitInt>()?.base10_parse::<u32>()?
+            } else if input.peek(Token![..]) {
+                let _: Token![..] = input.parse()?;
+                let end: LitInt = input.parse()?;
+                end.base10_parse::<u32>()?
+                    .checked_sub(1)
+                    .ok_or_else(|| syn::Error::new(end.span(), "the range is empty"))?
+            } else {
+                first
+            };
+
+            if last < first {
+                return Err(syn::Error::new(start.span(), "the range is empty"));
+            }
+
+            Ok(first..=last)
+        })?;
+
+    Ok(ranges.into_iter().collect())
+}
+
+/// Parses an identifier that has to be one of `choices`.
+fn parse_choice<T: Copy>(input: ParseStream<'_>, choices: &[(&str, T)]) -> syn::Result<T> {
+    let value: Ident = input.parse()?;
+
+    choices
+        .iter()
+        .find(|(name, _)| value == name)
+        .map(|(_, choice)| *choice)
+        .ok_or_else(|| {
+            let names: Vec<String> = choices
+                .iter()
+                .map(|(name, _)| format!("`{name}`"))
+                .collect();
+            syn::Error::new(
+                value.span(),
+                format!("expected one of {}", names.join(", ")),
+            )
+        })
+}
@@ -0,0 +1,825 @@
+//! Generates the icon modules of `iced_fonts` from a build script, as an alternative to its
+//! macros.
+//!
+//! The module is written to `OUT_DIR` and included by the crate, so it isn't expanded again on
+//! every check and rust-analyzer sees plain code.
+//!
+//! ```no_run
+//! // in `main` of build.rs
+//! iced_fonts_build::Generator::new("fonts/my_icons.ttf", "my_icons", "MY_ICONS_FONT")
+//!     .option("digits = keep")
+//!     .write("my_icons.rs")
+//!     .unwrap();
+//! ```
+//!
+//! ```ignore
+//! // src/lib.rs
+//! pub const MY_ICONS_FONT: Font = Font::with_name("My Icons");
+//! include!(concat!(env!("OUT_DIR"), "/my_icons.rs"));
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{LitInt, LitStr, Visibility};
+use ttf_parser::Face;
+
+mod cache;
+mod changes;
+mod deprecated;
+mod derive;
+mod functions;
+mod generator;
+mod icon;
+mod icon_enum;
+mod icons;
+mod input;
+mod link;
+mod manifest;
+mod metadata;
+mod outlines;
+mod phf;
+mod preview;
+mod pua;
+mod rename;
+mod roles;
+mod sfnt;
+mod shortcodes;
+mod snapshot;
+mod spinners;
+mod stubs;
+mod style;
+mod submodules;
+mod templates;
+mod usage;
+mod variation;
+mod weights;
+mod widgets;
+mod woff;
+
+pub use generator::{Error, Generator};
+/// The rules turning glyph names into function and variant names.
+pub use iced_fonts_names as names;
+
+use icons::{Icon, Icons, Source};
+use input::{Input, Options};
+use names::Digits;
+
+/// The text shaping the generated widgets use.
+#[derive(Clone, Copy)]
+enum Shaping {
+    Basic,
+    Advanced,
+    /// Every function takes the shaping as an argument.
+    Parameter,
+}
+
+/// What the generated functions return.
+#[derive(Clone, Copy, Default)]
+enum Output {
+    /// A text widget of the icon.
+    #[default]
+    Widget,
+    /// The icon's `char`, from a `const fn`.
+    Char,
+    /// The icon as a `&'static str`, from a `const fn`.
+    Str,
+}
+
+/// The implementations of the `iced_fonts_macros` macros, which can only export the macros
+/// themselves.
+#[doc(hidden)]
+pub mod __macros {
+    use proc_macro2::TokenStream;
+    use syn::{DeriveInput, LitStr};
+
+    use crate::{Input, Shaping, derive, generate, icon};
+
+    pub fn generate_icon_functions(input: TokenStream) -> TokenStream {
+        body(input, Shaping::Basic)
+    }
+
+    pub fn generate_icon_advanced_functions(input: TokenStream) -> TokenStream {
+        body(input, Shaping::Advanced)
+    }
+
+    fn body(input: TokenStream, shaping: Shaping) -> TokenStream {
+        syn::parse2::<Input>(input)
+            .and_then(|input| generate(input, shaping))
+            .map(crate::Module::item)
+            .unwrap_or_else(syn::Error::into_compile_error)
+    }
+
+    pub fn icon(input: TokenStream) -> TokenStream {
+        syn::parse2::<LitStr>(input)
+            .and_then(|name| icon::icon(&name))
+            .unwrap_or_else(syn::Error::into_compile_error)
+    }
+
+    pub fn derive_has_icon(input: TokenStream) -> TokenStream {
+        syn::parse2::<DeriveInput>(input)
+            .and_then(derive::has_icon)
+            .unwrap_or_else(syn::Error::into_compile_error)
+    }
+
+    pub fn derive_icon_default(input: TokenStream) -> TokenStream {
+        syn::parse2::<DeriveInput>(input)
+            .and_then(derive::icon_default)
+            .unwrap_or_else(syn::Error::into_compile_error)
+    }
+}
+
+/// Pictures of the glyphs of `codepoints` in the font `data`, as SVG data URIs like the ones of
+/// the `previews` option, for cheat sheets and other documentation. Glyphs without an outline are
+/// left out, and so is everything when the font can't be parsed.
+pub fn glyph_previews(
+    data: &[u8],
+    codepoints: impl IntoIterator<Item = char>,
+) -> HashMap<char, String> {
+    match Face::parse(data, 0) {
+        Ok(face) => preview::previews(&face, codepoints.into_iter()),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// Relative font paths are relative to the `Cargo.toml` of the crate invoking the macro, no matter
+/// where cargo was run from. Absolute paths are used as they are.
+fn resolve_path(path: &str) -> PathBuf {
+    match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(manifest_dir) => Path::new(&manifest_dir).join(path),
+        None => PathBuf::from(path),
+    }
+}
+
+/// `include_bytes!` of the file at `path`, relative to the `Cargo.toml` of the crate compiling the
+/// generated code. Unlike [`resolve_path`] it leaves that to the compiler, so modules written by
+/// `cargo iced-fonts`, which runs without `CARGO_MANIFEST_DIR`, include it from their own file.
+fn include_bytes(path: &str) -> TokenStream {
+    if Path::new(path).is_absolute() {
+        quote! { include_bytes!(#path) }
+    } else {
+        let path = format!("/{path}");
+        quote! { include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), #path)) }
+    }
+}
+
+/// A font file the macros read, decoded when it is a web font.
+struct FontFile {
+    data: Vec<u8>,
+    /// The format of the web font `data` was decoded from.
+    web_format: Option<&'static str>,
+}
+
+impl FontFile {
+    /// The constant of the decoded font named after the `Font` constant `font_name`, like
+    /// `MY_ICONS_FONT_BYTES`, for loading it into iced, which can't read web fonts either. Empty
+    /// for other fonts, which apps include themselves.
+    fn bytes(&self, font_name: &Ident, path: &LitStr) -> TokenStream {
+        let Some(format) = self.web_format else {
+            return quote! {};
+        };
+
+        let name = Ident::new(&format!("{font_name}_BYTES"), Span::call_site());
+        let doc = format!(
+            " The font decoded from the {format} font `{}`, for loading [`crate::{font_name}`].",
+            path.value()
+        );
+        let data = proc_macro2::Literal::byte_string(&self.data);
+        quote! {
+            #[doc = #doc]
+            pub const #name: &[u8] = #data;
+        }
+    }
+}
+
+/// Reads the font file at `path`, decoding WOFF and WOFF2 fonts.
+fn read_font(path: &LitStr) -> syn::Result<FontFile> {
+    let path_str = path.value();
+    let data = std::fs::read(resolve_path(&path_str)).map_err(|e| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to read font file `{path_str}`: {e}"),
+        )
+    })?;
+
+    let Some(format) = woff::format(&data) else {
+        return Ok(FontFile {
+            data,
+            web_format: None,
+        });
+    };
+    let data = woff::decode(&data).map_err(|e| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to decode the {format} font `{path_str}`: {e}"),
+        )
+    })?;
+
+    Ok(FontFile {
+        data,
+        web_format: Some(format),
+    })
+}
+
+fn parse_face<'a>(data: &'a [u8], index: u32, path: &LitStr) -> syn::Result<Face<'a>> {
+    Face::parse(data, index).map_err(|e| {
+        let faces = match ttf_parser::fonts_in_collection(data) {
+            Some(faces) => format!(", the collection has {faces} faces"),
+            None => String::new(),
+        };
+        syn::Error::new(
+            path.span(),
+            format!("failed to parse font file `{}`: {e}{faces}", path.value()),
+        )
+    })
+}
+
+/// The family names the font can be looked up by, the same way `fontdb` finds them. The
+/// typographic family when there is one, otherwise the legacy family.
+fn font_families(face: &Face<'_>) -> Vec<String> {
+    let collect = |name_id| {
+        let mut families: Vec<String> = Vec::new();
+        for name in face.names() {
+            if name.name_id == name_id
+                && let Some(family) = name.to_string()
+                && !families.contains(&family)
+            {
+                families.push(family);
+            }
+        }
+        families
+    };
+
+    let families = collect(ttf_parser::name_id::TYPOGRAPHIC_FAMILY);
+    if families.is_empty() {
+        collect(ttf_parser::name_id::FAMILY)
+    } else {
+        families
+    }
+}
+
+/// The font's part of the stable ids of its icons, 11 bits of the FNV-1a hash of its family name
+/// so it stays the same across releases of the font. Fonts without a family use `font_name`.
+fn font_id(face: &Face<'_>, font_name: &Ident) -> u32 {
+    let family = font_families(face)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| font_name.to_string());
+    let hash = family.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    // Folded, so every bit of the hash counts.
+    (hash ^ (hash >> 11) ^ (hash >> 22)) & 0x7ff
+}
+
+/// The character `c` as an escaped literal like `'\u{f101}'`, so the generated code stays ASCII
+/// whatever characters the font uses.
+fn char_literal(c: char) -> proc_macro2::Literal {
+    format!("'\\u{{{:x}}}'", u32::from(c))
+        .parse()
+        .expect("an escaped character is a literal")
+}
+
+/// `iced` finds fonts by their family name, a `Font` constant naming anything else silently
+/// renders the icons with some fallback font. This makes it a compile error instead.
+fn family_check(face: &Face<'_>, font_name: &Ident, font_path: &str) -> proc_macro2::TokenStream {
+    let families = font_families(face);
+    if families.is_empty() {
+        return quote! {};
+    }
+
+    let expected: Vec<String> = families
+        .iter()
+        .map(|family| format!("{family:?}"))
+        .collect();
+    let message = format!(
+        "`{font_name}` has to be created with `Font::with_name({})`, the family name of `{font_path}`",
+        expected.join(" | ")
+    );
+
+    quote! {
+        const _: () = {
+            const fn is_family(name: &str) -> bool {
+                let families: &[&str] = &[#(#families),*];
+
+                let mut i = 0;
+                while i < families.len() {
+                    let (a, b) = (name.as_bytes(), families[i].as_bytes());
+                    if a.len() == b.len() {
+                        let mut j = 0;
+                        while j < a.len() && a[j] == b[j] {
+                            j += 1;
+                        }
+                        if j == a.len() {
+                            return true;
+                        }
+                    }
+                    i += 1;
+                }
+
+                false
+            }
+
+            assert!(
+                matches!(crate::#font_name.family, iced_widget::core::font::Family::Name(name) if is_family(name)),
+                #message
+            );
+        };
+    }
+}
+
+/// What the parts of a generated module are made of, its options, its icons and what the
+/// options and features decided for the whole module.
+struct Context<'a> {
+    options: &'a Options,
+    icons: &'a [Icon<'a>],
+    sources: &'a [Source],
+    font_path: &'a str,
+    /// The shaping parameter of the functions, empty when the shaping is fixed.
+    shaping_parameter: TokenStream,
+    /// The shaping passed on to other functions, empty when the shaping is fixed.
+    shaping_argument: TokenStream,
+    /// The shaping the widgets are made with.
+    shaping: TokenStream,
+    /// Only the `Icon` enum and its tables, without a function per icon.
+    data_only: bool,
+    /// Whether the module gets the `advanced_text` submodule.
+    advanced_text: bool,
+    /// Whether the widgets record that they were used, see [`usage`].
+    tracks_usage: bool,
+    /// Whether the module is a stub for rust-analyzer, see [`stubs`].
+    stubs: bool,
+}
+
+impl Context<'_> {
+    /// The index of the icon whose name without the affixes is `name`.
+    fn position(&self, name: &str) -> Option<usize> {
+        self.icons.iter().position(|icon| icon.only_name == name)
+    }
+
+    /// The error of an option naming an icon the module doesn't have.
+    fn no_icon(&self, span: Span, name: &str) -> syn::Error {
+        syn::Error::new(
+            span,
+            format!("there is no icon named `{name}` in `{}`", self.font_path),
+        )
+    }
+}
+
+fn generate(input: Input, shaping: Shaping) -> syn::Result<Module> {
+    let Input {
+        font_path,
+        module_name,
+        font_name,
+        doc_link,
+        options,
+    } = input;
+
+    // rust-analyzer only needs the signatures, see `stubs`.
+    let stubs = stubs::enabled();
+    let font_path_str = font_path.value();
+    let font_file = read_font(&font_path)?;
+    let font_data = &font_file.data;
+    let face_index = options.face.unwrap_or(0);
+    let face = parse_face(font_data, face_index, &font_path)?;
+
+    let mut family_checks = family_check(&face, &font_name, &font_path_str);
+    let mut font_bytes = font_file.bytes(&font_name, &font_path);
+
+    let (shaping_parameter, shaping_argument, shaping) = match options.shaping.unwrap_or(shaping) {
+        Shaping::Basic => (quote! {}, quote! {}, quote! { text::Shaping::Basic }),
+        Shaping::Advanced => (quote! {}, quote! {}, quote! { text::Shaping::Advanced }),
+        Shaping::Parameter => (
+            quote! { shaping: text::Shaping },
+            quote! { shaping },
+            quote! { shaping },
+        ),
+    };
+
+    // An instance of a variable font becomes a static font with its own `Font`, the functions
+    // use that one.
+    let instance = options
+        .variation
+        .as_ref()
+        .map(|variation| variation.instance(&face, &quote! { crate::#font_name }))
+        .transpose()?;
+    let instance_face = instance
+        .as_ref()
+        .map(|instance| {
+            Face::parse(&instance.data, 0).map_err(|e| {
+                syn::Error::new(
+                    font_path.span(),
+                    format!("failed to instance the font: {e}"),
+                )
+            })
+        })
+        .transpose()?;
+    // The metrics of the icons are the ones of the instance.
+    let glyphs = match (&instance, &instance_face) {
+        (Some(instance), Some(instance_face)) => cache::glyphs(&instance.data, 0, instance_face),
+        _ => cache::glyphs(font_data, face_index, &face),
+    }
+    .map_err(|e| syn::Error::new(font_path.span(), e))?;
+
+    let (font_const, font, advanced_font) = match &instance {
+        Some(instance) => {
+            let instance_font = &instance.font;
+            let data = proc_macro2::Literal::byte_string(&instance.data);
+            (
+                quote! {
+                    /// The font of the instance the icons are drawn with.
+                    pub const FONT: iced_widget::core::Font = #instance_font;
+                    /// The static font of the instance, for loading [`FONT`].
+                    pub const FONT_BYTES: &[u8] = #data;
+                },
+                quote! { FONT },
+                quote! { super::FONT },
+            )
+        }
+        None => (
+            quote! {},
+            quote! { crate::#font_name },
+            quote! { crate::#font_name },
+        ),
+    };
+
+    if let Some(path) = options.weights.first()
+        && options.variation.is_some()
+    {
+        return Err(syn::Error::new(
+            path.span(),
+            "the weights of a variable font are its instances, pick one with `instance`",
+        ));
+    }
+    let weight_tokens = weights::tokens(&options.weights, &face, &font_name, &font_path_str)?;
+
+    // Apps only using the `Icon` enum don't pay for compiling thousands of functions.
+    let data_only = options.data_only || cfg!(feature = "data_only");
+
+    let previews = |face: &Face<'_>, glyphs: &[cache::Glyph]| {
+        if options.previews && !cfg!(feature = "slim_docs") && !data_only {
+            preview::previews(face, glyphs.iter().map(|glyph| glyph.codepoint))
+        } else {
+            HashMap::new()
+        }
+    };
+
+    // Icons of merged fonts follow the ones of the main font, each drawn with its own font.
+    let mut sources = vec![Source {
+        previews: previews(instance_face.as_ref().unwrap_or(&face), &glyphs),
+        glyphs,
+        font,
+        advanced_font,
+        id: font_id(&face, &font_name),
+    }];
+    for (path, name) in &options.merge {
+        if options.variation.is_some() {
+            return Err(syn::Error::new(
+                path.span(),
+                "fonts can't be merged into an instance of a variable font",
+            ));
+        }
+
+        let font_file = read_font(path)?;
+        let face = parse_face(&font_file.data, 0, path)?;
+        let glyphs = cache::glyphs(&font_file.data, 0, &face)
+            .map_err(|e| syn::Error::new(path.span(), e))?;
+
+        family_checks.extend(family_check(&face, name, &path.value()));
+        font_bytes.extend(font_file.bytes(name, path));
+        sources.push(Source {
+            previews: previews(&face, &glyphs),
+            glyphs,
+            font: quote! { crate::#name },
+            advanced_font: quote! { crate::#name },
+            id: font_id(&face, name),
+        });
+    }
+
+    let Icons {
+        icons,
+        skipped,
+        duplicates,
+    } = icons::collect(&options, &sources, &font_path_str)?;
+
+    let monochrome_tokens = match options.monochrome.as_ref().filter(|option| option.value) {
+        Some(option) => monochrome(
+            option,
+            &face,
+            &font_name,
+            &options,
+            &shaping_parameter,
+            &shaping,
+        )?,
+        None => quote! {},
+    };
+
+    let context = Context {
+        options: &options,
+        icons: &icons,
+        sources: &sources,
+        font_path: &font_path_str,
+        shaping_parameter,
+        shaping_argument,
+        shaping,
+        data_only,
+        // The option decides for the module, features of proc-macro crates don't reliably follow
+        // the crate invoking the macro.
+        advanced_text: options
+            .advanced_text
+            .unwrap_or(cfg!(feature = "advanced_text")),
+        // Only widgets can be tracked, the other outputs come from `const fn`s.
+        tracks_usage: cfg!(feature = "track_usage") && matches!(options.output, Output::Widget),
+        stubs,
+    };
+
+    let (deprecated_functions, deprecated_advanced_functions) = deprecated::tokens(&context)?;
+    let icon_enum = icon_enum::tokens(&context)?;
+    let conversions = widgets::conversions(&context);
+    let stubs_tokens = stubs::tokens(stubs);
+
+    let changes_tokens = changes::tokens(options.changes.as_ref())?;
+    let snapshot_tokens = match &options.snapshot {
+        Some(path) => snapshot::check(path, &icons)?,
+        None => quote! {},
+    };
+
+    // With `slim_docs` the per-icon doc strings are never built, which saves a lot of tokens for
+    // the big fonts.
+    let (mut functions, mut advanced_functions) = if data_only {
+        Default::default()
+    } else {
+        functions::Functions {
+            output: options.output,
+            docs: !cfg!(feature = "slim_docs") && !stubs,
+            stubs,
+            link: doc_link.as_ref(),
+            track_usage: context.tracks_usage,
+            advanced_text: context.advanced_text,
+            shaping_parameter: context.shaping_parameter.to_string(),
+        }
+        .tokens(&icons)
+    };
+    functions.extend(deprecated_functions);
+    advanced_functions.extend(deprecated_advanced_functions);
+    let advanced_text_tokens = functions::advanced_text(&context, advanced_functions);
+
+    let count_lit = LitInt::new(&icons.len().to_string(), Span::call_site());
+    let has_colors = icons.iter().any(|icon| icon.color);
+    let skipped_glyphs = skipped
+        .iter()
+        .map(|(c, raw_name)| quote! { (#c, #raw_name) });
+    let renamed_duplicates = duplicates
+        .iter()
+        .map(|(original, renamed)| quote! { (#original, #renamed) });
+    let doc = format!("A module with a function for every icon in {module_name}'s font.");
+    let naming_doc = match options.digits {
+        Digits::Spell => "Digits in icon names are spelled out, so `4k` becomes `fourk`.",
+        Digits::Keep => {
+            "Digits in icon names are kept, names starting with one get a leading underscore, \
+             so `4k` becomes `_4k`."
+        }
+    };
+    // Only widgets need anything from iced.
+    let widgets = widgets::tokens(&context);
+    let usage_tokens = usage::tokens(&context);
+
+    let min_size = proc_macro2::Literal::f32_unsuffixed(match &options.min_size {
+        Some(size) => size.base10_parse::<u16>()?.into(),
+        None => 0.0,
+    });
+
+    let pua_tokens = pua::tokens(&icons);
+    let submodules = submodules::tokens(&context)?;
+    let shortcode_tokens = shortcodes::tokens();
+    let number_badge_tokens = templates::number_badges(&context)?;
+    let direction_tokens = templates::directions(&context)?;
+
+    // The filled and outlined versions of icons and the icons of the roles, for `IconStyle`,
+    // `Role` and the smaller sets of roles in `iced_fonts`.
+    let mut role_tokens = style::tokens(&icons);
+    role_tokens.extend(roles::sets(&context)?);
+    role_tokens.extend(roles::tokens(&context)?);
+
+    let spinner_tokens = spinners::tokens(&context)?;
+
+    // Icons of a module that isn't public are only used where the code asks for them, the rest
+    // would each be a dead code warning.
+    let (visibility, allow_dead_code) = match &options.visibility {
+        None | Some(Visibility::Public(_)) => (quote! { pub }, quote! {}),
+        Some(visibility) => (quote! { #visibility }, quote! { #[allow(dead_code)] }),
+    };
+
+    let contents = quote! {
+        #widgets
+
+        /// The amount of icons in the font.
+        pub const COUNT: usize = #count_lit;
+
+        /// Icons whose name was already taken by another icon, as pairs of the original
+        /// name and the name of the function generated for it.
+        pub const DUPLICATES: &[(&str, &str)] = &[#(#renamed_duplicates),*];
+
+        /// Glyphs left without a function because no identifier can be made of their name, like
+        /// `.notdef` or names of only punctuation, as pairs of the character and the name. Give
+        /// them a name with the `rename` option.
+        pub const SKIPPED: &[(char, &str)] = &[#(#skipped_glyphs),*];
+
+        /// Whether some icons have colors of their own, like the glyphs of emoji fonts with
+        /// `COLR`, `CBDT`, `sbix` or `SVG` tables. `Icon::is_color` tells which.
+        pub const COLOR: bool = #has_colors;
+
+        #family_checks
+
+        #font_const
+
+        #font_bytes
+
+        #weight_tokens
+
+        #monochrome_tokens
+
+        /// The smallest size in pixels the font renders well at, fonts with fine details blur
+        /// below it. `Icon::sized` raises smaller sizes to it.
+        pub const MIN_SIZE: f32 = #min_size;
+
+        #pua_tokens
+
+        #icon_enum
+
+        #conversions
+
+        #functions
+
+        #submodules
+
+        #shortcode_tokens
+
+        #number_badge_tokens
+
+        #direction_tokens
+
+        #role_tokens
+
+        #spinner_tokens
+
+        #usage_tokens
+
+        #advanced_text_tokens
+
+        #snapshot_tokens
+        #changes_tokens
+        #stubs_tokens
+    };
+
+    Ok(Module {
+        manifest: icons.iter().map(manifest::Icon::from).collect(),
+        skipped,
+        docs: [doc.as_str(), "", naming_doc].map(String::from),
+        allow_dead_code,
+        visibility,
+        name: module_name,
+        contents,
+    })
+}
+
+/// The `monochrome` submodule of the `monochrome` option, with the font drawing the color glyphs
+/// of `face` in the text color, see [`outlines::monochrome`].
+fn monochrome(
+    option: &syn::LitBool,
+    face: &Face<'_>,
+    font_name: &Ident,
+    options: &Options,
+    shaping_parameter: &proc_macro2::TokenStream,
+    shaping: &proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let error = |message: &str| syn::Error::new(option.span(), message);
+    if face.tables().colr.is_none() {
+        return Err(error(
+            "only `COLR` fonts can be drawn in one color, the glyphs of `CBDT`, `sbix` and `SVG` \
+             fonts are images",
+        ));
+    }
+    if !options.merge.is_empty() {
+        return Err(error(
+            "a module with merged fonts can't have a monochrome font",
+        ));
+    }
+    if options.variation.is_some() {
+        return Err(error(
+            "an instance of a variable font can't have a monochrome font",
+        ));
+    }
+
+    let family = format!(
+        "{} Monochrome",
+        font_families(face)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| font_name.to_string())
+    );
+    let data = outlines::monochrome(face, &family)
+        .map_err(|e| error(&format!("failed to draw the font in one color: {e}")))?;
+    let data = proc_macro2::Literal::byte_string(&data);
+
+    let text = if matches!(options.output, Output::Widget) {
+        quote! {
+            /// The icon in the text color, drawn with [`FONT`].
+            pub fn text<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+                icon: Icon,
+                #shaping_parameter
+            ) -> Text<'a, Theme, Renderer> {
+                make_icon(icon.char(), FONT, #shaping)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        /// The icons in the text color, for renderers without color glyphs and places where
+        /// colors don't fit, like disabled buttons.
+        pub mod monochrome {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// The font drawing every icon with the outlines of its layers.
+            pub const FONT: iced_widget::core::Font = iced_widget::core::Font {
+                family: iced_widget::core::font::Family::Name(#family),
+                ..crate::#font_name
+            };
+            /// The monochrome font, for loading [`FONT`].
+            pub const FONT_BYTES: &[u8] = #data;
+
+            #text
+        }
+    })
+}
+
+/// A generated module, which becomes a `mod` item for the macros or the contents of a file of its
+/// own.
+struct Module {
+    /// The icons for the manifest, see [`manifest`].
+    manifest: Vec<manifest::Icon>,
+    /// The glyphs without a function, see `SKIPPED`.
+    skipped: Vec<(char, String)>,
+    docs: [String; 3],
+    allow_dead_code: proc_macro2::TokenStream,
+    visibility: proc_macro2::TokenStream,
+    name: Ident,
+    contents: proc_macro2::TokenStream,
+}
+
+impl Module {
+    /// The manifest of the module's icons as JSON.
+    fn manifest(&self) -> String {
+        manifest::json(&self.name.to_string(), &self.manifest)
+    }
+
+    /// A warning for every glyph without a function, which the module only lists in `SKIPPED`.
+    fn warnings(&self) -> Vec<String> {
+        self.skipped
+            .iter()
+            .map(|(c, raw_name)| {
+                format!(
+                    "`{}` has no function for the glyph `{raw_name}` at U+{:04X}, no identifier \
+                     can be made of its name, give it one with the `rename` option",
+                    self.name,
+                    u32::from(*c)
+                )
+            })
+            .collect()
+    }
+
+    fn item(self) -> proc_macro2::TokenStream {
+        let Self {
+            docs,
+            allow_dead_code,
+            visibility,
+            name,
+            contents,
+            ..
+        } = self;
+
+        quote! {
+            #(#[doc = #docs])*
+            #allow_dead_code
+            #visibility mod #name {
+                #contents
+            }
+        }
+    }
+
+    /// The contents with the docs as inner attributes, for a file declared with `mod name;`.
+    fn file(self) -> proc_macro2::TokenStream {
+        let Self { docs, contents, .. } = self;
+
+        quote! {
+            #(#![doc = #docs])*
+
+            #contents
+        }
+    }
+}
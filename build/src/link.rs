@@ -0,0 +1,94 @@
+//! The optional documentation link of every icon, a URL template like
+//! `"https://www.nerdfonts.com/cheat-sheet?q={codepoint:x}"`.
+//!
+//! * `{name}` is the generated function name,
+//! * `{raw}` the glyph name of the font (or of the `names` file),
+//! * `{codepoint:x}` and `{codepoint:X}` the codepoint in lower and upper case hex.
+//!
+//! `{{` and `}}` stand for literal braces. A link without placeholders gets `/{raw}` appended, like
+//! `"https://icons.getbootstrap.com/icons"` always did.
+
+use syn::LitStr;
+
+/// A parsed link template.
+pub(crate) struct Template(Vec<Part>);
+
+enum Part {
+    Text(String),
+    Name,
+    Raw,
+    Codepoint { upper: bool },
+}
+
+impl Template {
+    pub(crate) fn parse(template: &LitStr) -> syn::Result<Self> {
+        let value = template.value();
+        let error = |message: String| syn::Error::new(template.span(), message);
+
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut chars = value.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let (placeholder, rest) = chars.as_str().split_once('}').ok_or_else(|| {
+                        error("unclosed `{` in the link, write `{{` for a brace".into())
+                    })?;
+                    let part = match placeholder {
+                        "name" => Part::Name,
+                        "raw" => Part::Raw,
+                        "codepoint:x" => Part::Codepoint { upper: false },
+                        "codepoint:X" => Part::Codepoint { upper: true },
+                        _ => {
+                            return Err(error(format!(
+                                "unknown placeholder `{{{placeholder}}}` in the link, expected `{{name}}`, `{{raw}}` or `{{codepoint:x}}`"
+                            )));
+                        }
+                    };
+                    parts.push(Part::Text(std::mem::take(&mut text)));
+                    parts.push(part);
+                    chars = rest.chars();
+                }
+                '}' => {
+                    return Err(error(
+                        "unmatched `}` in the link, write `}}` for a brace".into(),
+                    ));
+                }
+                c => text.push(c),
+            }
+        }
+
+        if parts.is_empty() {
+            text.push('/');
+            parts.push(Part::Text(text));
+            parts.push(Part::Raw);
+        } else {
+            parts.push(Part::Text(text));
+        }
+
+        Ok(Self(parts))
+    }
+
+    /// The link of an icon, `None` when it needs the glyph name and the glyph has none.
+    pub(crate) fn render(&self, name: &str, raw: Option<&str>, c: char) -> Option<String> {
+        let mut link = String::new();
+        for part in &self.0 {
+            match part {
+                Part::Text(text) => link.push_str(text),
+                Part::Name => link.push_str(name),
+                Part::Raw => link.push_str(raw?),
+                Part::Codepoint { upper: false } => link.push_str(&format!("{:x}", u32::from(c))),
+                Part::Codepoint { upper: true } => link.push_str(&format!("{:X}", u32::from(c))),
+            }
+        }
+        Some(link)
+    }
+}
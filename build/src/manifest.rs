@@ -0,0 +1,93 @@
+//! The manifest of a module, its icons as JSON for tools outside of Rust like design systems and
+//! documentation sites.
+//!
+//! ```json
+//! {
+//!   "module": "lucide",
+//!   "icons": [
+//!     {
+//!       "name": "a-arrow-down",
+//!       "function": "a_arrow_down",
+//!       "codepoint": "e58a",
+//!       "categories": ["text"],
+//!       "aliases": ["letter", "font size"]
+//!     }
+//!   ]
+//! }
+//! ```
+
+use std::fmt::Write;
+
+use crate::icons;
+
+/// An icon of the manifest.
+pub(crate) struct Icon {
+    /// The glyph name from the font or the `names` file, the function name for unnamed glyphs.
+    pub(crate) name: String,
+    pub(crate) function: String,
+    pub(crate) codepoint: char,
+    pub(crate) categories: Vec<String>,
+    pub(crate) aliases: Vec<String>,
+}
+
+impl From<&icons::Icon<'_>> for Icon {
+    fn from(icon: &icons::Icon<'_>) -> Self {
+        Self {
+            name: icon.raw_name.unwrap_or(&icon.name).to_string(),
+            function: icon.name.clone(),
+            codepoint: icon.codepoint,
+            categories: icon.categories.clone(),
+            aliases: icon.aliases.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// The manifest of the module `module` as pretty printed JSON.
+pub(crate) fn json(module: &str, icons: &[Icon]) -> String {
+    let mut json = format!("{{\n  \"module\": {},\n  \"icons\": [", string(module));
+
+    for (i, icon) in icons.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        let _ = write!(
+            json,
+            "{separator}\n    {{\n      \"name\": {},\n      \"function\": {},\n      \
+             \"codepoint\": \"{:x}\",\n      \"categories\": {},\n      \"aliases\": {}\n    }}",
+            string(&icon.name),
+            string(&icon.function),
+            icon.codepoint as u32,
+            list(&icon.categories),
+            list(&icon.aliases),
+        );
+    }
+
+    json.push_str(if icons.is_empty() {
+        "]\n}\n"
+    } else {
+        "\n  ]\n}\n"
+    });
+    json
+}
+
+fn list(strings: &[String]) -> String {
+    let strings: Vec<String> = strings.iter().map(|s| string(s)).collect();
+    format!("[{}]", strings.join(", "))
+}
+
+/// A JSON string literal of `s`.
+fn string(s: &str) -> String {
+    let mut literal = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(literal, "\\u{:04x}", c as u32);
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
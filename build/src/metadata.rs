@@ -0,0 +1,426 @@
+//! Metadata files published next to the fonts. Plenty of icon fonts have poor glyph names but
+//! good metadata.
+//!
+//! The `names` option takes icon names from
+//! * Material's `.codepoints` files, a line of `name codepoint` per icon,
+//! * JSON objects with an entry per icon, whose `unicode` or `code` field is the codepoint, like
+//!   Font Awesome's `icons.json` and Nerd Fonts' `glyphnames.json`.
+//!
+//! The `aliases` option takes search terms from JSON objects with an entry per icon, either a list
+//! of tags like Lucide's `tags.json`, or an object with `tags`, `search.terms` or `aliases.names`
+//! like Font Awesome's `icons.json`. The `categories` option reads the same kind of file, with
+//! a list of categories or an object with `categories` per icon, like Lucide's `categories.json`.
+
+use std::collections::HashMap;
+
+use syn::LitStr;
+
+use crate::resolve_path;
+
+/// Reads the metadata file at `path` into the name of every codepoint. When several names share
+/// a codepoint the first one wins.
+pub(crate) fn read(path: &LitStr) -> syn::Result<HashMap<char, String>> {
+    let path_str = path.value();
+    let error = |message: String| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to read names from `{path_str}`: {message}"),
+        )
+    };
+
+    let contents =
+        std::fs::read_to_string(resolve_path(&path_str)).map_err(|e| error(e.to_string()))?;
+
+    let entries = if path_str.ends_with(".json") {
+        from_json(&contents).map_err(error)?
+    } else {
+        from_codepoints(&contents).map_err(error)?
+    };
+
+    let mut names = HashMap::new();
+    for (name, codepoint) in entries {
+        names.entry(codepoint).or_insert(name);
+    }
+
+    Ok(names)
+}
+
+/// Reads the search terms of every icon, by the icon's raw name, from the JSON file at `path`.
+pub(crate) fn read_tags(path: &LitStr) -> syn::Result<HashMap<String, Vec<String>>> {
+    read_lists(
+        path,
+        "aliases",
+        &[&["tags"], &["search", "terms"], &["aliases", "names"]],
+    )
+}
+
+/// Reads the categories of every icon, by the icon's raw name, from the JSON file at `path`.
+pub(crate) fn read_categories(path: &LitStr) -> syn::Result<HashMap<String, Vec<String>>> {
+    read_lists(path, "categories", &[&["categories"]])
+}
+
+/// Reads a list of strings per icon from a JSON object with an entry per icon. An entry is either
+/// the list itself, or an object with the lists at `fields`.
+fn read_lists(
+    path: &LitStr,
+    what: &str,
+    fields: &[&[&str]],
+) -> syn::Result<HashMap<String, Vec<String>>> {
+    let path_str = path.value();
+    let error = |message: String| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to read {what} from `{path_str}`: {message}"),
+        )
+    };
+
+    let contents =
+        std::fs::read_to_string(resolve_path(&path_str)).map_err(|e| error(e.to_string()))?;
+    let Json::Object(icons) = Parser::new(&contents).parse_document().map_err(error)? else {
+        return Err(error(
+            "expected a JSON object with an entry per icon".into(),
+        ));
+    };
+
+    Ok(icons
+        .into_iter()
+        .map(|(name, icon)| {
+            let mut list = Vec::new();
+            match icon {
+                Json::Array(_) => strings(&icon, &mut list),
+                Json::Object(_) => {
+                    for path in fields {
+                        if let Some(value) = icon.get(path) {
+                            strings(value, &mut list);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            (name, list)
+        })
+        .collect())
+}
+
+/// Collects the strings of an array.
+fn strings(array: &Json, into: &mut Vec<String>) {
+    if let Json::Array(values) = array {
+        into.extend(values.iter().filter_map(|value| match value {
+            Json::String(string) => Some(string.clone()),
+            _ => None,
+        }));
+    }
+}
+
+fn parse_codepoint(hex: &str) -> Option<char> {
+    char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+}
+
+fn from_codepoints(contents: &str) -> Result<Vec<(String, char)>, String> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            line.split_once(' ')
+                .and_then(|(name, hex)| Some((name.to_string(), parse_codepoint(hex.trim())?)))
+                .ok_or_else(|| format!("line {} isn't `name codepoint`", number + 1))
+        })
+        .collect()
+}
+
+fn from_json(contents: &str) -> Result<Vec<(String, char)>, String> {
+    let Json::Object(icons) = Parser::new(contents).parse_document()? else {
+        return Err("expected a JSON object with an entry per icon".into());
+    };
+
+    // Entries without a codepoint, like the `METADATA` entry of Nerd Fonts, are skipped.
+    Ok(icons
+        .into_iter()
+        .filter_map(|(name, icon)| {
+            let Json::Object(fields) = icon else {
+                return None;
+            };
+            fields.into_iter().find_map(|(key, value)| match value {
+                Json::String(hex) if key == "unicode" || key == "code" => {
+                    Some((name.clone(), parse_codepoint(&hex)?))
+                }
+                _ => None,
+            })
+        })
+        .collect())
+}
+
+/// Just enough of JSON to walk the metadata files, only strings, arrays and objects keep their
+/// contents.
+enum Json {
+    Null,
+    Bool,
+    Number,
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Follows the keys of `path` through nested objects.
+    fn get(&self, path: &[&str]) -> Option<&Json> {
+        path.iter().try_fold(self, |json, key| match json {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        })
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(contents: &'a str) -> Self {
+        Self {
+            chars: contents.char_indices().peekable(),
+        }
+    }
+
+    fn parse_document(&mut self) -> Result<Json, String> {
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+        match self.chars.next() {
+            None => Ok(value),
+            Some((at, _)) => Err(format!("unexpected trailing data at byte {at}")),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((at, c)) => Err(format!("expected `{expected}` at byte {at}, found `{c}`")),
+            None => Err(format!("expected `{expected}`, found the end of the file")),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        let Some(&(at, c)) = self.chars.peek() else {
+            return Err("unexpected end of the file".into());
+        };
+
+        match c {
+            '{' => self.parse_object(),
+            '[' => self.parse_array(),
+            '"' => self.parse_string().map(Json::String),
+            't' => self.parse_word("true").map(|()| Json::Bool),
+            'f' => self.parse_word("false").map(|()| Json::Bool),
+            'n' => self.parse_word("null").map(|()| Json::Null),
+            '-' | '0'..='9' => {
+                while self
+                    .chars
+                    .next_if(|(_, c)| matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9'))
+                    .is_some()
+                {}
+                Ok(Json::Number)
+            }
+            _ => Err(format!("unexpected `{c}` at byte {at}")),
+        }
+    }
+
+    fn parse_word(&mut self, word: &str) -> Result<(), String> {
+        for expected in word.chars() {
+            match self.chars.next() {
+                Some((_, c)) if c == expected => {}
+                _ => return Err(format!("expected `{word}`")),
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_object(&mut self) -> Result<Json, String> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, c)| *c == '}').is_some() {
+            return Ok(Json::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.expect(':')?;
+            fields.push((key, self.parse_value()?));
+
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => {}
+                Some((_, '}')) => return Ok(Json::Object(fields)),
+                _ => return Err("expected `,` or `}` in an object".into()),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Json, String> {
+        self.expect('[')?;
+
+        let mut values = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, c)| *c == ']').is_some() {
+            return Ok(Json::Array(values));
+        }
+
+        loop {
+            values.push(self.parse_value()?);
+
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => {}
+                Some((_, ']')) => return Ok(Json::Array(values)),
+                _ => return Err("expected `,` or `]` in an array".into()),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut string = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(string),
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, 'u')) => {
+                        let high = self.parse_unit()?;
+                        // Characters outside the BMP are escaped as a surrogate pair.
+                        let c = if (0xD800..0xDC00).contains(&high) {
+                            self.parse_word("\\u")?;
+                            let low = self.parse_unit()?;
+                            char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low & 0x3FF))
+                        } else {
+                            char::from_u32(high)
+                        };
+                        string.push(c.unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    Some((_, c)) => string.push(match c {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        c => c,
+                    }),
+                    None => return Err("unterminated string".into()),
+                },
+                Some((_, c)) => string.push(c),
+                None => return Err("unterminated string".into()),
+            }
+        }
+    }
+
+    /// The four hex digits of a `\u` escape.
+    fn parse_unit(&mut self) -> Result<u32, String> {
+        let hex: String = (0..4)
+            .filter_map(|_| self.chars.next())
+            .map(|(_, c)| c)
+            .collect();
+        u32::from_str_radix(&hex, 16).map_err(|_| "invalid `\\u` escape".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Json, Parser, from_codepoints, from_json, strings};
+
+    fn names(json: &str) -> Vec<(String, char)> {
+        from_json(json).unwrap()
+    }
+
+    #[test]
+    fn codepoints_are_read_from_unicode_or_code_fields() {
+        // Font Awesome's `icons.json` and Nerd Fonts' `glyphnames.json`.
+        assert_eq!(
+            names(r#"{"house": {"label": "House", "unicode": "f015", "free": ["solid"]}}"#),
+            [("house".to_string(), '\u{f015}')]
+        );
+        assert_eq!(
+            names(r#"{"nf-md-home": {"char": "󰋜", "code": "f02dc"}}"#),
+            [("nf-md-home".to_string(), '\u{f02dc}')]
+        );
+    }
+
+    #[test]
+    fn entries_without_a_codepoint_are_skipped() {
+        assert_eq!(
+            names(
+                r#"{
+                    "METADATA": {"website": "https://nerdfonts.com", "version": "3.2.1"},
+                    "nf-dev-git": {"char": "", "code": "e702"},
+                    "broken": {"code": "not hex"},
+                    "list": ["e000"],
+                    "number": 57344
+                }"#
+            ),
+            [("nf-dev-git".to_string(), '\u{e702}')]
+        );
+    }
+
+    #[test]
+    fn escapes_are_decoded() {
+        let string = Parser::new(r#""\uD83D\uDE00 \u00e9 \"quoted\" \\ \/ \n\t""#)
+            .parse_string()
+            .unwrap();
+        assert_eq!(string, "\u{1f600} é \"quoted\" \\ / \n\t");
+
+        // A high surrogate has to be followed by a low one.
+        assert!(Parser::new(r#""\uD83D""#).parse_string().is_err());
+        assert!(Parser::new(r#""\u00""#).parse_string().is_err());
+    }
+
+    #[test]
+    fn trailing_data_is_an_error() {
+        assert_eq!(
+            Parser::new("{} {}").parse_document().err().as_deref(),
+            Some("unexpected trailing data at byte 3")
+        );
+        assert!(Parser::new(" {} \n").parse_document().is_ok());
+        assert!(from_json(r#"{"a": {"code": "e000"}"#).is_err());
+        assert!(from_json(r#"["e000"]"#).is_err());
+    }
+
+    #[test]
+    fn lists_are_found_in_nested_objects() {
+        let icon = Parser::new(r#"{"search": {"terms": ["home", 1, "main"]}, "tags": null}"#)
+            .parse_document()
+            .unwrap();
+
+        let mut terms = Vec::new();
+        strings(icon.get(&["search", "terms"]).unwrap(), &mut terms);
+        assert_eq!(terms, ["home", "main"]);
+        assert!(icon.get(&["search", "missing"]).is_none());
+        assert!(matches!(icon.get(&["tags"]), Some(Json::Null)));
+    }
+
+    #[test]
+    fn codepoints_files_have_a_name_and_codepoint_per_line() {
+        assert_eq!(
+            from_codepoints("home e88a\n\nsearch e8b6\n").unwrap(),
+            [
+                ("home".to_string(), '\u{e88a}'),
+                ("search".to_string(), '\u{e8b6}'),
+            ]
+        );
+        assert_eq!(
+            from_codepoints("home e88a\nsearch\n").unwrap_err(),
+            "line 2 isn't `name codepoint`"
+        );
+        assert_eq!(
+            from_codepoints("home zzzz").unwrap_err(),
+            "line 1 isn't `name codepoint`"
+        );
+    }
+}
@@ -0,0 +1,676 @@
+//! TrueType fonts written back from the outlines `ttf-parser` reads of a font, for the static
+//! fonts of the instances of variable fonts and the monochrome fonts of color fonts.
+//!
+//! Instances are read at the coordinates of the instance, which applies the deltas of `gvar` and
+//! `HVAR`. Monochrome fonts draw the color glyphs with the outlines of their `COLR` layers. The
+//! tables of the variations go, and so does the hinting, which was made for the original outlines.
+
+use ttf_parser::colr::{ClipBox, CompositeMode, Paint, Painter};
+use ttf_parser::{Face, GlyphId, OutlineBuilder, RgbaColor, Tag, Transform};
+
+use crate::sfnt;
+
+/// The tables every font written from outlines leaves out, the variations and the hinting.
+const DROPPED: [&[u8; 4]; 15] = [
+    b"fvar", b"gvar", b"avar", b"cvar", b"HVAR", b"VVAR", b"MVAR", b"STAT", b"cvt ", b"fpgm",
+    b"prep", b"hdmx", b"VDMX", b"LTSH", b"DSIG",
+];
+
+/// The tables of the colors monochrome fonts leave out too.
+const COLORS: [&[u8; 4]; 6] = [b"COLR", b"CPAL", b"SVG ", b"CBDT", b"CBLC", b"sbix"];
+
+/// The font `face` at its variation coordinates, with the family `family` and the weight and
+/// width classes of the `wght` and `wdth` axes.
+pub(crate) fn instance(
+    face: &Face<'_>,
+    family: &str,
+    weight_class: Option<u16>,
+    width_class: Option<u16>,
+) -> Result<Vec<u8>, String> {
+    write(
+        face,
+        family,
+        weight_class,
+        width_class,
+        &[],
+        |glyph, outline| {
+            face.outline_glyph(glyph, outline);
+            false
+        },
+    )
+}
+
+/// The color font `face` in one color, with the family `family`. Glyphs keep their own outline,
+/// the one renderers without colors draw, and color glyphs without one get the outlines of all of
+/// their `COLR` layers, which renderers fill as one shape.
+pub(crate) fn monochrome(face: &Face<'_>, family: &str) -> Result<Vec<u8>, String> {
+    write(face, family, None, None, &COLORS, |glyph, outline| {
+        if face.outline_glyph(glyph, outline).is_some() || !face.is_color_glyph(glyph) {
+            return false;
+        }
+
+        let mut layers = Layers {
+            face,
+            outline,
+            transforms: vec![Transform::default()],
+        };
+        face.paint_color_glyph(glyph, 0, RgbaColor::new(0, 0, 0, 255), &mut layers);
+        true
+    })
+}
+
+/// The font `face` with the outlines `outline` draws of its glyphs, which returns whether the
+/// contours overlap, with the family `family`, the weight and width classes of `OS/2` unless
+/// `None`, and without the `dropped` tables.
+fn write(
+    face: &Face<'_>,
+    family: &str,
+    weight_class: Option<u16>,
+    width_class: Option<u16>,
+    dropped: &[&[u8; 4]],
+    outline: impl Fn(GlyphId, &mut Outline) -> bool,
+) -> Result<Vec<u8>, String> {
+    let raw = face.raw_face();
+    let table = |tag: &[u8; 4]| {
+        raw.table(Tag::from_bytes(tag))
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| format!("the font has no `{}`", String::from_utf8_lossy(tag)))
+    };
+
+    let mut glyf = Vec::new();
+    let mut loca = Vec::new();
+    let mut hmtx = Vec::new();
+    let (mut max_points, mut max_contours) = (0_u16, 0_u16);
+    // Of the glyphs with an outline, `x_min`, `y_min`, `x_max` and `y_max` of all of them and
+    // the smallest side bearings.
+    let mut bounds: Option<[i16; 4]> = None;
+    let (mut min_left, mut min_right, mut max_extent) = (i16::MAX, i16::MAX, i16::MIN);
+    let mut max_advance = 0_u16;
+
+    for glyph in (0..face.number_of_glyphs()).map(GlyphId) {
+        loca.extend(
+            u32::try_from(glyf.len())
+                .map_err(|e| e.to_string())?
+                .to_be_bytes(),
+        );
+
+        let mut contours = Outline::default();
+        let overlap = outline(glyph, &mut contours);
+        let outline = contours;
+        if outline.cubic {
+            return Err(format!("the glyph {} isn't a TrueType outline", glyph.0));
+        }
+        let advance = face.glyph_hor_advance(glyph).unwrap_or_default();
+        max_advance = max_advance.max(advance);
+
+        let points = outline.contours.concat();
+        let x_min = if points.is_empty() {
+            0
+        } else {
+            let out_of_range = || format!("the glyph {} is out of range", glyph.0);
+            let bbox = sfnt::bounding_box(&points).ok_or_else(out_of_range)?;
+            let contours = i16::try_from(outline.contours.len()).map_err(|_| out_of_range())?;
+
+            glyf.extend(contours.to_be_bytes());
+            glyf.extend(bbox.iter().flat_map(|value| value.to_be_bytes()));
+            let mut end = 0;
+            for contour in &outline.contours {
+                end += contour.len();
+                let end = u16::try_from(end - 1).map_err(|_| out_of_range())?;
+                glyf.extend(end.to_be_bytes());
+            }
+            // No instructions.
+            glyf.extend(0_u16.to_be_bytes());
+            sfnt::simple_points(&mut glyf, &points, overlap).map_err(|_| out_of_range())?;
+            glyf.resize(glyf.len().next_multiple_of(4), 0);
+
+            max_points = max_points.max(u16::try_from(points.len()).map_err(|_| out_of_range())?);
+            max_contours = max_contours.max(contours as u16);
+            bounds = Some(bounds.map_or(bbox, |bounds| {
+                [
+                    bounds[0].min(bbox[0]),
+                    bounds[1].min(bbox[1]),
+                    bounds[2].max(bbox[2]),
+                    bounds[3].max(bbox[3]),
+                ]
+            }));
+            min_left = min_left.min(bbox[0]);
+            min_right = min_right.min((i32::from(advance) - i32::from(bbox[2])) as i16);
+            max_extent = max_extent.max(bbox[2]);
+            bbox[0]
+        };
+
+        hmtx.extend(advance.to_be_bytes());
+        hmtx.extend(x_min.to_be_bytes());
+    }
+    loca.extend(
+        u32::try_from(glyf.len())
+            .map_err(|e| e.to_string())?
+            .to_be_bytes(),
+    );
+
+    let mut head = table(b"head")?;
+    let bounds = bounds.unwrap_or_default();
+    set(&mut head, 36, &bounds.map(i16::to_be_bytes).concat())?;
+    // The long format of `loca`.
+    set(&mut head, 50, &1_u16.to_be_bytes())?;
+
+    let mut hhea = table(b"hhea")?;
+    if bounds != [0; 4] {
+        set(&mut hhea, 12, &min_left.to_be_bytes())?;
+        set(&mut hhea, 14, &min_right.to_be_bytes())?;
+        set(&mut hhea, 16, &max_extent.to_be_bytes())?;
+    }
+    set(&mut hhea, 10, &max_advance.to_be_bytes())?;
+    set(&mut hhea, 34, &face.number_of_glyphs().to_be_bytes())?;
+
+    let mut maxp = table(b"maxp")?;
+    // Version 1 counts what the glyphs are made of, they are all simple now.
+    if maxp.len() >= 32 {
+        set(&mut maxp, 6, &max_points.to_be_bytes())?;
+        set(&mut maxp, 8, &max_contours.to_be_bytes())?;
+        set(&mut maxp, 10, &[0; 4])?;
+        set(&mut maxp, 26, &[0; 6])?;
+    }
+
+    let mut tables = vec![
+        (*b"glyf", glyf),
+        (*b"loca", loca),
+        (*b"hmtx", hmtx),
+        (*b"head", head),
+        (*b"hhea", hhea),
+        (*b"maxp", maxp),
+        (*b"name", name(&table(b"name")?, family)?),
+    ];
+    if let Ok(mut os2) = table(b"OS/2") {
+        if let Some(weight_class) = weight_class {
+            set(&mut os2, 4, &weight_class.to_be_bytes())?;
+        }
+        if let Some(width_class) = width_class {
+            set(&mut os2, 6, &width_class.to_be_bytes())?;
+        }
+        tables.push((*b"OS/2", os2));
+    }
+
+    for record in raw.table_records {
+        let tag = record.tag.to_bytes();
+        if !DROPPED.contains(&&tag)
+            && !dropped.contains(&&tag)
+            && !tables.iter().any(|(written, _)| *written == tag)
+        {
+            tables.push((tag, table(&tag)?));
+        }
+    }
+
+    Ok(sfnt::write(0x0001_0000, tables))
+}
+
+/// Overwrites the bytes of `table` at `offset`.
+fn set(table: &mut [u8], offset: usize, bytes: &[u8]) -> Result<(), String> {
+    table
+        .get_mut(offset..offset + bytes.len())
+        .ok_or_else(|| String::from("a table is truncated"))?
+        .copy_from_slice(bytes);
+    Ok(())
+}
+
+/// The `name` table with the family, full and PostScript names of `family`.
+fn name(table: &[u8], family: &str) -> Result<Vec<u8>, String> {
+    let read = |offset: usize| {
+        table
+            .get(offset..offset + 2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+            .ok_or_else(|| String::from("`name` is truncated"))
+    };
+    let version = read(0)?;
+    let count = usize::from(read(2)?);
+    let storage = usize::from(read(4)?);
+    let string = |length: u16, offset: u16| {
+        let start = storage + usize::from(offset);
+        table
+            .get(start..start + usize::from(length))
+            .ok_or_else(|| String::from("`name` is truncated"))
+    };
+    let postscript: String = family
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .take(63)
+        .collect();
+
+    let mut records = Vec::new();
+    let mut strings: Vec<u8> = Vec::new();
+    let mut push = |records: &mut Vec<u8>, fields: [u16; 4], value: &[u8]| -> Result<(), String> {
+        let too_long = |_| String::from("`name` is too long");
+        records.extend(fields.iter().flat_map(|field| field.to_be_bytes()));
+        records.extend(u16::try_from(value.len()).map_err(too_long)?.to_be_bytes());
+        records.extend(
+            u16::try_from(strings.len())
+                .map_err(too_long)?
+                .to_be_bytes(),
+        );
+        strings.extend(value);
+        Ok(())
+    };
+
+    for index in 0..count {
+        let record = 6 + index * 12;
+        let [platform, encoding, language, name_id, length, offset] =
+            [0, 2, 4, 6, 8, 10].map(|field| read(record + field));
+        let (platform, encoding, language, name_id) = (platform?, encoding?, language?, name_id?);
+
+        let replacement = match name_id {
+            // The family, full name, typographic and WWS family.
+            1 | 4 | 16 | 21 => Some(family),
+            6 => Some(postscript.as_str()),
+            _ => None,
+        };
+        let value = match (replacement, platform) {
+            (Some(value), 0 | 3) => value.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+            (Some(value), 1) => value
+                .chars()
+                .map(|c| if c.is_ascii() { c as u8 } else { b'?' })
+                .collect(),
+            _ => string(length?, offset?)?.to_vec(),
+        };
+        push(
+            &mut records,
+            [platform, encoding, language, name_id],
+            &value,
+        )?;
+    }
+
+    // Version 1 has tags of the languages after the records.
+    let mut language_tags = Vec::new();
+    if version == 1 {
+        let tags = 6 + count * 12;
+        let tag_count = usize::from(read(tags)?);
+        language_tags.extend((tag_count as u16).to_be_bytes());
+        for index in 0..tag_count {
+            let tag = tags + 2 + index * 4;
+            let value = string(read(tag)?, read(tag + 2)?)?.to_vec();
+            let too_long = |_| String::from("`name` is too long");
+            language_tags.extend(u16::try_from(value.len()).map_err(too_long)?.to_be_bytes());
+            language_tags.extend(
+                u16::try_from(strings.len())
+                    .map_err(too_long)?
+                    .to_be_bytes(),
+            );
+            strings.extend(value);
+        }
+    }
+
+    let storage = 6 + records.len() + language_tags.len();
+    let mut name = Vec::with_capacity(storage + strings.len());
+    name.extend(version.to_be_bytes());
+    name.extend((count as u16).to_be_bytes());
+    name.extend(
+        u16::try_from(storage)
+            .map_err(|_| "`name` is too long")?
+            .to_be_bytes(),
+    );
+    name.extend(records);
+    name.extend(language_tags);
+    name.extend(strings);
+    Ok(name)
+}
+
+/// The contours of a glyph as TrueType points, `x`, `y` and whether it's on the curve.
+#[derive(Default)]
+struct Outline {
+    contours: Vec<Vec<(i32, i32, bool)>>,
+    /// Whether the glyph has cubic curves, which TrueType can't have.
+    cubic: bool,
+}
+
+impl Outline {
+    fn point(&mut self, x: f32, y: f32, on_curve: bool) {
+        if let Some(contour) = self.contours.last_mut() {
+            contour.push((x.round() as i32, y.round() as i32, on_curve));
+        }
+    }
+}
+
+impl OutlineBuilder for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.contours.push(Vec::new());
+        self.point(x, y, true);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.point(x, y, true);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.point(x1, y1, false);
+        self.point(x, y, true);
+    }
+
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {
+        self.cubic = true;
+    }
+
+    fn close(&mut self) {
+        // The outline ends where it started, which TrueType contours do without the point.
+        if let Some(contour) = self.contours.last_mut()
+            && contour.len() > 1
+            && contour.first() == contour.last()
+        {
+            contour.pop();
+        }
+    }
+}
+
+/// Collects the outlines of the layers of a color glyph, in the places its transforms put them.
+struct Layers<'f, 'a> {
+    face: &'f Face<'a>,
+    outline: &'f mut Outline,
+    /// The transforms of the layers, combined with the ones they are in.
+    transforms: Vec<Transform>,
+}
+
+impl Layers<'_, '_> {
+    fn transform(&self) -> Transform {
+        self.transforms.last().copied().unwrap_or_default()
+    }
+
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        let t = self.transform();
+        (t.a * x + t.c * y + t.e, t.b * x + t.d * y + t.f)
+    }
+}
+
+impl<'a> Painter<'a> for Layers<'_, 'a> {
+    fn outline_glyph(&mut self, glyph_id: GlyphId) {
+        let first = self.outline.contours.len();
+        let face = self.face;
+        face.outline_glyph(glyph_id, self);
+
+        // Mirroring turns the contours around, which would make them holes in the others.
+        let t = self.transform();
+        if t.a * t.d - t.b * t.c < 0.0 {
+            for contour in &mut self.outline.contours[first..] {
+                contour.reverse();
+            }
+        }
+    }
+
+    fn paint(&mut self, _: Paint<'a>) {}
+
+    fn push_clip(&mut self) {}
+
+    fn push_clip_box(&mut self, _: ClipBox) {}
+
+    fn pop_clip(&mut self) {}
+
+    fn push_layer(&mut self, _: CompositeMode) {}
+
+    fn pop_layer(&mut self) {}
+
+    fn push_transform(&mut self, transform: Transform) {
+        self.transforms
+            .push(Transform::combine(self.transform(), transform));
+    }
+
+    fn pop_transform(&mut self) {
+        self.transforms.pop();
+    }
+}
+
+impl OutlineBuilder for Layers<'_, '_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.outline.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.outline.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let ((x1, y1), (x, y)) = (self.point(x1, y1), self.point(x, y));
+        self.outline.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {
+        self.outline.cubic = true;
+    }
+
+    fn close(&mut self) {
+        self.outline.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ttf_parser::{Face, GlyphId, Tag};
+
+    use super::{instance, monochrome};
+    use crate::sfnt;
+
+    const FILL: Tag = Tag::from_bytes(b"FILL");
+
+    fn codicon() -> Vec<u8> {
+        std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../fonts/codicon.ttf")).unwrap()
+    }
+
+    /// The tables of a font by their tags.
+    fn tables(font: &[u8]) -> Vec<([u8; 4], Vec<u8>)> {
+        let num_tables = u16::from_be_bytes([font[4], font[5]]);
+        (0..usize::from(num_tables))
+            .map(|index| {
+                let record = &font[12 + index * 16..];
+                let offset = u32::from_be_bytes(record[8..12].try_into().unwrap()) as usize;
+                let length = u32::from_be_bytes(record[12..16].try_into().unwrap()) as usize;
+                (
+                    record[..4].try_into().unwrap(),
+                    font[offset..offset + length].to_vec(),
+                )
+            })
+            .collect()
+    }
+
+    /// The number of points of the simple glyph `glyph`.
+    fn points(face: &Face<'_>, glyph: u16) -> usize {
+        let raw = face.raw_face();
+        let head = raw.table(Tag::from_bytes(b"head")).unwrap();
+        let loca = raw.table(Tag::from_bytes(b"loca")).unwrap();
+        let glyf = raw.table(Tag::from_bytes(b"glyf")).unwrap();
+        let offset = if head[51] == 0 {
+            let at = usize::from(glyph) * 2;
+            usize::from(u16::from_be_bytes([loca[at], loca[at + 1]])) * 2
+        } else {
+            let at = usize::from(glyph) * 4;
+            u32::from_be_bytes(loca[at..at + 4].try_into().unwrap()) as usize
+        };
+        let contours = usize::from(u16::from_be_bytes([glyf[offset], glyf[offset + 1]]));
+        let last = offset + 10 + (contours - 1) * 2;
+        usize::from(u16::from_be_bytes([glyf[last], glyf[last + 1]])) + 1
+    }
+
+    /// Packed deltas of `gvar`, in runs of words.
+    fn deltas(values: &[i16]) -> Vec<u8> {
+        values
+            .chunks(64)
+            .flat_map(|run| {
+                let mut bytes = vec![0x40 | (run.len() - 1) as u8];
+                bytes.extend(run.iter().flat_map(|value| value.to_be_bytes()));
+                bytes
+            })
+            .collect()
+    }
+
+    /// Codicon as a variable font with a `FILL` axis from 0 to 1, which at 1 moves the outline of
+    /// `glyph` right by 50 units and makes it 100 units wider.
+    fn variable(glyph: u16) -> Vec<u8> {
+        let font = codicon();
+        let face = Face::parse(&font, 0).unwrap();
+        let glyph_count = face.number_of_glyphs();
+        let points = points(&face, glyph);
+
+        let mut fvar = Vec::new();
+        for value in [1_u16, 0, 16, 2, 1, 20, 0, 8] {
+            fvar.extend(value.to_be_bytes());
+        }
+        fvar.extend(b"FILL");
+        for value in [0_u32, 0, 0x10000] {
+            fvar.extend(value.to_be_bytes());
+        }
+        fvar.extend([0, 0, 1, 0]);
+
+        // Shared points, all of them, then the deltas of the phantom points too.
+        let mut x = vec![50; points];
+        x.extend([0, 100, 0, 0]);
+        let mut data = vec![0];
+        data.extend(deltas(&x));
+        data.extend(deltas(&vec![0; points + 4]));
+        let mut variations = Vec::new();
+        for value in [0x8001_u16, 10, (data.len() - 1) as u16, 0x8000, 0x4000] {
+            variations.extend(value.to_be_bytes());
+        }
+        variations.extend(data);
+
+        let offsets = 20 + (usize::from(glyph_count) + 1) * 4;
+        let mut gvar = Vec::new();
+        for value in [1_u16, 0, 1, 0] {
+            gvar.extend(value.to_be_bytes());
+        }
+        gvar.extend((offsets as u32).to_be_bytes());
+        gvar.extend(glyph_count.to_be_bytes());
+        gvar.extend(1_u16.to_be_bytes());
+        gvar.extend((offsets as u32).to_be_bytes());
+        for index in 0..=glyph_count {
+            let offset = if index > glyph { variations.len() } else { 0 };
+            gvar.extend((offset as u32).to_be_bytes());
+        }
+        gvar.extend(variations);
+
+        let mut tables = tables(&font);
+        tables.push((*b"fvar", fvar));
+        tables.push((*b"gvar", gvar));
+        sfnt::write(0x0001_0000, tables)
+    }
+
+    /// The instance of `variable` at `fill`.
+    fn at(variable: &[u8], fill: f32) -> Vec<u8> {
+        let mut face = Face::parse(variable, 0).unwrap();
+        face.set_variation(FILL, fill).unwrap();
+        instance(&face, "codicon FILL1", Some(700), None).unwrap()
+    }
+
+    #[test]
+    fn instances_have_the_outlines_of_their_coordinates() {
+        let original = codicon();
+        let original = Face::parse(&original, 0).unwrap();
+        let glyph = (1..original.number_of_glyphs())
+            .find(|glyph| original.glyph_bounding_box(GlyphId(*glyph)).is_some())
+            .unwrap();
+        let variable = variable(glyph);
+
+        let filled = at(&variable, 1.0);
+        let filled = Face::parse(&filled, 0).unwrap();
+        assert!(!filled.is_variable());
+        let bounds = original.glyph_bounding_box(GlyphId(glyph)).unwrap();
+        let moved = filled.glyph_bounding_box(GlyphId(glyph)).unwrap();
+        assert_eq!(moved.x_min, bounds.x_min + 50);
+        assert_eq!(moved.x_max, bounds.x_max + 50);
+        assert_eq!(moved.y_min, bounds.y_min);
+        assert_eq!(
+            filled.glyph_hor_advance(GlyphId(glyph)),
+            original
+                .glyph_hor_advance(GlyphId(glyph))
+                .map(|advance| advance + 100)
+        );
+        assert_eq!(filled.weight().to_number(), 700);
+        let family = filled
+            .names()
+            .into_iter()
+            .filter(|name| name.name_id == ttf_parser::name_id::FAMILY && name.is_unicode())
+            .find_map(|name| name.to_string());
+        assert_eq!(family.as_deref(), Some("codicon FILL1"));
+
+        let unfilled = at(&variable, 0.0);
+        let unfilled = Face::parse(&unfilled, 0).unwrap();
+        for glyph in (0..original.number_of_glyphs()).map(GlyphId) {
+            assert_eq!(
+                unfilled.glyph_bounding_box(glyph),
+                original.glyph_bounding_box(glyph)
+            );
+            assert_eq!(
+                unfilled.glyph_hor_advance(glyph),
+                original.glyph_hor_advance(glyph)
+            );
+        }
+    }
+
+    /// Codicon as a color font, with `base` drawn with the `layers` in palette color 0.
+    fn colored(base: u16, layers: &[u16]) -> Vec<u8> {
+        let mut colr = Vec::new();
+        for value in [0_u16, 1] {
+            colr.extend(value.to_be_bytes());
+        }
+        colr.extend(14_u32.to_be_bytes());
+        colr.extend(20_u32.to_be_bytes());
+        colr.extend((layers.len() as u16).to_be_bytes());
+        for value in [base, 0, layers.len() as u16] {
+            colr.extend(value.to_be_bytes());
+        }
+        for layer in layers {
+            colr.extend(layer.to_be_bytes());
+            colr.extend(0_u16.to_be_bytes());
+        }
+
+        let mut cpal = Vec::new();
+        for value in [0_u16, 1, 1, 1] {
+            cpal.extend(value.to_be_bytes());
+        }
+        cpal.extend(14_u32.to_be_bytes());
+        cpal.extend(0_u16.to_be_bytes());
+        cpal.extend([0, 0, 255, 255]);
+
+        let mut tables = tables(&codicon());
+        tables.push((*b"COLR", colr));
+        tables.push((*b"CPAL", cpal));
+        sfnt::write(0x0001_0000, tables)
+    }
+
+    #[test]
+    fn monochrome_glyphs_have_the_outlines_of_their_layers() {
+        let original = codicon();
+        let original = Face::parse(&original, 0).unwrap();
+        let blank = (0..original.number_of_glyphs())
+            .find(|glyph| original.glyph_bounding_box(GlyphId(*glyph)).is_none())
+            .unwrap();
+        let mut outlined = (0..original.number_of_glyphs())
+            .filter(|glyph| original.glyph_bounding_box(GlyphId(*glyph)).is_some());
+        let (kept, first, second) = (
+            outlined.next().unwrap(),
+            outlined.next().unwrap(),
+            outlined.next().unwrap(),
+        );
+
+        for base in [kept, blank] {
+            let color = colored(base, &[first, second]);
+            let color = Face::parse(&color, 0).unwrap();
+            assert!(color.is_color_glyph(GlyphId(base)));
+
+            let font = monochrome(&color, "codicon Monochrome").unwrap();
+            let font = Face::parse(&font, 0).unwrap();
+            assert!(font.tables().colr.is_none());
+            assert!(font.raw_face().table(Tag::from_bytes(b"CPAL")).is_none());
+
+            let bounds = font.glyph_bounding_box(GlyphId(base)).unwrap();
+            if base == kept {
+                // Renderers without colors draw the outline of the glyph, so it stays.
+                assert_eq!(Some(bounds), original.glyph_bounding_box(GlyphId(base)));
+            } else {
+                let [first, second] = [first, second]
+                    .map(|glyph| original.glyph_bounding_box(GlyphId(glyph)).unwrap());
+                assert_eq!(bounds.x_min, first.x_min.min(second.x_min));
+                assert_eq!(bounds.y_min, first.y_min.min(second.y_min));
+                assert_eq!(bounds.x_max, first.x_max.max(second.x_max));
+                assert_eq!(bounds.y_max, first.y_max.max(second.y_max));
+            }
+        }
+    }
+}
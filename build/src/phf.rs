@@ -0,0 +1,178 @@
+//! A perfect hash of the icon names, built while generating the module so looking up an icon by
+//! name at runtime is a hash and a comparison, without building a map at startup.
+//!
+//! This is the hash and displace algorithm of the `phf` crate: the names are hashed into buckets
+//! of about five, and every bucket gets the displacements that move its names into free slots.
+
+use quote::quote;
+
+/// Names per bucket on average, fewer make bigger tables but find displacements sooner.
+const BUCKET_SIZE: usize = 5;
+
+/// The displacement of every bucket and the index of the name in every slot.
+pub(crate) struct Map {
+    seed: u64,
+    displacements: Vec<(u32, u32)>,
+    slots: Vec<u32>,
+}
+
+impl Map {
+    /// Builds the perfect hash of `names`, which have to be unique.
+    pub(crate) fn new(names: &[&str]) -> Self {
+        // Practically every seed works, the next one is tried when one doesn't.
+        (0..)
+            .find_map(|seed| Self::with_seed(seed, names))
+            .expect("a seed works")
+    }
+
+    fn with_seed(seed: u64, names: &[&str]) -> Option<Self> {
+        let len = names.len() as u32;
+        let bucket_count = names.len().div_ceil(BUCKET_SIZE).max(1);
+        let hashes: Vec<(u32, u32, u32)> = names.iter().map(|name| hash(seed, name)).collect();
+
+        let mut buckets = vec![Vec::new(); bucket_count];
+        for (i, &(bucket, _, _)) in hashes.iter().enumerate() {
+            buckets[bucket as usize % bucket_count].push(i);
+        }
+        // The biggest buckets go first, while most slots are still free.
+        let mut order: Vec<usize> = (0..bucket_count).collect();
+        order.sort_by_key(|&bucket| std::cmp::Reverse(buckets[bucket].len()));
+
+        let mut displacements = vec![(0, 0); bucket_count];
+        let mut slots: Vec<Option<u32>> = vec![None; names.len()];
+        let mut taken = Vec::with_capacity(BUCKET_SIZE * 2);
+        for bucket in order {
+            let names = &buckets[bucket];
+            if names.is_empty() {
+                continue;
+            }
+
+            let found = (0..len)
+                .flat_map(|d1| (0..len).map(move |d2| (d1, d2)))
+                .find(|&(d1, d2)| {
+                    taken.clear();
+                    names.iter().all(|&i| {
+                        let (_, f1, f2) = hashes[i];
+                        let slot = displace(f1, f2, d1, d2) % len;
+                        let free = slots[slot as usize].is_none() && !taken.contains(&slot);
+                        taken.push(slot);
+                        free
+                    })
+                })?;
+
+            displacements[bucket] = found;
+            for (&i, &slot) in names.iter().zip(&taken) {
+                slots[slot as usize] = Some(i as u32);
+            }
+        }
+
+        Some(Self {
+            seed,
+            displacements,
+            slots: slots.into_iter().map(|slot| slot.unwrap_or(0)).collect(),
+        })
+    }
+
+    /// The body of `Icon::from_name`, which hashes `name` with the same functions as here.
+    pub(crate) fn lookup(&self) -> proc_macro2::TokenStream {
+        if self.slots.is_empty() {
+            return quote! {
+                let _ = name;
+                None
+            };
+        }
+
+        let seed = self.seed;
+        let displacements = self
+            .displacements
+            .iter()
+            .map(|(d1, d2)| quote! { (#d1, #d2) });
+        let slots = &self.slots;
+        let hash = hash_tokens();
+
+        quote! {
+            const DISPLACEMENTS: &[(u32, u32)] = &[#(#displacements),*];
+            const SLOTS: &[u32] = &[#(#slots),*];
+            #hash
+
+            let (bucket, f1, f2) = hash(#seed, name);
+            let (d1, d2) = DISPLACEMENTS[bucket as usize % DISPLACEMENTS.len()];
+            let slot = displace(f1, f2, d1, d2) % SLOTS.len() as u32;
+            let icon = Self::ALL[SLOTS[slot as usize] as usize];
+            (icon.name() == name).then_some(icon)
+        }
+    }
+}
+
+// The functions below are also generated into every module by `hash_tokens`, keep them the same.
+
+fn hash(seed: u64, name: &str) -> (u32, u32, u32) {
+    // FNV-1a, mixed with splitmix64 into three independent parts.
+    let mut hash = 0xcbf2_9ce4_8422_2325 ^ seed;
+    for byte in name.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    let a = mix(hash);
+    let b = mix(hash ^ 0x9e37_79b9_7f4a_7c15);
+    ((a >> 32) as u32, a as u32, b as u32)
+}
+
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+    d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+}
+
+fn hash_tokens() -> proc_macro2::TokenStream {
+    quote! {
+        fn hash(seed: u64, name: &str) -> (u32, u32, u32) {
+            let mut hash = 0xcbf2_9ce4_8422_2325_u64 ^ seed;
+            for byte in name.bytes() {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+            let a = mix(hash);
+            let b = mix(hash ^ 0x9e37_79b9_7f4a_7c15);
+            ((a >> 32) as u32, a as u32, b as u32)
+        }
+
+        fn mix(mut x: u64) -> u64 {
+            x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            x ^ (x >> 31)
+        }
+
+        fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+            d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Map, displace, hash};
+
+    /// The index of the name in the slot `name` hashes to, the way `Icon::from_name` finds it.
+    fn slot(map: &Map, name: &str) -> u32 {
+        let (bucket, f1, f2) = hash(map.seed, name);
+        let (d1, d2) = map.displacements[bucket as usize % map.displacements.len()];
+        map.slots[(displace(f1, f2, d1, d2) % map.slots.len() as u32) as usize]
+    }
+
+    #[test]
+    fn every_name_has_a_slot_of_its_own() {
+        let names: Vec<String> = (0..5000).map(|i| format!("icon_{i}")).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        for count in [1, 2, 5, 6, 5000] {
+            let map = Map::new(&names[..count]);
+            for (index, name) in names[..count].iter().enumerate() {
+                assert_eq!(slot(&map, name), index as u32, "{name}");
+            }
+        }
+    }
+}
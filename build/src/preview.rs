@@ -0,0 +1,92 @@
+//! The `previews` option, embedding a picture of every icon in its documentation.
+//!
+//! The glyph outlines are turned into a small SVG at compile time and put into the doc comment as
+//! a base64 data URI, which rustdoc and most editors' hovers show like any other image. Variable
+//! fonts are drawn at their default instance.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use ttf_parser::{Face, OutlineBuilder};
+
+/// The size the previews are shown at, in pixels.
+const SIZE: u32 = 32;
+
+/// The preview of every glyph of `face` that has an outline, by codepoint, as an image data URI.
+pub(crate) fn previews(
+    face: &Face<'_>,
+    codepoints: impl Iterator<Item = char>,
+) -> HashMap<char, String> {
+    codepoints
+        .filter_map(|c| Some((c, data_uri(&svg(face, c)?))))
+        .collect()
+}
+
+/// An SVG of the glyph of `c`, its outline centered in a square around it.
+fn svg(face: &Face<'_>, c: char) -> Option<String> {
+    let id = face.glyph_index(c)?;
+    let mut path = Path(String::new());
+    let bounds = face.outline_glyph(id, &mut path)?;
+
+    let width = i32::from(bounds.width());
+    let height = i32::from(bounds.height());
+    let size = width.max(height);
+    let x = i32::from(bounds.x_min) - (size - width) / 2;
+    let y = -i32::from(bounds.y_max) - (size - height) / 2;
+
+    Some(format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{SIZE}" height="{SIZE}" viewBox="{x} {y} {size} {size}"><path fill="#808080" d="{}"/></svg>"##,
+        path.0
+    ))
+}
+
+/// Writes the outline as SVG path data. Fonts point y up while SVG points it down, so y is negated.
+struct Path(String);
+
+impl OutlineBuilder for Path {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let _ = write!(self.0, "M{:.0} {:.0}", x, -y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let _ = write!(self.0, "L{:.0} {:.0}", x, -y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let _ = write!(self.0, "Q{:.0} {:.0} {:.0} {:.0}", x1, -y1, x, -y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let _ = write!(
+            self.0,
+            "C{:.0} {:.0} {:.0} {:.0} {:.0} {:.0}",
+            x1, -y1, x2, -y2, x, -y
+        );
+    }
+
+    fn close(&mut self) {
+        self.0.push('Z');
+    }
+}
+
+fn data_uri(svg: &str) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut uri = String::from("data:image/svg+xml;base64,");
+    for chunk in svg.as_bytes().chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                uri.push(char::from(ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize]));
+            } else {
+                uri.push('=');
+            }
+        }
+    }
+    uri
+}
@@ -0,0 +1,54 @@
+//! The Private Use Area codepoints of a module, which fonts derived from the Nerd Fonts share, so
+//! apps can tell which of them can't be loaded side by side.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::icons::Icon;
+
+/// Returns `PUA_RANGES`, the runs of consecutive Private Use Area codepoints `icons` occupy, and
+/// `conflicts_with`.
+pub(crate) fn tokens(icons: &[Icon<'_>]) -> TokenStream {
+    let mut pua: Vec<u32> = icons
+        .iter()
+        .map(|icon| u32::from(icon.codepoint))
+        .filter(|&c| (0xe000..=0xf8ff).contains(&c) || c >= 0xf_0000)
+        .collect();
+    pua.sort_unstable();
+    pua.dedup();
+    let mut ranges: Vec<(char, char)> = Vec::new();
+    for c in pua.into_iter().filter_map(char::from_u32) {
+        match ranges.last_mut() {
+            Some((_, end)) if u32::from(*end) + 1 == u32::from(c) => *end = c,
+            _ => ranges.push((c, c)),
+        }
+    }
+    let ranges = ranges
+        .into_iter()
+        .map(|(start, end)| quote! { #start..=#end });
+
+    quote! {
+        /// The Private Use Area codepoints the icons occupy. Fonts derived from the Nerd Fonts
+        /// share these, and loading two of them that map the same codepoints makes iced draw one
+        /// font's glyph for the other's icon, see [`conflicts_with`].
+        pub const PUA_RANGES: &[std::ops::RangeInclusive<char>] = &[#(#ranges),*];
+
+        /// The codepoints both this font and the font of `other`, e.g. `octicons::PUA_RANGES`,
+        /// have icons at, empty when they can be used side by side.
+        #[must_use]
+        pub fn conflicts_with(
+            other: &[std::ops::RangeInclusive<char>],
+        ) -> Vec<std::ops::RangeInclusive<char>> {
+            PUA_RANGES
+                .iter()
+                .flat_map(|ours| {
+                    other.iter().filter_map(move |theirs| {
+                        let start = *ours.start().max(theirs.start());
+                        let end = *ours.end().min(theirs.end());
+                        (start <= end).then_some(start..=end)
+                    })
+                })
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! The `rename` option, overriding the function names of single glyphs, and the other options
+//! written as pairs of names and strings.
+
+use proc_macro2::Span;
+use syn::{
+    LitStr, Token, braced, ext::IdentExt, parse::ParseStream, punctuated::Punctuated, token::Comma,
+};
+
+use crate::resolve_path;
+
+/// A glyph name and the function name it gets instead of its sanitized one, or for the
+/// `deprecated` option, an old function name and the one it was renamed to, or for the `fallbacks`
+/// option, a function name and its text.
+pub(crate) struct Rename {
+    pub(crate) from: String,
+    pub(crate) to: String,
+    /// Where the rename was written, for errors.
+    pub(crate) span: Span,
+}
+
+/// Parses `{ "glyph-name" = "fn_name", other = "other_fn" }` or the path of a file with the same
+/// pairs as a flat TOML table, relative to the invoking crate's `Cargo.toml`.
+pub(crate) fn parse(input: ParseStream<'_>) -> syn::Result<Vec<Rename>> {
+    parse_pairs(input, "rename", validate)
+}
+
+/// Parses `{ house = "[H]" }` or a file of such pairs, function names and the text standing in for
+/// their icons where fonts can't be shown.
+pub(crate) fn parse_fallbacks(input: ParseStream<'_>) -> syn::Result<Vec<Rename>> {
+    parse_pairs(input, "fallback", |_, _| Ok(()))
+}
+
+/// Parses `{ checked = "check_square", unchecked = "square" }`, roles and the function names of
+/// the icons playing them.
+pub(crate) fn parse_roles(input: ParseStream<'_>) -> syn::Result<Vec<Rename>> {
+    parse_pairs(input, "role", validate)
+}
+
+/// Parses `{ de = "translations/lucide.de.toml", "pt-BR" = "..." }`, locales and the files
+/// translating the icons into them, each with a `function_name = "Name"` pair per line.
+pub(crate) fn parse_translations(
+    input: ParseStream<'_>,
+) -> syn::Result<Vec<(String, Vec<Rename>)>> {
+    let content;
+    braced!(content in input);
+
+    let files = Punctuated::<(String, LitStr), Comma>::parse_terminated_with(&content, |input| {
+        let locale = if input.peek(LitStr) {
+            input.parse::<LitStr>()?.value()
+        } else {
+            input.call(syn::Ident::parse_any)?.to_string()
+        };
+        let _: Token![=] = input.parse()?;
+        Ok((locale, input.parse()?))
+    })?;
+
+    files
+        .into_iter()
+        .map(|(locale, path)| Ok((locale, read_file(&path, "translation", |_, _| Ok(()))?)))
+        .collect()
+}
+
+/// Parses pairs in either form, checking every value with `check`.
+fn parse_pairs(
+    input: ParseStream<'_>,
+    what: &str,
+    check: fn(&str, Span) -> syn::Result<()>,
+) -> syn::Result<Vec<Rename>> {
+    if input.peek(LitStr) {
+        let path: LitStr = input.parse()?;
+        return read_file(&path, what, check);
+    }
+
+    let content;
+    braced!(content in input);
+
+    let pairs = Punctuated::<Rename, Comma>::parse_terminated_with(&content, |input| {
+        let from = if input.peek(LitStr) {
+            input.parse::<LitStr>()?.value()
+        } else {
+            input.call(syn::Ident::parse_any)?.to_string()
+        };
+        let _: Token![=] = input.parse()?;
+        let to: LitStr = input.parse()?;
+
+        Ok(Rename {
+            from,
+            to: to.value(),
+            span: to.span(),
+        })
+    })?;
+
+    for pair in &pairs {
+        check(&pair.to, pair.span)?;
+    }
+    Ok(pairs.into_iter().collect())
+}
+
+/// Reads lines of `glyph-name = "fn_name"`, the subset of flat TOML tables the files need. Names can
+/// be quoted, `"glyph name" = "fn_name"`, and empty lines and `#` comments, on their own line or
+/// after a pair, are skipped. Quoted strings are taken as they are, without escapes.
+fn read_file(
+    path: &LitStr,
+    what: &str,
+    check: fn(&str, Span) -> syn::Result<()>,
+) -> syn::Result<Vec<Rename>> {
+    let path_str = path.value();
+    let contents = std::fs::read_to_string(resolve_path(&path_str)).map_err(|e| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to read {what} file `{path_str}`: {e}"),
+        )
+    })?;
+
+    let mut renames = Vec::new();
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = |message: &str| {
+            syn::Error::new(
+                path.span(),
+                format!("`{path_str}` line {}: {message}", number + 1),
+            )
+        };
+
+        let (from, to) = pair(line).map_err(error)?;
+
+        check(to, path.span()).map_err(|e| error(&e.to_string()))?;
+        renames.push(Rename {
+            from: from.to_string(),
+            to: to.to_string(),
+            span: path.span(),
+        });
+    }
+
+    Ok(renames)
+}
+
+/// Splits a line of `name = "value" # comment` into the name and the value.
+fn pair(line: &str) -> Result<(&str, &str), &'static str> {
+    // Quoted names can have a `=` of their own.
+    let (from, rest) = match line.strip_prefix('"') {
+        Some(quoted) => quoted
+            .split_once('"')
+            .ok_or("the name is missing its closing quote")?,
+        None => line.split_at(line.find('=').unwrap_or(line.len())),
+    };
+    let value = rest
+        .trim_start()
+        .strip_prefix('=')
+        .ok_or("expected `name = \"value\"`")?
+        .trim_start();
+    let (to, rest) = value
+        .strip_prefix('"')
+        .and_then(|value| value.split_once('"'))
+        .ok_or("the value has to be quoted")?;
+
+    let rest = rest.trim_start();
+    if rest.is_empty() || rest.starts_with('#') {
+        Ok((from.trim(), to))
+    } else {
+        Err("expected a `#` comment or nothing after the value")
+    }
+}
+
+/// Renamed functions skip sanitizing, so they have to be valid lower snake_case names already.
+pub(crate) fn validate(name: &str, span: Span) -> syn::Result<()> {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !matches!(name, "_" | "self" | "super" | "crate");
+
+    if valid {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            span,
+            format!("`{name}` is not a lower snake_case function name"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+    use syn::LitStr;
+
+    use super::{Rename, pair, read_file, validate};
+
+    /// The pairs of a file with `contents`, read without checking the values.
+    fn read(name: &str, contents: &str) -> syn::Result<Vec<(String, String)>> {
+        let path = std::env::temp_dir().join(format!("iced_fonts_rename_{name}.toml"));
+        std::fs::write(&path, contents).unwrap();
+        let path = LitStr::new(path.to_str().unwrap(), Span::call_site());
+
+        let renames = read_file(&path, "rename", |_, _| Ok(()));
+        std::fs::remove_file(path.value()).unwrap();
+        Ok(renames?
+            .into_iter()
+            .map(|Rename { from, to, .. }| (from, to))
+            .collect())
+    }
+
+    #[test]
+    fn pairs_can_be_quoted_and_commented() {
+        assert_eq!(pair(r#"house = "home""#), Ok(("house", "home")));
+        assert_eq!(pair(r#"arrow-up="up""#), Ok(("arrow-up", "up")));
+        assert_eq!(pair(r#""glyph name" = "name""#), Ok(("glyph name", "name")));
+        assert_eq!(pair(r#""a=b" = "equals""#), Ok(("a=b", "equals")));
+        assert_eq!(pair(r##"hash = "#" # the sign"##), Ok(("hash", "#")));
+        assert_eq!(pair(r##"house = "home"#note"##), Ok(("house", "home")));
+    }
+
+    #[test]
+    fn malformed_pairs_are_errors() {
+        assert_eq!(pair("house"), Err("expected `name = \"value\"`"));
+        assert_eq!(pair("house = home"), Err("the value has to be quoted"));
+        assert_eq!(pair(r#"house = "home"#), Err("the value has to be quoted"));
+        assert_eq!(
+            pair(r#""house = "home""#),
+            Err("expected `name = \"value\"`")
+        );
+        assert_eq!(
+            pair(r#""house = "home"#),
+            Err("expected `name = \"value\"`")
+        );
+        assert_eq!(
+            pair(r#"house = "home" "house""#),
+            Err("expected a `#` comment or nothing after the value")
+        );
+    }
+
+    #[test]
+    fn files_skip_comments_and_empty_lines() {
+        let pairs = read(
+            "comments",
+            "# Renames\n\nhouse = \"home\" # the old name\n  \"glyph name\" = \"name\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            pairs,
+            [
+                ("house".to_string(), "home".to_string()),
+                ("glyph name".to_string(), "name".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn file_errors_name_the_line() {
+        let error = read("error", "house = \"home\"\n\ngear = cog\n").unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .ends_with("line 3: the value has to be quoted"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn renames_have_to_be_function_names() {
+        for name in ["house", "arrow_up_2", "_4k", "type", "self_"] {
+            assert!(validate(name, Span::call_site()).is_ok(), "{name:?}");
+        }
+        for name in [
+            "House", "4k", "arrow-up", "r#type", "", "_", "self", "super", "crate",
+        ] {
+            assert!(validate(name, Span::call_site()).is_err(), "{name:?}");
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//! The options pairing roles with the icons playing them. `roles` covers the common actions and
+//! states of `Role` in `iced_fonts`, for icon themes and finding the same icon in other fonts, and
+//! may leave roles out. The smaller sets like `selection` or `media` need every one of theirs.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::Context;
+use crate::rename::Rename;
+
+/// The roles in the order of `Role`, as they're written in the option.
+pub(crate) const ROLES: [&str; 100] = [
+    "close",
+    "save",
+    "search",
+    "warning",
+    "error",
+    "info",
+    "settings",
+    "home",
+    "add",
+    "remove",
+    "edit",
+    "delete",
+    "copy",
+    "refresh",
+    "menu",
+    "folder",
+    "file",
+    "download",
+    "upload",
+    "user",
+    "help",
+    "check",
+    "back",
+    "forward",
+    "up",
+    "down",
+    "chevron_left",
+    "chevron_right",
+    "chevron_up",
+    "chevron_down",
+    "more",
+    "more_vertical",
+    "cut",
+    "paste",
+    "undo",
+    "redo",
+    "share",
+    "print",
+    "filter",
+    "sort",
+    "link",
+    "external_link",
+    "attach",
+    "send",
+    "reply",
+    "zoom_in",
+    "zoom_out",
+    "maximize",
+    "minimize",
+    "lock",
+    "unlock",
+    "login",
+    "logout",
+    "play",
+    "pause",
+    "stop",
+    "skip_next",
+    "skip_previous",
+    "volume",
+    "mute",
+    "folder_open",
+    "image",
+    "video",
+    "music",
+    "calendar",
+    "clock",
+    "users",
+    "mail",
+    "phone",
+    "chat",
+    "bookmark",
+    "star",
+    "heart",
+    "tag",
+    "cart",
+    "globe",
+    "location",
+    "camera",
+    "microphone",
+    "cloud",
+    "database",
+    "code",
+    "terminal",
+    "key",
+    "shield",
+    "show",
+    "hide",
+    "notification",
+    "archive",
+    "inbox",
+    "flag",
+    "history",
+    "grid",
+    "list",
+    "light_mode",
+    "dark_mode",
+    "wifi",
+    "power",
+    "bug",
+    "keyboard",
+];
+
+/// Returns `Icon::ROLES`, the icon of every role the pairs name and `None` for the others,
+/// nothing without the `roles` option.
+pub(crate) fn tokens(context: &Context<'_>) -> syn::Result<TokenStream> {
+    let pairs = &context.options.roles;
+    if pairs.is_empty() {
+        return Ok(quote! {});
+    }
+    if let Some(pair) = pairs
+        .iter()
+        .find(|pair| !ROLES.contains(&pair.from.as_str()))
+    {
+        return Err(syn::Error::new(
+            pair.span,
+            format!(
+                "unknown role `{}`, expected one of {}",
+                pair.from,
+                ROLES.join(", ")
+            ),
+        ));
+    }
+
+    let mut icons = Vec::with_capacity(ROLES.len());
+    for role in ROLES {
+        let Some(pair) = pairs.iter().find(|pair| pair.from == role) else {
+            icons.push(quote! { None });
+            continue;
+        };
+        let index = context
+            .position(&pair.to)
+            .ok_or_else(|| context.no_icon(pair.span, &pair.to))?;
+        icons.push(quote! { Some(Self::ALL[#index]) });
+    }
+
+    Ok(quote! {
+        impl Icon {
+            /// The icons of common actions and states, in the order of `Role` in `iced_fonts`,
+            /// `None` for the roles the `roles` option leaves out.
+            pub const ROLES: [Option<Icon>; 100] = [#(#icons),*];
+        }
+    })
+}
+
+/// Returns the constants of the smaller sets of roles, `Icon::SELECTION`, `Icon::MEDIA`,
+/// `Icon::VCS`, `Icon::FILE_KINDS` and `Icon::PLATFORMS`, for `Selection`, `Media`, `GitStatus`,
+/// `FileKind` and `Os` in `iced_fonts`. Options that aren't there get no constant.
+pub(crate) fn sets(context: &Context<'_>) -> syn::Result<TokenStream> {
+    let options = context.options;
+    let mut tokens = TokenStream::new();
+
+    if !options.selection.is_empty() {
+        let icons = set(
+            context,
+            "selection",
+            &[
+                "checked",
+                "unchecked",
+                "indeterminate",
+                "radio_on",
+                "radio_off",
+            ],
+            &options.selection,
+        )?;
+        tokens.extend(quote! {
+            impl Icon {
+                /// The icons of a checked, unchecked and indeterminate checkbox and of a selected
+                /// and unselected radio button, from the `selection` option.
+                pub const SELECTION: [Icon; 5] = [#(Self::ALL[#icons]),*];
+            }
+        });
+    }
+
+    if !options.media.is_empty() {
+        let icons = set(
+            context,
+            "media",
+            &[
+                "play",
+                "pause",
+                "stop",
+                "next",
+                "previous",
+                "shuffle",
+                "repeat",
+                "volume_mute",
+                "volume_low",
+                "volume_high",
+            ],
+            &options.media,
+        )?;
+        tokens.extend(quote! {
+            impl Icon {
+                /// The icons of media player controls, play, pause, stop, next, previous,
+                /// shuffle, repeat, and a muted, low and high volume, from the `media` option.
+                pub const MEDIA: [Icon; 10] = [#(Self::ALL[#icons]),*];
+            }
+        });
+    }
+
+    if !options.vcs.is_empty() {
+        let icons = set(
+            context,
+            "vcs",
+            &[
+                "modified",
+                "added",
+                "deleted",
+                "renamed",
+                "conflicted",
+                "untracked",
+            ],
+            &options.vcs,
+        )?;
+        tokens.extend(quote! {
+            impl Icon {
+                /// The icons of modified, added, deleted, renamed, conflicted and untracked
+                /// files in version control, from the `vcs` option.
+                pub const VCS: [Icon; 6] = [#(Self::ALL[#icons]),*];
+            }
+        });
+    }
+
+    if !options.file_kinds.is_empty() {
+        let icons = set(
+            context,
+            "file_kinds",
+            &[
+                "file",
+                "text",
+                "code",
+                "pdf",
+                "document",
+                "spreadsheet",
+                "presentation",
+                "image",
+                "audio",
+                "video",
+                "archive",
+                "font",
+                "binary",
+            ],
+            &options.file_kinds,
+        )?;
+        tokens.extend(quote! {
+            impl Icon {
+                /// The icons of generic, text, code, PDF, document, spreadsheet, presentation,
+                /// image, audio, video, archive, font and binary files, from the `file_kinds`
+                /// option.
+                pub const FILE_KINDS: [Icon; 13] = [#(Self::ALL[#icons]),*];
+            }
+        });
+    }
+
+    if !options.platforms.is_empty() {
+        let icons = set(
+            context,
+            "platforms",
+            &["linux", "windows", "macos", "android", "ios"],
+            &options.platforms,
+        )?;
+        tokens.extend(quote! {
+            impl Icon {
+                /// The logos of Linux, Windows, macOS, Android and iOS, from the `platforms`
+                /// option.
+                pub const PLATFORMS: [Icon; 5] = [#(Self::ALL[#icons]),*];
+            }
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// The indices of the icons playing `roles`, in their order, from an option like `selection`
+/// pairing every role with the function name of its icon.
+fn set(
+    context: &Context<'_>,
+    option: &str,
+    roles: &[&str],
+    pairs: &[Rename],
+) -> syn::Result<Vec<usize>> {
+    if let Some(pair) = pairs
+        .iter()
+        .find(|pair| !roles.contains(&pair.from.as_str()))
+    {
+        return Err(syn::Error::new(
+            pair.span,
+            format!(
+                "unknown {option} `{}`, expected one of {}",
+                pair.from,
+                roles.join(", ")
+            ),
+        ));
+    }
+
+    roles
+        .iter()
+        .map(|role| {
+            let Some(pair) = pairs.iter().find(|pair| pair.from == *role) else {
+                return Err(syn::Error::new(
+                    pairs[0].span,
+                    format!("the `{option}` option is missing `{role}`"),
+                ));
+            };
+            context
+                .position(&pair.to)
+                .ok_or_else(|| context.no_icon(pair.span, &pair.to))
+        })
+        .collect()
+}
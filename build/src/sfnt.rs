@@ -0,0 +1,120 @@
+//! Just enough of the sfnt container format (the wrapper around TrueType and OpenType fonts) to
+//! put a font together from its tables, for the web fonts and instances of variable fonts the
+//! macros turn into fonts iced can load.
+
+/// A font of the `tables`, with offsets and checksums computed.
+pub(crate) fn write(flavor: u32, mut tables: Vec<([u8; 4], Vec<u8>)>) -> Vec<u8> {
+    tables.sort_by_key(|(tag, _)| *tag);
+
+    let num_tables = tables.len() as u16;
+    let entry_selector = num_tables.max(1).ilog2() as u16;
+    let search_range = (1 << entry_selector) * 16;
+    let range_shift = num_tables * 16 - search_range;
+
+    let mut font = Vec::new();
+    font.extend(flavor.to_be_bytes());
+    font.extend(num_tables.to_be_bytes());
+    font.extend(search_range.to_be_bytes());
+    font.extend(entry_selector.to_be_bytes());
+    font.extend(range_shift.to_be_bytes());
+
+    let mut offset = 12 + tables.len() * 16;
+    for (tag, table) in &mut tables {
+        if tag == b"head"
+            && let Some(adjustment) = table.get_mut(8..12)
+        {
+            // `checkSumAdjustment` is zero while the checksums are computed.
+            adjustment.fill(0);
+        }
+
+        font.extend(*tag);
+        font.extend(checksum(table).to_be_bytes());
+        font.extend((offset as u32).to_be_bytes());
+        font.extend((table.len() as u32).to_be_bytes());
+        offset += table.len().next_multiple_of(4);
+    }
+
+    let mut head = None;
+    for (tag, table) in &tables {
+        if tag == b"head" {
+            head = Some(font.len());
+        }
+        font.extend(table);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+
+    if let Some(head) = head
+        && font.len() >= head + 12
+    {
+        let adjustment = 0xb1b0_afba_u32.wrapping_sub(checksum(&font));
+        font[head + 8..head + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+
+    font
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0_u32, |sum, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// The smallest box around the points, `x_min`, `y_min`, `x_max` and `y_max`.
+pub(crate) fn bounding_box(points: &[(i32, i32, bool)]) -> Option<[i16; 4]> {
+    if points.is_empty() {
+        return Some([0; 4]);
+    }
+
+    let x_min = points.iter().map(|point| point.0).min()?;
+    let y_min = points.iter().map(|point| point.1).min()?;
+    let x_max = points.iter().map(|point| point.0).max()?;
+    let y_max = points.iter().map(|point| point.1).max()?;
+    let bbox = [x_min, y_min, x_max, y_max].map(i16::try_from);
+    Some([bbox[0].ok()?, bbox[1].ok()?, bbox[2].ok()?, bbox[3].ok()?])
+}
+
+/// Writes the flags and coordinates of the points of a simple glyph, in the short forms where
+/// they fit.
+pub(crate) fn simple_points(
+    glyf: &mut Vec<u8>,
+    points: &[(i32, i32, bool)],
+    overlap: bool,
+) -> Result<(), std::num::TryFromIntError> {
+    let mut flags = Vec::with_capacity(points.len());
+    let (mut xs, mut ys) = (Vec::new(), Vec::new());
+    let (mut x, mut y) = (0, 0);
+
+    for (index, &(point_x, point_y, on_curve)) in points.iter().enumerate() {
+        let mut flag = u8::from(on_curve);
+        if overlap && index == 0 {
+            flag |= 0x40;
+        }
+
+        for (delta, coordinates, short, same) in [
+            (point_x - x, &mut xs, 0x02, 0x10),
+            (point_y - y, &mut ys, 0x04, 0x20),
+        ] {
+            if delta == 0 {
+                flag |= same;
+            } else if delta.unsigned_abs() < 256 {
+                flag |= short;
+                if delta > 0 {
+                    flag |= same;
+                }
+                coordinates.push(delta.unsigned_abs() as u8);
+            } else {
+                coordinates.extend(i16::try_from(delta)?.to_be_bytes());
+            }
+        }
+
+        flags.push(flag);
+        (x, y) = (point_x, point_y);
+    }
+
+    glyf.extend(flags);
+    glyf.extend(xs);
+    glyf.extend(ys);
+    Ok(())
+}
@@ -0,0 +1,373 @@
+//! Shortcodes like `:house:` in text, replaced by the icons they name for `rich_text`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::icons::Icon;
+
+/// GitHub's shortcodes of the emoji of a single codepoint, from its `gemoji` list, sorted.
+const GEMOJI: &[(&str, char)] = &[
+    ("+1", '\u{1f44d}'),
+    ("-1", '\u{1f44e}'),
+    ("100", '\u{1f4af}'),
+    ("airplane", '\u{2708}'),
+    ("alarm_clock", '\u{23f0}'),
+    ("alien", '\u{1f47d}'),
+    ("angry", '\u{1f620}'),
+    ("apple", '\u{1f34e}'),
+    ("arrow_down", '\u{2b07}'),
+    ("arrow_left", '\u{2b05}'),
+    ("arrow_right", '\u{27a1}'),
+    ("arrow_up", '\u{2b06}'),
+    ("arrows_counterclockwise", '\u{1f504}'),
+    ("art", '\u{1f3a8}'),
+    ("atom_symbol", '\u{269b}'),
+    ("baby", '\u{1f476}'),
+    ("balloon", '\u{1f388}'),
+    ("banana", '\u{1f34c}'),
+    ("bar_chart", '\u{1f4ca}'),
+    ("basketball", '\u{1f3c0}'),
+    ("battery", '\u{1f50b}'),
+    ("bear", '\u{1f43b}'),
+    ("bee", '\u{1f41d}'),
+    ("beer", '\u{1f37a}'),
+    ("bell", '\u{1f514}'),
+    ("bike", '\u{1f6b2}'),
+    ("biohazard", '\u{2623}'),
+    ("bird", '\u{1f426}'),
+    ("birthday", '\u{1f382}'),
+    ("black_circle", '\u{26ab}'),
+    ("black_joker", '\u{1f0cf}'),
+    ("blue_heart", '\u{1f499}'),
+    ("blush", '\u{1f60a}'),
+    ("bomb", '\u{1f4a3}'),
+    ("book", '\u{1f4d6}'),
+    ("bookmark", '\u{1f516}'),
+    ("books", '\u{1f4da}'),
+    ("boom", '\u{1f4a5}'),
+    ("brain", '\u{1f9e0}'),
+    ("broken_heart", '\u{1f494}'),
+    ("bug", '\u{1f41b}'),
+    ("bulb", '\u{1f4a1}'),
+    ("cactus", '\u{1f335}'),
+    ("cake", '\u{1f370}'),
+    ("calendar", '\u{1f4c6}'),
+    ("camera", '\u{1f4f7}'),
+    ("car", '\u{1f697}'),
+    ("cat", '\u{1f431}'),
+    ("chart_with_downwards_trend", '\u{1f4c9}'),
+    ("chart_with_upwards_trend", '\u{1f4c8}'),
+    ("checkered_flag", '\u{1f3c1}'),
+    ("cherry_blossom", '\u{1f338}'),
+    ("clap", '\u{1f44f}'),
+    ("clipboard", '\u{1f4cb}'),
+    ("cloud", '\u{2601}'),
+    ("clubs", '\u{2663}'),
+    ("coffee", '\u{2615}'),
+    ("collision", '\u{1f4a5}'),
+    ("computer", '\u{1f4bb}'),
+    ("confused", '\u{1f615}'),
+    ("construction", '\u{1f6a7}'),
+    ("cool", '\u{1f192}'),
+    ("copyright", '\u{a9}'),
+    ("crab", '\u{1f980}'),
+    ("credit_card", '\u{1f4b3}'),
+    ("crescent_moon", '\u{1f319}'),
+    ("crossed_fingers", '\u{1f91e}'),
+    ("crown", '\u{1f451}'),
+    ("cry", '\u{1f622}'),
+    ("crystal_ball", '\u{1f52e}'),
+    ("dart", '\u{1f3af}'),
+    ("date", '\u{1f4c5}'),
+    ("deciduous_tree", '\u{1f333}'),
+    ("diamonds", '\u{2666}'),
+    ("disappointed", '\u{1f61e}'),
+    ("dna", '\u{1f9ec}'),
+    ("dog", '\u{1f436}'),
+    ("dollar", '\u{1f4b5}'),
+    ("droplet", '\u{1f4a7}'),
+    ("e-mail", '\u{1f4e7}'),
+    ("earth_africa", '\u{1f30d}'),
+    ("earth_americas", '\u{1f30e}'),
+    ("earth_asia", '\u{1f30f}'),
+    ("electric_plug", '\u{1f50c}'),
+    ("email", '\u{1f4e7}'),
+    ("envelope", '\u{2709}'),
+    ("evergreen_tree", '\u{1f332}'),
+    ("exclamation", '\u{2757}'),
+    ("expressionless", '\u{1f611}'),
+    ("eyeglasses", '\u{1f453}'),
+    ("eyes", '\u{1f440}'),
+    ("facepunch", '\u{1f44a}'),
+    ("fallen_leaf", '\u{1f342}'),
+    ("file_folder", '\u{1f4c1}'),
+    ("fire", '\u{1f525}'),
+    ("fish", '\u{1f41f}'),
+    ("fist", '\u{270a}'),
+    ("fist_oncoming", '\u{1f44a}'),
+    ("fist_raised", '\u{270a}'),
+    ("flashlight", '\u{1f526}'),
+    ("four_leaf_clover", '\u{1f340}'),
+    ("fox_face", '\u{1f98a}'),
+    ("free", '\u{1f193}'),
+    ("frog", '\u{1f438}'),
+    ("full_moon", '\u{1f315}'),
+    ("gear", '\u{2699}'),
+    ("gem", '\u{1f48e}'),
+    ("ghost", '\u{1f47b}'),
+    ("gift", '\u{1f381}'),
+    ("globe_with_meridians", '\u{1f310}'),
+    ("green_heart", '\u{1f49a}'),
+    ("grimacing", '\u{1f62c}'),
+    ("grin", '\u{1f601}'),
+    ("grinning", '\u{1f600}'),
+    ("hamburger", '\u{1f354}'),
+    ("hammer", '\u{1f528}'),
+    ("hankey", '\u{1f4a9}'),
+    ("headphones", '\u{1f3a7}'),
+    ("heart", '\u{2764}'),
+    ("heart_eyes", '\u{1f60d}'),
+    ("hearts", '\u{2665}'),
+    ("heavy_check_mark", '\u{2714}'),
+    ("heavy_exclamation_mark", '\u{2757}'),
+    ("heavy_minus_sign", '\u{2796}'),
+    ("heavy_plus_sign", '\u{2795}'),
+    ("high_brightness", '\u{1f506}'),
+    ("honeybee", '\u{1f41d}'),
+    ("hospital", '\u{1f3e5}'),
+    ("hourglass", '\u{231b}'),
+    ("hourglass_flowing_sand", '\u{23f3}'),
+    ("house", '\u{1f3e0}'),
+    ("inbox_tray", '\u{1f4e5}'),
+    ("infinity", '\u{267e}'),
+    ("information_source", '\u{2139}'),
+    ("innocent", '\u{1f607}'),
+    ("iphone", '\u{1f4f1}'),
+    ("jigsaw", '\u{1f9e9}'),
+    ("joy", '\u{1f602}'),
+    ("key", '\u{1f511}'),
+    ("keyboard", '\u{2328}'),
+    ("kissing_heart", '\u{1f618}'),
+    ("large_blue_circle", '\u{1f535}'),
+    ("laughing", '\u{1f606}'),
+    ("link", '\u{1f517}'),
+    ("lipstick", '\u{1f484}'),
+    ("lock", '\u{1f512}'),
+    ("loud_sound", '\u{1f50a}'),
+    ("low_brightness", '\u{1f505}'),
+    ("mag", '\u{1f50d}'),
+    ("mag_right", '\u{1f50e}'),
+    ("mailbox", '\u{1f4eb}'),
+    ("mask", '\u{1f637}'),
+    ("memo", '\u{1f4dd}'),
+    ("microphone", '\u{1f3a4}'),
+    ("microscope", '\u{1f52c}'),
+    ("moneybag", '\u{1f4b0}'),
+    ("monkey_face", '\u{1f435}'),
+    ("mountain", '\u{26f0}'),
+    ("mouse", '\u{1f42d}'),
+    ("muscle", '\u{1f4aa}'),
+    ("mushroom", '\u{1f344}'),
+    ("musical_note", '\u{1f3b5}'),
+    ("mute", '\u{1f507}'),
+    ("nerd_face", '\u{1f913}'),
+    ("neutral_face", '\u{1f610}'),
+    ("new", '\u{1f195}'),
+    ("new_moon", '\u{1f311}'),
+    ("newspaper", '\u{1f4f0}'),
+    ("no_bell", '\u{1f515}'),
+    ("no_entry", '\u{26d4}'),
+    ("no_mouth", '\u{1f636}'),
+    ("ocean", '\u{1f30a}'),
+    ("octopus", '\u{1f419}'),
+    ("office", '\u{1f3e2}'),
+    ("ok", '\u{1f197}'),
+    ("ok_hand", '\u{1f44c}'),
+    ("open_book", '\u{1f4d6}'),
+    ("open_file_folder", '\u{1f4c2}'),
+    ("outbox_tray", '\u{1f4e4}'),
+    ("package", '\u{1f4e6}'),
+    ("panda_face", '\u{1f43c}'),
+    ("paperclip", '\u{1f4ce}'),
+    ("peace_symbol", '\u{262e}'),
+    ("pencil", '\u{1f4dd}'),
+    ("pencil2", '\u{270f}'),
+    ("penguin", '\u{1f427}'),
+    ("pensive", '\u{1f614}'),
+    ("pill", '\u{1f48a}'),
+    ("pizza", '\u{1f355}'),
+    ("point_down", '\u{1f447}'),
+    ("point_left", '\u{1f448}'),
+    ("point_right", '\u{1f449}'),
+    ("point_up", '\u{261d}'),
+    ("point_up_2", '\u{1f446}'),
+    ("poop", '\u{1f4a9}'),
+    ("pout", '\u{1f621}'),
+    ("pray", '\u{1f64f}'),
+    ("printer", '\u{1f5a8}'),
+    ("punch", '\u{1f44a}'),
+    ("purple_heart", '\u{1f49c}'),
+    ("pushpin", '\u{1f4cc}'),
+    ("question", '\u{2753}'),
+    ("rabbit", '\u{1f430}'),
+    ("radioactive", '\u{2622}'),
+    ("rage", '\u{1f621}'),
+    ("rainbow", '\u{1f308}'),
+    ("raised_hands", '\u{1f64c}'),
+    ("recycle", '\u{267b}'),
+    ("red_car", '\u{1f697}'),
+    ("red_circle", '\u{1f534}'),
+    ("registered", '\u{ae}'),
+    ("relaxed", '\u{263a}'),
+    ("relieved", '\u{1f60c}'),
+    ("repeat", '\u{1f501}'),
+    ("robot", '\u{1f916}'),
+    ("rocket", '\u{1f680}'),
+    ("rofl", '\u{1f923}'),
+    ("roll_eyes", '\u{1f644}'),
+    ("rose", '\u{1f339}'),
+    ("rotating_light", '\u{1f6a8}'),
+    ("satellite", '\u{1f4e1}'),
+    ("satisfied", '\u{1f606}'),
+    ("school", '\u{1f3eb}'),
+    ("scissors", '\u{2702}'),
+    ("scream", '\u{1f631}'),
+    ("see_no_evil", '\u{1f648}'),
+    ("seedling", '\u{1f331}'),
+    ("shield", '\u{1f6e1}'),
+    ("ship", '\u{1f6a2}'),
+    ("shit", '\u{1f4a9}'),
+    ("signal_strength", '\u{1f4f6}'),
+    ("skull", '\u{1f480}'),
+    ("sleeping", '\u{1f634}'),
+    ("sleepy", '\u{1f62a}'),
+    ("slightly_smiling_face", '\u{1f642}'),
+    ("smile", '\u{1f604}'),
+    ("smiley", '\u{1f603}'),
+    ("smirk", '\u{1f60f}'),
+    ("snake", '\u{1f40d}'),
+    ("snowflake", '\u{2744}'),
+    ("snowman", '\u{26c4}'),
+    ("sob", '\u{1f62d}'),
+    ("soccer", '\u{26bd}'),
+    ("sos", '\u{1f198}'),
+    ("spades", '\u{2660}'),
+    ("sparkles", '\u{2728}'),
+    ("sparkling_heart", '\u{1f496}'),
+    ("speaker", '\u{1f508}'),
+    ("speech_balloon", '\u{1f4ac}'),
+    ("star", '\u{2b50}'),
+    ("star2", '\u{1f31f}'),
+    ("stopwatch", '\u{23f1}'),
+    ("stuck_out_tongue", '\u{1f61b}'),
+    ("stuck_out_tongue_winking_eye", '\u{1f61c}'),
+    ("sunflower", '\u{1f33b}'),
+    ("sunglasses", '\u{1f60e}'),
+    ("sunny", '\u{2600}'),
+    ("sweat_smile", '\u{1f605}'),
+    ("syringe", '\u{1f489}'),
+    ("tada", '\u{1f389}'),
+    ("telescope", '\u{1f52d}'),
+    ("thermometer", '\u{1f321}'),
+    ("thinking", '\u{1f914}'),
+    ("thought_balloon", '\u{1f4ad}'),
+    ("thumbsdown", '\u{1f44e}'),
+    ("thumbsup", '\u{1f44d}'),
+    ("tm", '\u{2122}'),
+    ("trophy", '\u{1f3c6}'),
+    ("tshirt", '\u{1f455}'),
+    ("turtle", '\u{1f422}'),
+    ("tv", '\u{1f4fa}'),
+    ("umbrella", '\u{2614}'),
+    ("unamused", '\u{1f612}'),
+    ("unicorn", '\u{1f984}'),
+    ("unlock", '\u{1f513}'),
+    ("upside_down_face", '\u{1f643}'),
+    ("v", '\u{270c}'),
+    ("video_game", '\u{1f3ae}'),
+    ("warning", '\u{26a0}'),
+    ("wastebasket", '\u{1f5d1}'),
+    ("watch", '\u{231a}'),
+    ("wave", '\u{1f44b}'),
+    ("whale", '\u{1f433}'),
+    ("white_check_mark", '\u{2705}'),
+    ("white_circle", '\u{26aa}'),
+    ("wink", '\u{1f609}'),
+    ("worried", '\u{1f61f}'),
+    ("wrench", '\u{1f527}'),
+    ("x", '\u{274c}'),
+    ("yellow_heart", '\u{1f49b}'),
+    ("yin_yang", '\u{262f}'),
+    ("yum", '\u{1f60b}'),
+    ("zap", '\u{26a1}'),
+    ("zzz", '\u{1f4a4}'),
+];
+
+/// The shortcodes of the emoji `icons` draw, sorted, with the index of the first icon of the
+/// emoji's codepoint. Fonts without emoji, the icon fonts, have none.
+pub(crate) fn table(icons: &[Icon<'_>]) -> Vec<(&'static str, usize)> {
+    GEMOJI
+        .iter()
+        .filter_map(|&(shortcode, c)| {
+            let index = icons.iter().position(|icon| icon.codepoint == c)?;
+            Some((shortcode, index))
+        })
+        .collect()
+}
+
+/// Returns `replace_shortcodes`, nothing for iced 0.12, whose text has no spans yet.
+pub(crate) fn tokens() -> TokenStream {
+    if cfg!(feature = "iced_0_12") {
+        return quote! {};
+    }
+
+    quote! {
+        /// Splits `text` into spans for `rich_text`, with the icons of shortcodes like
+        /// `:house:` in their font and everything else as plain text. Shortcodes of icons the
+        /// font doesn't have stay text.
+        #[must_use]
+        pub fn replace_shortcodes<'a, Link>(
+            text: &'a str,
+        ) -> Vec<iced_widget::core::text::Span<'a, Link>> {
+            use iced_widget::core::text::Span;
+
+            let mut spans = Vec::new();
+            // Where the text that isn't in a span yet starts, and where to look for colons.
+            let mut plain = 0;
+            let mut next = 0;
+            while let Some(open) = text[next..].find(':').map(|i| next + i) {
+                let Some(close) = text[open + 1..].find(':').map(|i| open + 1 + i) else {
+                    break;
+                };
+
+                match Icon::from_shortcode(&text[open..=close]) {
+                    Some(icon) => {
+                        if plain < open {
+                            spans.push(Span::new(&text[plain..open]));
+                        }
+                        spans.push(Span::new(icon.char()).font(icon.font()));
+                        plain = close + 1;
+                        next = close + 1;
+                    }
+                    // The closing colon may open the next shortcode, like in `at 10:30 :clock:`.
+                    None => next = close,
+                }
+            }
+            if plain < text.len() {
+                spans.push(Span::new(&text[plain..]));
+            }
+
+            spans
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GEMOJI;
+
+    #[test]
+    fn gemoji_is_sorted() {
+        assert!(GEMOJI.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
@@ -0,0 +1,172 @@
+//! Snapshots of the generated icon names, so font updates that rename or move icons fail a test
+//! instead of breaking the code of everyone using them.
+//!
+//! A snapshot is a text file with a line per icon, the codepoint in hex, a tab and the name of the
+//! function. Building with `ICED_FONTS_SNAPSHOT=overwrite` writes the current icons to it.
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+use crate::icons::Icon;
+use crate::{include_bytes, resolve_path};
+
+/// How many differences the failing test lists before it stops.
+const SHOWN_DIFFERENCES: usize = 20;
+
+/// Returns a test module that fails when `icons` differ from the snapshot at `path`.
+pub(crate) fn check(path: &LitStr, icons: &[Icon<'_>]) -> syn::Result<TokenStream> {
+    let path_str = path.value();
+    let full_path = resolve_path(&path_str);
+    let current: String = icons
+        .iter()
+        .map(|icon| format!("{:x}\t{}\n", u32::from(icon.codepoint), icon.name))
+        .collect();
+
+    if std::env::var_os("ICED_FONTS_SNAPSHOT").is_some_and(|value| value == "overwrite") {
+        if let Some(parent) = full_path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        std::fs::write(&full_path, &current).map_err(|e| {
+            syn::Error::new(
+                path.span(),
+                format!("failed to write snapshot `{path_str}`: {e}"),
+            )
+        })?;
+    }
+
+    let differences = match std::fs::read_to_string(&full_path) {
+        Ok(snapshot) => differences(&snapshot, &current),
+        Err(e) => vec![format!(
+            "failed to read `{path_str}`: {e}, build with `ICED_FONTS_SNAPSHOT=overwrite` to create it"
+        )],
+    };
+
+    // Makes cargo rebuild the invoking crate when the snapshot or the variable changes.
+    let track_file = full_path.exists().then(|| {
+        let include = include_bytes(&path_str);
+        quote! { const _: &[u8] = #include; }
+    });
+    let track = quote! {
+        #track_file
+        const _: Option<&str> = option_env!("ICED_FONTS_SNAPSHOT");
+    };
+
+    let mismatch = if differences.is_empty() {
+        quote! {}
+    } else {
+        let mut message = format!("the icons changed compared to the snapshot `{path_str}`:\n");
+        for difference in differences.iter().take(SHOWN_DIFFERENCES) {
+            message.push_str(difference);
+            message.push('\n');
+        }
+        if differences.len() > SHOWN_DIFFERENCES {
+            message.push_str(&format!(
+                "and {} more\n",
+                differences.len() - SHOWN_DIFFERENCES
+            ));
+        }
+        message.push_str("if this is intended, build with `ICED_FONTS_SNAPSHOT=overwrite`");
+
+        quote! {
+            #[test]
+            fn icons_match_snapshot() {
+                panic!("{}", #message);
+            }
+        }
+    };
+
+    // Besides the names, the lookups of the `Icon` enum are checked with every icon.
+    Ok(quote! {
+        #track
+
+        #[cfg(test)]
+        mod snapshot {
+            use super::Icon;
+
+            #mismatch
+
+            #[test]
+            fn values_are_codepoints() {
+                for icon in Icon::ALL {
+                    let value = icon as u32;
+                    assert_eq!(Icon::from_repr(value), Some(icon));
+                    assert_eq!(Icon::ALL[icon.index()], icon);
+                    // Icons of merged fonts sharing a codepoint have values past `char::MAX`.
+                    if let Some(c) = char::from_u32(value) {
+                        assert_eq!(c, icon.char());
+                        assert_eq!(Icon::from_char(c), Some(icon));
+                    }
+                }
+                assert_eq!(Icon::from_repr(u32::MAX), None);
+            }
+
+            #[test]
+            fn chars_round_trip() {
+                // The first icon of every character, merged fonts can share one.
+                let mut first = std::collections::HashMap::new();
+                for icon in Icon::ALL {
+                    first.entry(icon.char()).or_insert(icon);
+                }
+                for icon in Icon::ALL {
+                    assert_eq!(Icon::from_char(icon.char()), first.get(&icon.char()).copied());
+                }
+                const NUL: Option<Icon> = Icon::from_char('\0');
+                assert_eq!(NUL, None);
+            }
+
+            #[test]
+            fn ids_are_the_font_and_codepoint() {
+                for icon in Icon::ALL {
+                    assert_eq!(icon.id() & 0x1f_ffff, icon.char() as u32);
+                    assert_eq!(Icon::from_id(icon.id()), Some(icon));
+                }
+                assert_eq!(Icon::from_id(u32::MAX), None);
+            }
+
+            #[test]
+            fn names_are_found() {
+                for icon in Icon::ALL {
+                    assert_eq!(Icon::from_name(icon.name()), Some(icon));
+                }
+                assert_eq!(Icon::from_name(""), None);
+                assert_eq!(Icon::from_name("not an icon"), None);
+            }
+
+            #[test]
+            fn search_finds_names_however_they_are_written() {
+                // Every search goes through all icons, a sample of them keeps the big fonts fast.
+                for icon in Icon::ALL.into_iter().step_by(Icon::ALL.len() / 16 + 1) {
+                    let name = icon.name().trim_start_matches("r#");
+                    for query in [name.to_uppercase(), name.replace('_', " "), name.replace('_', "-")] {
+                        assert!(Icon::search(&query).any(|found| found == icon), "{query:?}");
+                    }
+                }
+                // The icons come in the order of `ALL`, each once, and an empty query finds all.
+                let found: Vec<usize> = Icon::search("a").map(Icon::index).collect();
+                assert!(found.windows(2).all(|pair| pair[0] < pair[1]));
+                assert_eq!(Icon::search("").count(), Icon::ALL.len());
+                assert_eq!(Icon::search("not an icon at all").next(), None);
+            }
+        }
+    })
+}
+
+/// Lines of the form `- e001 name` for icons missing from `current` and `+ e001 name` for new ones.
+fn differences(snapshot: &str, current: &str) -> Vec<String> {
+    let snapshot_lines: HashSet<&str> = snapshot.lines().collect();
+    let current_lines: HashSet<&str> = current.lines().collect();
+
+    let removed = snapshot
+        .lines()
+        .filter(|line| !current_lines.contains(line))
+        .map(|line| format!("- {}", line.replace('\t', " ")));
+    let added = current
+        .lines()
+        .filter(|line| !snapshot_lines.contains(line))
+        .map(|line| format!("+ {}", line.replace('\t', " ")));
+
+    removed.chain(added).collect()
+}
@@ -0,0 +1,52 @@
+//! The `spinners` option, loading indicators as the characters of their frames, named by the
+//! icons of the frames.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::Context;
+
+/// Returns the `spinners` module with a constant per spinner and `spinner_frames`, nothing without
+/// the option.
+pub(crate) fn tokens(context: &Context<'_>) -> syn::Result<TokenStream> {
+    let spinners = &context.options.spinners;
+    let Some((first, _)) = spinners.first() else {
+        return Ok(quote! {});
+    };
+
+    let mut constants = Vec::with_capacity(spinners.len());
+    for (name, frames) in spinners {
+        let chars = frames
+            .iter()
+            .map(|frame| {
+                let i = context
+                    .position(&frame.value())
+                    .ok_or_else(|| context.no_icon(frame.span(), &frame.value()))?;
+                Ok(context.icons[i].codepoint)
+            })
+            .collect::<syn::Result<Vec<char>>>()?;
+
+        let constant = Ident::new(&name.to_string().to_uppercase(), name.span());
+        let doc = format!(" The {} frames of the `{name}` spinner.", chars.len());
+        constants.push(quote! {
+            #[doc = #doc]
+            pub const #constant: &[char] = &[#(#chars),*];
+        });
+    }
+
+    let first_constant = Ident::new(&first.to_string().to_uppercase(), first.span());
+    let doc = format!(" The frames of the `{first}` spinner, the first in [`spinners`].");
+    Ok(quote! {
+        /// Frame sequences of loading indicators, for the `Spinner` widget of `iced_fonts`
+        /// or text UIs. Every frame is drawn with the font of the module.
+        pub mod spinners {
+            #(#constants)*
+        }
+
+        #[doc = #doc]
+        #[must_use]
+        pub const fn spinner_frames() -> &'static [char] {
+            spinners::#first_constant
+        }
+    })
+}
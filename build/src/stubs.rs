@@ -0,0 +1,37 @@
+//! Stubs for rust-analyzer. With `ICED_FONTS_RA_STUBS` set the macros generate the items of a
+//! module with their signatures but without docs, function bodies or the contents of the tables,
+//! so expanding the big fonts doesn't hold up the editor.
+//!
+//! Stubs don't work, so a build other than rust-analyzer's fails with a `compile_error!` instead
+//! of running them. rust-analyzer sets `cfg(rust_analyzer)`, real builds don't.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The environment variable turning stubs on.
+const VARIABLE: &str = "ICED_FONTS_RA_STUBS";
+
+/// Whether to generate stubs, which is when the variable is set to anything but `0`.
+pub(crate) fn enabled() -> bool {
+    std::env::var_os(VARIABLE).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Makes cargo rebuild the invoking crate when the variable changes, and fails builds other than
+/// rust-analyzer's when `enabled`.
+pub(crate) fn tokens(enabled: bool) -> TokenStream {
+    let guard = enabled.then(|| {
+        let message = format!(
+            "`{VARIABLE}` is set, which only rust-analyzer should see, unset it or set it to `0` \
+             to build"
+        );
+        quote! {
+            #[cfg(not(rust_analyzer))]
+            compile_error!(#message);
+        }
+    });
+
+    quote! {
+        #guard
+        const _: Option<&str> = option_env!(#VARIABLE);
+    }
+}
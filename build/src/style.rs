@@ -0,0 +1,85 @@
+//! The filled and outlined versions of icons, paired by name like `house` and `house_fill` or
+//! `home` and `home_outline`, for `IconStyle` in `iced_fonts`.
+
+use std::collections::HashMap;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::icons::Icon;
+use crate::names;
+
+/// The endings of the names of filled icons.
+const FILL: [&str; 3] = ["_fill", "_filled", "_solid"];
+/// The endings of the names of outlined icons.
+const OUTLINE: [&str; 2] = ["_outline", "_outlined"];
+
+/// Returns `Icon::fill` and `Icon::outline`, switching every icon to its filled or outlined
+/// version. The names of the icons without the affixes pair them.
+pub(crate) fn tokens(icons: &[Icon<'_>]) -> TokenStream {
+    let positions: HashMap<&str, usize> = icons
+        .iter()
+        .enumerate()
+        .map(|(i, icon)| (icon.only_name.as_str(), i))
+        .collect();
+    let position = |name: &str| positions.get(name).copied();
+    let variant = |i: usize| Ident::new(&names::variant(&icons[i].name), Span::call_site());
+
+    let mut fill_arms = Vec::new();
+    let mut outline_arms = Vec::new();
+    for (i, name) in icons.iter().map(|icon| &icon.only_name).enumerate() {
+        let base = FILL
+            .iter()
+            .chain(&OUTLINE)
+            .find_map(|ending| name.strip_suffix(ending))
+            .unwrap_or(name);
+        let is = |endings: &[&str]| endings.iter().any(|ending| name.ends_with(ending));
+        // Another version of the icon, `house_fill` for `house`, or the plain `home` the font
+        // only has an outline of for `home_outline`.
+        let other = |endings: &[&str], others: &[&str]| {
+            if is(endings) {
+                return None;
+            }
+            endings
+                .iter()
+                .find_map(|ending| position(&format!("{base}{ending}")))
+                .or_else(|| if is(others) { position(base) } else { None })
+        };
+
+        let from = variant(i);
+        if let Some(fill) = other(&FILL, &OUTLINE) {
+            let to = variant(fill);
+            fill_arms.push(quote! { Icon::#from => Icon::#to, });
+        }
+        if let Some(outline) = other(&OUTLINE, &FILL) {
+            let to = variant(outline);
+            outline_arms.push(quote! { Icon::#from => Icon::#to, });
+        }
+    }
+
+    quote! {
+        impl Icon {
+            /// The filled version of the icon, `house_fill` for `house` or `home` for
+            /// `home_outline`. Icons without one stay as they are.
+            #[must_use]
+            pub const fn fill(self) -> Icon {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#fill_arms)*
+                    _ => self,
+                }
+            }
+
+            /// The outlined version of the icon, `home_outline` for `home` or `house` for
+            /// `house_fill`. Icons without one stay as they are.
+            #[must_use]
+            pub const fn outline(self) -> Icon {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#outline_arms)*
+                    _ => self,
+                }
+            }
+        }
+    }
+}
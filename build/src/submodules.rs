@@ -0,0 +1,58 @@
+//! The `submodules` option, groups of a big font in a submodule of their own for code completion,
+//! re-exporting the functions under their names without the group's prefix.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::Context;
+
+/// Returns a submodule for every group of the option, none with `data_only`.
+pub(crate) fn tokens(context: &Context<'_>) -> syn::Result<TokenStream> {
+    let options = context.options;
+    let mut submodules = TokenStream::new();
+    for group in options.submodules.iter().filter(|_| !context.data_only) {
+        let module = syn::parse_str::<Ident>(&group.value()).map_err(|_| {
+            syn::Error::new(group.span(), "the submodule name has to be an identifier")
+        })?;
+        let prefix = format!("{}_", group.value());
+
+        let reexports: Vec<_> = context
+            .icons
+            .iter()
+            .filter_map(|icon| {
+                let stripped = icon.only_name.strip_prefix(&prefix)?;
+                // Names that can't be identifiers on their own keep the prefix.
+                let alias = if stripped.starts_with(|c: char| c.is_ascii_digit())
+                    || matches!(stripped, "self" | "super" | "crate" | "_")
+                {
+                    icon.name.clone()
+                } else {
+                    format!("{}{stripped}{}", options.prefix, options.suffix)
+                };
+                let name = Ident::new_raw(&icon.name, Span::call_site());
+                let alias = Ident::new_raw(&alias, Span::call_site());
+                Some(quote! { pub use super::#name as #alias; })
+            })
+            .collect();
+        if reexports.is_empty() {
+            return Err(syn::Error::new(
+                group.span(),
+                format!(
+                    "no icon name in `{}` starts with `{prefix}`",
+                    context.font_path
+                ),
+            ));
+        }
+
+        let doc =
+            format!(" The icons whose names start with `{prefix}`, under their names without it.");
+        submodules.extend(quote! {
+            #[doc = #doc]
+            pub mod #module {
+                #(#reexports)*
+            }
+        });
+    }
+
+    Ok(submodules)
+}
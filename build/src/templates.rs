@@ -0,0 +1,119 @@
+//! The options naming a set of icons with one template like `"{}_circle"`, `number_badges` for
+//! the digits and `arrows` and `chevrons` for the directions.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+use crate::{Context, Output, names};
+
+/// Returns `Icon::DIGITS`, `Icon::number_badge` and the `number_badge` widget, nothing without the
+/// `number_badges` option.
+pub(crate) fn number_badges(context: &Context<'_>) -> syn::Result<TokenStream> {
+    let Context {
+        shaping_parameter,
+        shaping,
+        ..
+    } = context;
+    let Some(template) = &context.options.number_badges else {
+        return Ok(quote! {});
+    };
+
+    // Counters are spelled with the icons of their digits, which the template names.
+    let digits = names::DIGIT_NAMES
+        .iter()
+        .enumerate()
+        .map(|(digit, word)| template_icon(context, template, &[word, &digit.to_string()]))
+        .collect::<syn::Result<Vec<usize>>>()?;
+
+    let track_digits = if context.tracks_usage {
+        quote! {
+            for icon in &icons {
+                track(icon.index());
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let widget = match context.options.output {
+        Output::Widget => quote! {
+            /// A text widget of `n` for notification counts, drawn with the icon of each
+            /// of its digits.
+            #[must_use]
+            pub fn number_badge<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+                n: u8,
+                #shaping_parameter
+            ) -> Text<'a, Theme, Renderer> {
+                let icons = Icon::number_badge(n);
+                #track_digits
+                let digits: String = icons.into_iter().map(Icon::char).collect();
+                iced_widget::text(digits)
+                    .font(Icon::DIGITS[0].font())
+                    .shaping(#shaping)
+            }
+        },
+        Output::Char | Output::Str => quote! {},
+    };
+
+    Ok(quote! {
+        impl Icon {
+            /// The icons of the digits 0 to 9, from the `number_badges` option.
+            pub const DIGITS: [Icon; 10] = [#(Self::ALL[#digits]),*];
+
+            /// The icons spelling `n` digit by digit, one for counts below 10.
+            #[must_use]
+            pub fn number_badge(n: u8) -> Vec<Icon> {
+                n.to_string()
+                    .bytes()
+                    .map(|digit| Self::DIGITS[usize::from(digit - b'0')])
+                    .collect()
+            }
+        }
+
+        #widget
+    })
+}
+
+/// Returns `Icon::ARROWS` and `Icon::CHEVRONS`, the icons pointing every way for `Direction` in
+/// `iced_fonts`, for the options there are.
+pub(crate) fn directions(context: &Context<'_>) -> syn::Result<TokenStream> {
+    let options = context.options;
+    let mut tokens = TokenStream::new();
+    for (template, constant, what) in [
+        (&options.arrows, quote! { ARROWS }, "arrows"),
+        (&options.chevrons, quote! { CHEVRONS }, "chevrons"),
+    ] {
+        let Some(template) = template else {
+            continue;
+        };
+        let icons = ["up", "down", "left", "right"]
+            .map(|direction| template_icon(context, template, &[direction]))
+            .into_iter()
+            .collect::<syn::Result<Vec<usize>>>()?;
+
+        let doc =
+            format!(" The {what} pointing up, down, left and right, from the `{what}` option.");
+        tokens.extend(quote! {
+            impl Icon {
+                #[doc = #doc]
+                pub const #constant: [Icon; 4] = [#(Self::ALL[#icons]),*];
+            }
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// The index of the icon a template like `"{}_circle"` names, with `{}` replaced by the first of
+/// `fills` the font has an icon for.
+fn template_icon(context: &Context<'_>, template: &LitStr, fills: &[&str]) -> syn::Result<usize> {
+    let names: Vec<String> = fills
+        .iter()
+        .map(|fill| template.value().replace("{}", fill))
+        .collect();
+
+    names
+        .iter()
+        .find_map(|name| context.position(name))
+        .ok_or_else(|| context.no_icon(template.span(), &names[0]))
+}
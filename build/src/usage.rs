@@ -0,0 +1,51 @@
+//! The `track_usage` feature, which records the icons whose widgets were created so an app can
+//! trim the module down to them with the `only` option.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::Context;
+
+/// Returns `track` and the `used_icons` and `usage_report` functions, nothing without tracking.
+pub(crate) fn tokens(context: &Context<'_>) -> TokenStream {
+    if !context.tracks_usage {
+        return quote! {};
+    }
+
+    let only_names = context.icons.iter().map(|icon| &icon.only_name);
+    quote! {
+        /// Whether the widget of each icon was created since the program started, by the
+        /// icon's index in `Icon::ALL`.
+        static USED: [std::sync::atomic::AtomicBool; COUNT] =
+            [const { std::sync::atomic::AtomicBool::new(false) }; COUNT];
+
+        fn track(index: usize) {
+            USED[index].store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        /// The icons whose widgets were created since the program started.
+        pub fn used_icons() -> Vec<Icon> {
+            Icon::ALL
+                .into_iter()
+                .filter(|icon| USED[icon.index()].load(std::sync::atomic::Ordering::Relaxed))
+                .collect()
+        }
+
+        /// The `only` option listing the icons used since the program started, to trim the
+        /// module down to them.
+        pub fn usage_report() -> String {
+            const ONLY_NAMES: [&str; COUNT] = [#(#only_names),*];
+
+            let used: Vec<String> = used_icons()
+                .into_iter()
+                .map(|icon| format!("{:?}", ONLY_NAMES[icon.index()]))
+                .collect();
+            format!(
+                "// {} of {} icons used\nonly = [{}]",
+                used.len(),
+                COUNT,
+                used.join(", ")
+            )
+        }
+    }
+}
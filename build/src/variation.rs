@@ -0,0 +1,299 @@
+//! Picking an instance of a variable font with the `instance` and `axes` options.
+//!
+//! iced selects fonts by family, weight, stretch and style, which can't express axes like the
+//! `FILL` axis of Material Symbols. So the instance becomes a static font of its own, with the
+//! outlines and advances of its coordinates, see [`crate::outlines`], and a family named after them
+//! that its `Font` selects it by.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    LitStr, Token, braced,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Comma,
+};
+use ttf_parser::{Face, Tag};
+
+use crate::outlines;
+
+/// A variable font at the coordinates of an instance, as a static font.
+pub(crate) struct Instance {
+    /// The `Font` of the instance.
+    pub(crate) font: TokenStream,
+    /// The static font, with a family of its own.
+    pub(crate) data: Vec<u8>,
+}
+
+/// Which instance of a variable font to use.
+pub(crate) enum Variation {
+    /// `instance = "Bold"`, a named instance of the font.
+    Instance(LitStr),
+    /// `axes = { wght = 700 }`, the axes not listed stay at their default.
+    Axes(Vec<(Tag, f32, Span)>),
+}
+
+impl Variation {
+    pub(crate) fn parse_axes(input: ParseStream<'_>) -> syn::Result<Self> {
+        let content;
+        braced!(content in input);
+
+        let axes =
+            Punctuated::<(Tag, f32, Span), Comma>::parse_terminated_with(&content, |input| {
+                let tag: syn::Ident = input.parse()?;
+                let _: Token![=] = input.parse()?;
+                let value: Number = input.parse()?;
+
+                let name = tag.to_string();
+                if name.len() > 4 {
+                    return Err(syn::Error::new(
+                        tag.span(),
+                        "axis tags have up to 4 letters",
+                    ));
+                }
+                let mut bytes = *b"    ";
+                bytes[..name.len()].copy_from_slice(name.as_bytes());
+
+                Ok((Tag::from_bytes(&bytes), value.0, tag.span()))
+            })?;
+
+        Ok(Self::Axes(axes.into_iter().collect()))
+    }
+
+    /// The instance of the variable font `face` as a static font, with the `Font` drawing with
+    /// it based on the constant `font_name`.
+    pub(crate) fn instance(
+        &self,
+        face: &Face<'_>,
+        font_name: &TokenStream,
+    ) -> syn::Result<Instance> {
+        let axes = face
+            .tables()
+            .fvar
+            .map(|fvar| fvar.axes)
+            .ok_or_else(|| syn::Error::new(self.span(), "the font isn't a variable font"))?;
+        if face.tables().glyf.is_none() {
+            return Err(syn::Error::new(
+                self.span(),
+                "only variable fonts with TrueType outlines can be instanced, not CFF2 ones",
+            ));
+        }
+
+        // Every axis at its default, then overwritten by the instance.
+        let mut coordinates: Vec<(Tag, f32)> =
+            axes.into_iter().map(|a| (a.tag, a.def_value)).collect();
+        let suffix = match self {
+            Self::Instance(name) => {
+                let instance = named_instances(face)
+                    .into_iter()
+                    .find(|(instance, _)| *instance == name.value())
+                    .ok_or_else(|| {
+                        let names: Vec<String> = named_instances(face)
+                            .into_iter()
+                            .map(|(name, _)| format!("`{name}`"))
+                            .collect();
+                        syn::Error::new(
+                            name.span(),
+                            format!(
+                                "the font has no instance named `{}`, it has {}",
+                                name.value(),
+                                names.join(", ")
+                            ),
+                        )
+                    })?;
+                coordinates = instance.1;
+                name.value()
+            }
+            Self::Axes(settings) => {
+                for (tag, value, span) in settings {
+                    let axis = axes.into_iter().find(|a| a.tag == *tag).ok_or_else(|| {
+                        syn::Error::new(*span, format!("the font has no `{tag}` axis"))
+                    })?;
+                    if !(axis.min_value..=axis.max_value).contains(value) {
+                        return Err(syn::Error::new(
+                            *span,
+                            format!("`{tag}` goes from {} to {}", axis.min_value, axis.max_value),
+                        ));
+                    }
+
+                    for coordinate in coordinates.iter_mut().filter(|(t, _)| t == tag) {
+                        coordinate.1 = *value;
+                    }
+                }
+                settings
+                    .iter()
+                    .map(|(tag, value, _)| format!("{}{value}", tag.to_string().trim_end()))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }
+        };
+
+        let mut fields = TokenStream::new();
+        let (mut weight_class, mut width_class) = (None, None);
+        for (tag, value) in &coordinates {
+            match &tag.to_bytes() {
+                b"wght" => {
+                    let weight = weight(*value);
+                    fields.extend(quote! { weight: iced_widget::core::font::Weight::#weight, });
+                    weight_class = Some(value.round().clamp(1.0, 1000.0) as u16);
+                }
+                b"wdth" => {
+                    let (class, stretch) = stretch(*value);
+                    fields.extend(quote! { stretch: iced_widget::core::font::Stretch::#stretch, });
+                    width_class = Some(class);
+                }
+                b"ital" if *value >= 0.5 => {
+                    fields.extend(quote! { style: iced_widget::core::font::Style::Italic, });
+                }
+                b"slnt" if *value != 0.0 => {
+                    fields.extend(quote! { style: iced_widget::core::font::Style::Oblique, });
+                }
+                _ => {}
+            }
+        }
+
+        let mut instance_face = face.clone();
+        for (tag, value) in &coordinates {
+            instance_face.set_variation(*tag, *value);
+        }
+        // A family of its own, so iced doesn't confuse the instance with the variable font or
+        // other instances of it.
+        let family = format!(
+            "{} {suffix}",
+            crate::font_families(face)
+                .into_iter()
+                .next()
+                .unwrap_or_else(|| font_name.to_string())
+        );
+        let data = outlines::instance(&instance_face, &family, weight_class, width_class).map_err(
+            |e| syn::Error::new(self.span(), format!("failed to instance the font: {e}")),
+        )?;
+
+        Ok(Instance {
+            font: quote! {
+                iced_widget::core::Font {
+                    family: iced_widget::core::font::Family::Name(#family),
+                    #fields
+                    ..#font_name
+                }
+            },
+            data,
+        })
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Self::Instance(name) => name.span(),
+            Self::Axes(axes) => axes
+                .first()
+                .map_or_else(Span::call_site, |(_, _, span)| *span),
+        }
+    }
+}
+
+/// An integer or float literal as `f32`.
+struct Number(f32);
+
+impl Parse for Number {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if input.peek(syn::LitFloat) {
+            Ok(Self(input.parse::<syn::LitFloat>()?.base10_parse()?))
+        } else {
+            Ok(Self(input.parse::<syn::LitInt>()?.base10_parse()?))
+        }
+    }
+}
+
+/// The named instances of the `fvar` table with their coordinates. `ttf-parser` only parses the
+/// axes, the instances follow right after them.
+fn named_instances(face: &Face<'_>) -> Vec<(String, Vec<(Tag, f32)>)> {
+    let Some(fvar) = face.raw_face().table(Tag::from_bytes(b"fvar")) else {
+        return Vec::new();
+    };
+    let read_u16 = |offset: usize| {
+        fvar.get(offset..offset + 2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+    };
+    let read_fixed = |offset: usize| {
+        fvar.get(offset..offset + 4).map(|bytes| {
+            i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32 / 65536.0
+        })
+    };
+
+    let (Some(axes_offset), Some(axis_count), Some(axis_size), Some(count), Some(size)) = (
+        read_u16(4),
+        read_u16(8),
+        read_u16(10),
+        read_u16(12),
+        read_u16(14),
+    ) else {
+        return Vec::new();
+    };
+    let tags: Vec<Tag> = (0..usize::from(axis_count))
+        .filter_map(|i| {
+            let offset = usize::from(axes_offset) + i * usize::from(axis_size);
+            Some(Tag::from_bytes(
+                fvar.get(offset..offset + 4)?.try_into().ok()?,
+            ))
+        })
+        .collect();
+
+    let start = usize::from(axes_offset) + usize::from(axis_count) * usize::from(axis_size);
+    (0..usize::from(count))
+        .filter_map(|i| {
+            let offset = start + i * usize::from(size);
+            let name_id = read_u16(offset)?;
+            let name = face
+                .names()
+                .into_iter()
+                .filter(|name| name.name_id == name_id)
+                .find_map(|name| name.to_string())?;
+
+            let coordinates = tags
+                .iter()
+                .enumerate()
+                .map(|(axis, tag)| Some((*tag, read_fixed(offset + 4 + axis * 4)?)))
+                .collect::<Option<_>>()?;
+            Some((name, coordinates))
+        })
+        .collect()
+}
+
+/// The closest `iced::font::Weight` to a `wght` value.
+pub(crate) fn weight(value: f32) -> syn::Ident {
+    let names = [
+        "Thin",
+        "ExtraLight",
+        "Light",
+        "Normal",
+        "Medium",
+        "Semibold",
+        "Bold",
+        "ExtraBold",
+        "Black",
+    ];
+    let index = ((value / 100.0).round() as usize).clamp(1, 9) - 1;
+    syn::Ident::new(names[index], Span::call_site())
+}
+
+/// The closest `iced::font::Stretch` to a `wdth` value, a percentage of the normal width, with
+/// its `usWidthClass` of `OS/2`.
+fn stretch(value: f32) -> (u16, syn::Ident) {
+    let stretches = [
+        (50.0, "UltraCondensed"),
+        (62.5, "ExtraCondensed"),
+        (75.0, "Condensed"),
+        (87.5, "SemiCondensed"),
+        (100.0, "Normal"),
+        (112.5, "SemiExpanded"),
+        (125.0, "Expanded"),
+        (150.0, "ExtraExpanded"),
+        (200.0, "UltraExpanded"),
+    ];
+    let (class, (_, name)) = stretches
+        .iter()
+        .enumerate()
+        .min_by(|(_, (a, _)), (_, (b, _))| (a - value).abs().total_cmp(&(b - value).abs()))
+        .unwrap();
+    (class as u16 + 1, syn::Ident::new(name, Span::call_site()))
+}
@@ -0,0 +1,112 @@
+//! The `weights` option, the other weights and styles of the font's family, each in a file of its
+//! own, as `Font` constants like `FONT_BOLD` with the weight and style iced selects them by.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::LitStr;
+use ttf_parser::Face;
+
+use crate::{font_families, include_bytes, names, parse_face, read_font, variation};
+
+/// Returns a `Font` constant for the main font and every file of `paths`, and the bytes of the
+/// files to load them with. Empty without files.
+pub(crate) fn tokens(
+    paths: &[LitStr],
+    face: &Face<'_>,
+    font_name: &Ident,
+    font_path: &str,
+) -> syn::Result<TokenStream> {
+    if paths.is_empty() {
+        return Ok(quote! {});
+    }
+
+    let families = font_families(face);
+    let (name, font) = constant(face, font_name);
+    let mut names = vec![name.clone()];
+    let mut tokens = quote! {
+        /// The font of the icons, with the weight and style of its file.
+        pub const #name: iced_widget::core::Font = #font;
+    };
+
+    for path in paths {
+        let font_file = read_font(path)?;
+        let weight = parse_face(&font_file.data, 0, path)?;
+
+        let weight_families = font_families(&weight);
+        if !weight_families
+            .iter()
+            .any(|family| families.contains(family))
+        {
+            return Err(syn::Error::new(
+                path.span(),
+                format!(
+                    "`{}` is the family {weight_families:?} and `{font_path}` {families:?}, \
+                     only weights of the same family can be selected by weight",
+                    path.value()
+                ),
+            ));
+        }
+
+        let (name, font) = constant(&weight, font_name);
+        if names.contains(&name) {
+            return Err(syn::Error::new(
+                path.span(),
+                format!(
+                    "`{}` has the weight and style of another file, `{name}`",
+                    path.value()
+                ),
+            ));
+        }
+        names.push(name.clone());
+
+        let bytes = Ident::new(&format!("{name}_BYTES"), Span::call_site());
+        // Web fonts are embedded decoded, iced can't read them.
+        let data = if font_file.web_format.is_some() {
+            let data = proc_macro2::Literal::byte_string(&font_file.data);
+            quote! { #data }
+        } else {
+            include_bytes(&path.value())
+        };
+        let doc = format!(" The font of the icons from `{}`.", path.value());
+        let bytes_doc = format!(" The bytes of `{}`, for loading [`{name}`].", path.value());
+        tokens.extend(quote! {
+            #[doc = #doc]
+            pub const #name: iced_widget::core::Font = #font;
+
+            #[doc = #bytes_doc]
+            pub const #bytes: &[u8] = #data;
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// The name of the constant of `face`, like `FONT_BOLD` or `FONT_LIGHT_ITALIC`, and its `Font`
+/// based on the constant `font_name`.
+fn constant(face: &Face<'_>, font_name: &Ident) -> (Ident, TokenStream) {
+    let weight = variation::weight(f32::from(face.weight().to_number()));
+    let (style, suffix) = match face.style() {
+        ttf_parser::Style::Normal => (quote! { Normal }, ""),
+        ttf_parser::Style::Italic => (quote! { Italic }, "_ITALIC"),
+        ttf_parser::Style::Oblique => (quote! { Oblique }, "_OBLIQUE"),
+    };
+    // `Normal` is what fonts call `Regular`.
+    let weight_name = match weight.to_string().as_str() {
+        "Normal" => String::from("regular"),
+        weight => names::sanitize(weight, names::Digits::Keep).unwrap_or_default(),
+    };
+
+    (
+        Ident::new(
+            &format!("FONT_{}{suffix}", weight_name.to_ascii_uppercase()),
+            Span::call_site(),
+        ),
+        quote! {
+            iced_widget::core::Font {
+                weight: iced_widget::core::font::Weight::#weight,
+                style: iced_widget::core::font::Style::#style,
+                ..crate::#font_name
+            }
+        },
+    )
+}
@@ -0,0 +1,203 @@
+//! The text widgets of the icons, what the functions and `Icon` make them with and the ways icons
+//! turn into elements. Only the default `widget` output has them.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::{Context, Output};
+
+/// Returns the imports of the widgets and `make_icon`, with a `make_icon_N` for every source with
+/// icons that the functions call.
+pub(crate) fn tokens(context: &Context<'_>) -> TokenStream {
+    let Context {
+        shaping_parameter,
+        shaping,
+        ..
+    } = context;
+    if !matches!(context.options.output, Output::Widget) {
+        return quote! {};
+    }
+
+    let shaping_parameter_tail = if context.shaping_argument.is_empty() {
+        quote! {}
+    } else {
+        quote! { , #shaping_parameter }
+    };
+    // The icon functions of a source call its own `make_icon_N` instead of naming the font and
+    // shaping in each of the thousands of functions the big fonts have.
+    let make_icon_sources = context
+        .sources
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            !context.data_only && context.icons.iter().any(|icon| icon.source == *index)
+        })
+        .map(|(index, source)| {
+            let font = &source.font;
+            let make_icon = Ident::new(&format!("make_icon_{index}"), Span::call_site());
+            quote! {
+                fn #make_icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+                    c: char
+                    #shaping_parameter_tail
+                ) -> Text<'a, Theme, Renderer> {
+                    make_icon(c, #font, #shaping)
+                }
+            }
+        });
+    // iced 0.12 styles text through the `StyleSheet` trait that later became `Catalog`, the
+    // bounds are the same otherwise.
+    let catalog = if cfg!(feature = "iced_0_12") {
+        quote! { use iced_widget::text::StyleSheet as Catalog; }
+    } else {
+        quote! { use iced_widget::text::Catalog; }
+    };
+
+    quote! {
+        use iced_widget::core::text;
+        use iced_widget::core::Font;
+        use iced_widget::text::Text;
+        #catalog
+
+        /// Every icon function is a thin wrapper around this one, so the widget setup is only
+        /// compiled once per font instead of once per icon.
+        fn make_icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+            c: char,
+            font: Font,
+            shaping: text::Shaping,
+        ) -> Text<'a, Theme, Renderer> {
+            iced_widget::text(c).font(font).shaping(shaping)
+        }
+
+        #(#make_icon_sources)*
+    }
+}
+
+/// Returns `Icon::text`, `Icon::sized` and `Icon::gallery`, the methods of `Icon` making widgets.
+pub(crate) fn methods(context: &Context<'_>) -> TokenStream {
+    let Context {
+        shaping_parameter,
+        shaping_argument,
+        shaping,
+        ..
+    } = context;
+    if !matches!(context.options.output, Output::Widget) {
+        return quote! {};
+    }
+
+    let track_self = if context.tracks_usage {
+        quote! { track(self.index()); }
+    } else {
+        quote! {}
+    };
+    // The gallery needs the widgets and styling of iced 0.13 or newer.
+    let gallery = if cfg!(feature = "iced_0_12") {
+        quote! {}
+    } else {
+        quote! {
+            /// Every icon with its name in a scrollable grid below a search field, to look
+            /// through the font. Only the icons [`Icon::search`] finds for `search` are shown,
+            /// `on_search` makes the message of a new search.
+            pub fn gallery<'a, Message, Theme, Renderer>(
+                search: &str,
+                on_search: impl Fn(String) -> Message + 'a,
+                #shaping_parameter
+            ) -> iced_widget::core::Element<'a, Message, Theme, Renderer>
+            where
+                Message: Clone + 'a,
+                Theme: Catalog + iced_widget::text_input::Catalog + iced_widget::scrollable::Catalog + 'a,
+                Renderer: text::Renderer<Font = Font> + 'a,
+            {
+                let icons = Self::search(search).map(|icon| {
+                    iced_widget::column![
+                        icon.text(#shaping_argument).size(32),
+                        iced_widget::text(icon.name()).size(10),
+                    ]
+                    .width(96)
+                    .align_x(iced_widget::core::Alignment::Center)
+                    .into()
+                });
+
+                iced_widget::column![
+                    iced_widget::text_input("Search icons", search).on_input(on_search),
+                    iced_widget::scrollable(
+                        iced_widget::Row::with_children(icons)
+                            .spacing(12)
+                            .width(iced_widget::core::Length::Fill)
+                            .wrap()
+                    ),
+                ]
+                .spacing(12)
+                .padding(12)
+                .into()
+            }
+        }
+    };
+
+    quote! {
+        /// A text widget of the icon.
+        #[must_use]
+        pub fn text<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(self, #shaping_parameter) -> Text<'a, Theme, Renderer> {
+            #track_self
+            make_icon(self.char(), self.font(), #shaping)
+        }
+
+        /// A text widget of the icon at `size` in pixels, or an `IconSize` of `iced_fonts`,
+        /// raised to the [`MIN_SIZE`] the font renders well at.
+        #[must_use]
+        pub fn sized<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+            self,
+            size: impl Into<iced_widget::core::Pixels>,
+            #shaping_parameter
+        ) -> Text<'a, Theme, Renderer> {
+            self.text(#shaping_argument).size(size.into().0.max(MIN_SIZE))
+        }
+
+        #gallery
+    }
+}
+
+/// Returns the conversions of icons into elements, wherever iced or a widget library takes
+/// content, like `row![Icon::House]`, or the tabs of iced_aw with the `iced_aw = true` option.
+/// They need a shaping of their own, so modules with the `parameter` shaping don't get them.
+pub(crate) fn conversions(context: &Context<'_>) -> TokenStream {
+    if !matches!(context.options.output, Output::Widget) || !context.shaping_argument.is_empty() {
+        return quote! {};
+    }
+
+    // Only the modules asking for them, the crate invoking the macro has to depend on iced_aw.
+    let tab_label = if context.options.iced_aw {
+        quote! {
+            /// A tab label of the icon, `Tabs` draws it with the font set by `icon_font`.
+            impl From<Icon> for iced_aw::TabLabel {
+                fn from(icon: Icon) -> Self {
+                    iced_aw::TabLabel::Icon(icon.char())
+                }
+            }
+
+            impl Icon {
+                /// A tab label of the icon followed by `text`.
+                #[must_use]
+                pub fn tab_label(self, text: impl Into<String>) -> iced_aw::TabLabel {
+                    iced_aw::TabLabel::IconText(self.char(), text.into())
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl<'a, Message, Theme, Renderer> From<Icon>
+            for iced_widget::core::Element<'a, Message, Theme, Renderer>
+        where
+            Theme: Catalog + 'a,
+            Renderer: text::Renderer<Font = Font> + 'a,
+        {
+            fn from(icon: Icon) -> Self {
+                icon.text().into()
+            }
+        }
+
+        #tab_label
+    }
+}
@@ -0,0 +1,977 @@
+//! Decoding of WOFF and WOFF2 web fonts into the TrueType or OpenType font they were made of,
+//! which is what `ttf-parser` and iced read.
+//!
+//! WOFF compresses every table with zlib. WOFF2 compresses all of them together with Brotli and
+//! usually transforms `glyf`, `loca` and `hmtx` first, which are rebuilt here as described in
+//! <https://www.w3.org/TR/WOFF2/>.
+
+use std::io::Read;
+
+use crate::sfnt;
+
+/// `Some` with the name of the format when `data` is a WOFF or WOFF2 font.
+pub(crate) fn format(data: &[u8]) -> Option<&'static str> {
+    match data.get(..4)? {
+        b"wOFF" => Some("WOFF"),
+        b"wOF2" => Some("WOFF2"),
+        _ => None,
+    }
+}
+
+/// The font the web font `data` was made of.
+pub(crate) fn decode(data: &[u8]) -> Result<Vec<u8>, String> {
+    match data.get(..4) {
+        Some(b"wOFF") => decode_woff(data),
+        Some(b"wOF2") => decode_woff2(data),
+        _ => Err(String::from("not a web font")),
+    }
+}
+
+fn decode_woff(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut reader = Reader::new(data, 4);
+    let flavor = reader.u32()?;
+    reader.skip(4)?;
+    let num_tables = reader.u16()?;
+    // The header is 44 bytes, the table directory follows it.
+    let mut reader = Reader::new(data, 44);
+
+    let tables = (0..num_tables)
+        .map(|_| {
+            let tag = reader.tag()?;
+            let offset = reader.u32()? as usize;
+            let compressed_length = reader.u32()? as usize;
+            let length = reader.u32()? as usize;
+            reader.skip(4)?;
+
+            let stored = data
+                .get(offset..offset + compressed_length)
+                .ok_or_else(|| format!("the table `{}` is out of bounds", tag_name(tag)))?;
+            let table = if compressed_length < length {
+                miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(stored, length)
+                    .map_err(|e| format!("failed to decompress `{}`: {e}", tag_name(tag)))?
+            } else {
+                stored.to_vec()
+            };
+            if table.len() != length {
+                return Err(format!(
+                    "the table `{}` has the wrong length",
+                    tag_name(tag)
+                ));
+            }
+
+            Ok((tag, table))
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok(sfnt::write(flavor, tables))
+}
+
+/// The tags of the tables WOFF2 refers to by their index.
+const KNOWN_TAGS: [&[u8; 4]; 63] = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm",
+    b"glyf", b"loca", b"prep", b"CFF ", b"VORG", b"EBDT", b"EBLC", b"gasp", b"hdmx", b"kern",
+    b"LTSH", b"PCLT", b"VDMX", b"vhea", b"vmtx", b"BASE", b"GDEF", b"GPOS", b"GSUB", b"EBSC",
+    b"JSTF", b"MATH", b"CBDT", b"CBLC", b"COLR", b"CPAL", b"SVG ", b"sbix", b"acnt", b"avar",
+    b"bdat", b"bloc", b"bsln", b"cvar", b"fdsc", b"feat", b"fmtx", b"fvar", b"gvar", b"hsty",
+    b"just", b"lcar", b"mort", b"morx", b"opbd", b"prop", b"trak", b"Zapf", b"Silf", b"Glat",
+    b"Gloc", b"Feat", b"Sill",
+];
+
+/// A table of the directory of a WOFF2 font.
+struct Entry {
+    tag: [u8; 4],
+    /// The length of the table in the font.
+    length: usize,
+    /// The length of the table in the compressed stream, when it is transformed.
+    transform_length: Option<usize>,
+}
+
+fn decode_woff2(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut reader = Reader::new(data, 4);
+    let flavor = reader.u32()?;
+    reader.skip(4)?;
+    let num_tables = reader.u16()?;
+    reader.skip(6)?;
+    let compressed_length = reader.u32()? as usize;
+    if flavor == u32::from_be_bytes(*b"ttcf") {
+        return Err(String::from("WOFF2 font collections aren't supported"));
+    }
+    // The header is 48 bytes, the table directory follows it.
+    let mut reader = Reader::new(data, 48);
+
+    let entries = (0..num_tables)
+        .map(|_| {
+            let flags = reader.u8()?;
+            let tag = match usize::from(flags & 0x3f) {
+                63 => reader.tag()?,
+                index => *KNOWN_TAGS[index],
+            };
+            let version = flags >> 6;
+            // The null transform of `glyf` and `loca` is version 3, the one of the others 0.
+            let transformed = if matches!(&tag, b"glyf" | b"loca") {
+                version != 3
+            } else {
+                version != 0
+            };
+
+            let length = reader.base128()? as usize;
+            let transform_length = transformed
+                .then(|| reader.base128().map(|length| length as usize))
+                .transpose()?;
+            Ok(Entry {
+                tag,
+                length,
+                transform_length,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let compressed = data
+        .get(reader.offset..reader.offset + compressed_length)
+        .ok_or("the compressed tables are out of bounds")?;
+    let stream_length: usize = entries
+        .iter()
+        .map(|entry| entry.transform_length.unwrap_or(entry.length))
+        .sum();
+    let mut stream = Vec::with_capacity(stream_length);
+    brotli_decompressor::Decompressor::new(compressed, 4096)
+        .take(stream_length as u64 + 1)
+        .read_to_end(&mut stream)
+        .map_err(|e| format!("failed to decompress the tables: {e}"))?;
+    if stream.len() != stream_length {
+        return Err(String::from("the compressed tables have the wrong length"));
+    }
+
+    let mut tables = Vec::with_capacity(entries.len());
+    let mut offset = 0;
+    for entry in &entries {
+        let length = entry.transform_length.unwrap_or(entry.length);
+        tables.push((entry, &stream[offset..offset + length]));
+        offset += length;
+    }
+
+    let transformed = |tag: &[u8; 4]| {
+        tables
+            .iter()
+            .find(|(entry, _)| &entry.tag == tag && entry.transform_length.is_some())
+    };
+    let table = |tag: &[u8; 4]| {
+        tables
+            .iter()
+            .find(|(entry, _)| &entry.tag == tag)
+            .map(|(_, table)| *table)
+    };
+
+    // The transformed `glyf` holds the glyphs `loca` points to, and the bounding boxes the
+    // transformed `hmtx` takes its side bearings from.
+    let glyphs = match transformed(b"glyf") {
+        Some((_, glyf)) => Some(glyf_and_loca(glyf)?),
+        None => None,
+    };
+    let hmtx = match transformed(b"hmtx") {
+        Some((_, hmtx)) => {
+            let x_mins = glyphs
+                .as_ref()
+                .map(|glyphs| glyphs.x_mins.as_slice())
+                .ok_or("the transformed `hmtx` needs a transformed `glyf`")?;
+            let hhea = table(b"hhea").ok_or("the font has no `hhea`")?;
+            let number_of_h_metrics = Reader::new(hhea, 34).u16()?;
+            Some(hmtx_table(hmtx, number_of_h_metrics, x_mins)?)
+        }
+        None => None,
+    };
+
+    let mut glyphs = glyphs;
+    let tables = tables
+        .iter()
+        .map(|(entry, table)| {
+            let table = match (&entry.tag, entry.transform_length, &mut glyphs) {
+                (b"glyf", Some(_), Some(glyphs)) => std::mem::take(&mut glyphs.glyf),
+                (b"loca", Some(_), Some(glyphs)) => std::mem::take(&mut glyphs.loca),
+                (b"loca", Some(_), None) => {
+                    return Err(String::from(
+                        "the transformed `loca` needs a transformed `glyf`",
+                    ));
+                }
+                (b"hmtx", Some(_), _) => hmtx.clone().unwrap_or_default(),
+                (tag, Some(_), _) => {
+                    return Err(format!(
+                        "the table `{}` has an unknown transform",
+                        tag_name(*tag)
+                    ));
+                }
+                (_, None, _) => table.to_vec(),
+            };
+            Ok((entry.tag, table))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    // Only `glyf` is allowed to come out with another length, its points are written anew.
+    if let Some(((tag, _), _)) = tables
+        .iter()
+        .zip(&entries)
+        .find(|((_, table), entry)| table.len() != entry.length && &entry.tag != b"glyf")
+    {
+        return Err(format!(
+            "the table `{}` has the wrong length",
+            tag_name(*tag)
+        ));
+    }
+
+    Ok(sfnt::write(flavor, tables))
+}
+
+/// The glyphs of a transformed `glyf` table.
+struct Glyphs {
+    glyf: Vec<u8>,
+    loca: Vec<u8>,
+    /// The left edge of every glyph, for the side bearings of a transformed `hmtx`.
+    x_mins: Vec<i16>,
+}
+
+/// Rebuilds `glyf` and `loca` from a transformed `glyf`.
+fn glyf_and_loca(data: &[u8]) -> Result<Glyphs, String> {
+    let mut header = Reader::new(data, 2);
+    let option_flags = header.u16()?;
+    let num_glyphs = usize::from(header.u16()?);
+    let index_format = header.u16()?;
+
+    let mut streams = Vec::with_capacity(7);
+    let mut offset = 36;
+    for _ in 0..7 {
+        let length = header.u32()? as usize;
+        streams.push(
+            data.get(offset..offset + length)
+                .ok_or("the transformed `glyf` is truncated")?,
+        );
+        offset += length;
+    }
+    let [
+        contours,
+        points,
+        flags,
+        glyph_stream,
+        composites,
+        bboxes,
+        instructions,
+    ] = <[&[u8]; 7]>::try_from(streams).expect("there are seven streams");
+    // The glyphs whose first flag has the `OVERLAP_SIMPLE` bit, only when the font has it.
+    let overlaps = (option_flags & 1 == 1)
+        .then(|| {
+            data.get(offset..offset + num_glyphs.div_ceil(8))
+                .ok_or("the transformed `glyf` is truncated")
+        })
+        .transpose()?;
+
+    let bbox_bitmap_length = num_glyphs.div_ceil(32) * 4;
+    let bbox_bitmap = bboxes
+        .get(..bbox_bitmap_length)
+        .ok_or("the transformed `glyf` is truncated")?;
+    let has_bbox = |glyph: usize| bbox_bitmap[glyph / 8] & (0x80 >> (glyph % 8)) != 0;
+
+    let mut contours = Reader::new(contours, 0);
+    let mut points = Reader::new(points, 0);
+    let mut flags = Reader::new(flags, 0);
+    let mut glyph_stream = Reader::new(glyph_stream, 0);
+    let mut composites = Reader::new(composites, 0);
+    let mut bboxes = Reader::new(bboxes, bbox_bitmap_length);
+    let mut instructions = Reader::new(instructions, 0);
+
+    let mut glyf = Vec::new();
+    let mut offsets = Vec::with_capacity(num_glyphs + 1);
+    let mut x_mins = Vec::with_capacity(num_glyphs);
+    for glyph in 0..num_glyphs {
+        offsets.push(glyf.len());
+        let number_of_contours = contours.i16()?;
+
+        let explicit_bbox = if has_bbox(glyph) {
+            Some([bboxes.i16()?, bboxes.i16()?, bboxes.i16()?, bboxes.i16()?])
+        } else {
+            None
+        };
+
+        match number_of_contours {
+            0 => {
+                if explicit_bbox.is_some() {
+                    return Err(format!("the empty glyph {glyph} has a bounding box"));
+                }
+                x_mins.push(0);
+            }
+            -1 => {
+                let bbox = explicit_bbox
+                    .ok_or_else(|| format!("the composite glyph {glyph} has no bounding box"))?;
+                let start = composites.offset;
+                let mut has_instructions = false;
+                loop {
+                    let component_flags = composites.u16()?;
+                    has_instructions |= component_flags & 0x0100 != 0;
+                    // The glyph index, the arguments and the scale.
+                    let arguments = if component_flags & 0x0001 != 0 { 4 } else { 2 };
+                    let scale = if component_flags & 0x0008 != 0 {
+                        2
+                    } else if component_flags & 0x0040 != 0 {
+                        4
+                    } else if component_flags & 0x0080 != 0 {
+                        8
+                    } else {
+                        0
+                    };
+                    composites.skip(2 + arguments + scale)?;
+                    if component_flags & 0x0020 == 0 {
+                        break;
+                    }
+                }
+
+                glyf.extend((-1_i16).to_be_bytes());
+                glyf.extend(bbox.iter().flat_map(|value| value.to_be_bytes()));
+                glyf.extend(&composites.data[start..composites.offset]);
+                if has_instructions {
+                    let length = glyph_stream.u255()?;
+                    glyf.extend(length.to_be_bytes());
+                    glyf.extend(instructions.bytes(usize::from(length))?);
+                }
+                x_mins.push(bbox[0]);
+            }
+            number_of_contours if number_of_contours > 0 => {
+                let mut end_points = Vec::with_capacity(number_of_contours as usize);
+                let mut total = 0_usize;
+                for _ in 0..number_of_contours {
+                    total += usize::from(points.u255()?);
+                    end_points.push(
+                        u16::try_from(total - 1)
+                            .map_err(|_| format!("the glyph {glyph} has too many points"))?,
+                    );
+                }
+
+                let mut coordinates = Vec::with_capacity(total);
+                let (mut x, mut y) = (0_i32, 0_i32);
+                for _ in 0..total {
+                    let flag = flags.u8()?;
+                    let (dx, dy) = triplet(flag & 0x7f, &mut glyph_stream)?;
+                    x += dx;
+                    y += dy;
+                    coordinates.push((x, y, flag & 0x80 == 0));
+                }
+                let instruction_length = glyph_stream.u255()?;
+
+                let bbox = match explicit_bbox {
+                    Some(bbox) => bbox,
+                    None => sfnt::bounding_box(&coordinates)
+                        .ok_or_else(|| format!("the glyph {glyph} is out of range"))?,
+                };
+                let overlap = overlaps
+                    .is_some_and(|overlaps| overlaps[glyph / 8] & (0x80 >> (glyph % 8)) != 0);
+
+                glyf.extend(number_of_contours.to_be_bytes());
+                glyf.extend(bbox.iter().flat_map(|value| value.to_be_bytes()));
+                glyf.extend(end_points.iter().flat_map(|point| point.to_be_bytes()));
+                glyf.extend(instruction_length.to_be_bytes());
+                glyf.extend(instructions.bytes(usize::from(instruction_length))?);
+                sfnt::simple_points(&mut glyf, &coordinates, overlap)
+                    .map_err(|_| format!("the glyph {glyph} is out of range"))?;
+                x_mins.push(bbox[0]);
+            }
+            _ => {
+                return Err(format!(
+                    "the glyph {glyph} has a negative amount of contours"
+                ));
+            }
+        }
+
+        // Glyphs start at offsets divisible by four, like the ones of most fonts.
+        glyf.resize(glyf.len().next_multiple_of(4), 0);
+    }
+    offsets.push(glyf.len());
+
+    let loca = if index_format == 0 {
+        offsets
+            .iter()
+            .map(|offset| u16::try_from(offset / 2).map(u16::to_be_bytes))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "the glyphs are too big for the short `loca` format")?
+            .concat()
+    } else {
+        offsets
+            .iter()
+            .map(|offset| u32::try_from(*offset).map(u32::to_be_bytes))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "the glyphs are too big for `loca`")?
+            .concat()
+    };
+
+    Ok(Glyphs { glyf, loca, x_mins })
+}
+
+/// The change of the position by the point with the triplet flag `flag`, whose coordinates are
+/// read from `glyphs`.
+fn triplet(flag: u8, glyphs: &mut Reader<'_>) -> Result<(i32, i32), String> {
+    let with_sign = |flag: u8, value: i32| if flag & 1 == 1 { value } else { -value };
+    let flag_value = i32::from(flag);
+
+    Ok(match flag {
+        0..10 => (
+            0,
+            with_sign(flag, ((flag_value & 14) << 7) + i32::from(glyphs.u8()?)),
+        ),
+        10..20 => (
+            with_sign(
+                flag,
+                (((flag_value - 10) & 14) << 7) + i32::from(glyphs.u8()?),
+            ),
+            0,
+        ),
+        20..84 => {
+            let b0 = flag_value - 20;
+            let b1 = i32::from(glyphs.u8()?);
+            (
+                with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+                with_sign(flag >> 1, 1 + ((b0 & 0x0c) << 2) + (b1 & 0x0f)),
+            )
+        }
+        84..120 => {
+            let b0 = flag_value - 84;
+            let (b1, b2) = (i32::from(glyphs.u8()?), i32::from(glyphs.u8()?));
+            (
+                with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+                with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+            )
+        }
+        120..124 => {
+            let [b1, b2, b3] = [glyphs.u8()?, glyphs.u8()?, glyphs.u8()?].map(i32::from);
+            (
+                with_sign(flag, (b1 << 4) + (b2 >> 4)),
+                with_sign(flag >> 1, ((b2 & 0x0f) << 8) + b3),
+            )
+        }
+        _ => {
+            let [x, y] = [glyphs.u16()?, glyphs.u16()?].map(i32::from);
+            (with_sign(flag, x), with_sign(flag >> 1, y))
+        }
+    })
+}
+
+/// Rebuilds `hmtx` from a transformed one, which leaves out side bearings that are the same as
+/// the left edge of their glyph.
+fn hmtx_table(data: &[u8], number_of_h_metrics: u16, x_mins: &[i16]) -> Result<Vec<u8>, String> {
+    let mut reader = Reader::new(data, 0);
+    let flags = reader.u8()?;
+    let number_of_h_metrics = usize::from(number_of_h_metrics);
+    if number_of_h_metrics == 0 || number_of_h_metrics > x_mins.len() {
+        return Err(String::from("`hhea` has the wrong amount of metrics"));
+    }
+
+    let advances = (0..number_of_h_metrics)
+        .map(|_| reader.u16())
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut bearings = |range: std::ops::Range<usize>, derived: bool| {
+        range
+            .map(|glyph| {
+                if derived {
+                    Ok(x_mins[glyph])
+                } else {
+                    reader.i16()
+                }
+            })
+            .collect::<Result<Vec<_>, String>>()
+    };
+    let proportional = bearings(0..number_of_h_metrics, flags & 1 != 0)?;
+    let monospaced = bearings(number_of_h_metrics..x_mins.len(), flags & 2 != 0)?;
+
+    let mut hmtx = Vec::with_capacity(number_of_h_metrics * 4 + monospaced.len() * 2);
+    for (advance, bearing) in advances.iter().zip(&proportional) {
+        hmtx.extend(advance.to_be_bytes());
+        hmtx.extend(bearing.to_be_bytes());
+    }
+    hmtx.extend(monospaced.iter().flat_map(|bearing| bearing.to_be_bytes()));
+    Ok(hmtx)
+}
+
+fn tag_name(tag: [u8; 4]) -> String {
+    String::from_utf8_lossy(&tag).trim_end().to_string()
+}
+
+/// Reads the big-endian numbers of a web font, failing at its end.
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], offset: usize) -> Self {
+        Self { data, offset }
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + length)
+            .ok_or("the font is truncated")?;
+        self.offset += length;
+        Ok(bytes)
+    }
+
+    fn skip(&mut self, length: usize) -> Result<(), String> {
+        self.bytes(length).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn i16(&mut self) -> Result<i16, String> {
+        Ok(i16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn tag(&mut self) -> Result<[u8; 4], String> {
+        Ok(self.bytes(4)?.try_into().unwrap())
+    }
+
+    /// A `UIntBase128` of WOFF2, seven bits per byte with the high bit set on all but the last.
+    fn base128(&mut self) -> Result<u32, String> {
+        let mut value = 0_u32;
+        for index in 0..5 {
+            let byte = self.u8()?;
+            if index == 0 && byte == 0x80 {
+                return Err(String::from("a number has leading zeros"));
+            }
+            if value & 0xfe00_0000 != 0 {
+                return Err(String::from("a number is too big"));
+            }
+            value = (value << 7) | u32::from(byte & 0x7f);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(String::from("a number is too long"))
+    }
+
+    /// A `255UInt16` of WOFF2, small values in one byte.
+    fn u255(&mut self) -> Result<u16, String> {
+        Ok(match self.u8()? {
+            253 => self.u16()?,
+            254 => u16::from(self.u8()?) + 253 * 2,
+            255 => u16::from(self.u8()?) + 253,
+            code => u16::from(code),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use ttf_parser::{Face, GlyphId};
+
+    use super::decode;
+
+    /// The tables of a font by their tags.
+    fn tables(font: &[u8]) -> Vec<([u8; 4], &[u8])> {
+        let num_tables = u16::from_be_bytes([font[4], font[5]]);
+        (0..usize::from(num_tables))
+            .map(|index| {
+                let record = &font[12 + index * 16..];
+                let offset = u32::from_be_bytes(record[8..12].try_into().unwrap()) as usize;
+                let length = u32::from_be_bytes(record[12..16].try_into().unwrap()) as usize;
+                (
+                    record[..4].try_into().unwrap(),
+                    &font[offset..offset + length],
+                )
+            })
+            .collect()
+    }
+
+    fn codicon() -> Vec<u8> {
+        std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../fonts/codicon.ttf")).unwrap()
+    }
+
+    /// A `255UInt16` of WOFF2.
+    fn u255(value: u16) -> Vec<u8> {
+        match value {
+            0..253 => vec![value as u8],
+            _ => [&[253][..], &value.to_be_bytes()].concat(),
+        }
+    }
+
+    /// A `UIntBase128` of WOFF2.
+    fn base128(mut value: u32) -> Vec<u8> {
+        let mut bytes = vec![(value & 0x7f) as u8];
+        value >>= 7;
+        while value != 0 {
+            bytes.insert(0, (value & 0x7f) as u8 | 0x80);
+            value >>= 7;
+        }
+        bytes
+    }
+
+    /// A Brotli stream of uncompressed meta-blocks, which every Brotli decoder reads.
+    fn brotli(data: &[u8]) -> Vec<u8> {
+        let mut bits = Vec::new();
+        // A window of 16 bits.
+        bits.push(false);
+        let mut out = Vec::new();
+        let flush = |bits: &mut Vec<bool>, out: &mut Vec<u8>| {
+            for byte in bits.chunks(8) {
+                out.push(
+                    byte.iter()
+                        .enumerate()
+                        .map(|(index, bit)| u8::from(*bit) << index)
+                        .sum(),
+                );
+            }
+            bits.clear();
+        };
+
+        for chunk in data.chunks(0x1_0000) {
+            // Not the last meta-block, four nibbles of length, uncompressed.
+            bits.extend([false, false, false]);
+            bits.extend((0..16).map(|bit| (chunk.len() - 1) >> bit & 1 == 1));
+            bits.push(true);
+            flush(&mut bits, &mut out);
+            out.extend(chunk);
+        }
+        // The last meta-block, empty.
+        bits.extend([true, true]);
+        flush(&mut bits, &mut out);
+        out
+    }
+
+    /// `glyf` transformed the way WOFF2 stores it, with every point in the four byte form of the
+    /// triplets and bounding boxes only where they differ from the one of the points.
+    fn transform_glyf(glyf: &[u8], loca: &[u8], index_format: u16) -> Vec<u8> {
+        let offsets: Vec<usize> = if index_format == 0 {
+            loca.chunks(2)
+                .map(|offset| usize::from(u16::from_be_bytes([offset[0], offset[1]])) * 2)
+                .collect()
+        } else {
+            loca.chunks(4)
+                .map(|offset| u32::from_be_bytes(offset.try_into().unwrap()) as usize)
+                .collect()
+        };
+        let num_glyphs = offsets.len() - 1;
+
+        let [
+            mut contours,
+            mut points,
+            mut flags,
+            mut glyphs,
+            mut composites,
+            mut instructions,
+        ] = [(); 6].map(|()| Vec::new());
+        let mut bbox_bitmap = vec![0_u8; num_glyphs.div_ceil(32) * 4];
+        let mut bboxes = Vec::new();
+
+        for glyph in 0..num_glyphs {
+            let data = &glyf[offsets[glyph]..offsets[glyph + 1]];
+            if data.is_empty() {
+                contours.extend(0_i16.to_be_bytes());
+                continue;
+            }
+
+            let read = |offset: usize| i16::from_be_bytes([data[offset], data[offset + 1]]);
+            let number_of_contours = read(0);
+            contours.extend(number_of_contours.to_be_bytes());
+            let bbox = &data[2..10];
+
+            if number_of_contours < 0 {
+                bbox_bitmap[glyph / 8] |= 0x80 >> (glyph % 8);
+                bboxes.extend(bbox);
+
+                let mut offset = 10;
+                let mut has_instructions = false;
+                loop {
+                    let component_flags = read(offset) as u16;
+                    has_instructions |= component_flags & 0x0100 != 0;
+                    offset += 4 + if component_flags & 1 != 0 { 4 } else { 2 };
+                    offset += match component_flags {
+                        flags if flags & 0x0008 != 0 => 2,
+                        flags if flags & 0x0040 != 0 => 4,
+                        flags if flags & 0x0080 != 0 => 8,
+                        _ => 0,
+                    };
+                    if component_flags & 0x0020 == 0 {
+                        break;
+                    }
+                }
+                composites.extend(&data[10..offset]);
+                if has_instructions {
+                    let length = read(offset) as u16;
+                    glyphs.extend(u255(length));
+                    instructions.extend(&data[offset + 2..offset + 2 + usize::from(length)]);
+                }
+                continue;
+            }
+
+            let contours_end = 10 + usize::from(number_of_contours as u16) * 2;
+            let mut start = 0;
+            for contour in (10..contours_end).step_by(2) {
+                let end = read(contour) as u16 + 1;
+                points.extend(u255(end - start));
+                start = end;
+            }
+            let total = usize::from(start);
+            let instruction_length = usize::from(read(contours_end) as u16);
+            let instruction_data = &data[contours_end + 2..contours_end + 2 + instruction_length];
+
+            // The flags of the points, with their repeats spelled out.
+            let mut offset = contours_end + 2 + instruction_length;
+            let mut point_flags = Vec::with_capacity(total);
+            while point_flags.len() < total {
+                let flag = data[offset];
+                offset += 1;
+                point_flags.push(flag);
+                if flag & 0x08 != 0 {
+                    point_flags.extend(std::iter::repeat_n(flag, usize::from(data[offset])));
+                    offset += 1;
+                }
+            }
+            let mut deltas = |short: u8, same: u8| {
+                point_flags
+                    .iter()
+                    .map(|flag| {
+                        if flag & short != 0 {
+                            let delta = i32::from(data[offset]);
+                            offset += 1;
+                            if flag & same != 0 { delta } else { -delta }
+                        } else if flag & same != 0 {
+                            0
+                        } else {
+                            offset += 2;
+                            i32::from(read(offset - 2))
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let dxs = deltas(0x02, 0x10);
+            let dys = deltas(0x04, 0x20);
+
+            let (mut x, mut y) = (0, 0);
+            let mut computed = [i32::MAX, i32::MAX, i32::MIN, i32::MIN];
+            for ((flag, dx), dy) in point_flags.iter().zip(dxs).zip(dys) {
+                let signs = u8::from(dx >= 0) | u8::from(dy >= 0) << 1;
+                flags.push(124 | signs | if flag & 1 == 0 { 0x80 } else { 0 });
+                glyphs.extend((dx.unsigned_abs() as u16).to_be_bytes());
+                glyphs.extend((dy.unsigned_abs() as u16).to_be_bytes());
+
+                (x, y) = (x + dx, y + dy);
+                computed = [
+                    computed[0].min(x),
+                    computed[1].min(y),
+                    computed[2].max(x),
+                    computed[3].max(y),
+                ];
+            }
+            glyphs.extend(u255(instruction_length as u16));
+            instructions.extend(instruction_data);
+
+            let computed: Vec<u8> = computed
+                .iter()
+                .flat_map(|value| (*value as i16).to_be_bytes())
+                .collect();
+            if computed != bbox {
+                bbox_bitmap[glyph / 8] |= 0x80 >> (glyph % 8);
+                bboxes.extend(bbox);
+            }
+        }
+
+        let bboxes = [bbox_bitmap, bboxes].concat();
+        let streams = [
+            contours,
+            points,
+            flags,
+            glyphs,
+            composites,
+            bboxes,
+            instructions,
+        ];
+        let mut transformed = Vec::new();
+        transformed.extend(0_u16.to_be_bytes());
+        transformed.extend(0_u16.to_be_bytes());
+        transformed.extend((num_glyphs as u16).to_be_bytes());
+        transformed.extend(index_format.to_be_bytes());
+        for stream in &streams {
+            transformed.extend((stream.len() as u32).to_be_bytes());
+        }
+        transformed.extend(streams.concat());
+        transformed
+    }
+
+    /// `font` as WOFF2, with `glyf`, `loca` and `hmtx` transformed.
+    fn woff2(font: &[u8]) -> Vec<u8> {
+        let tables = tables(font);
+        let table = |tag: &[u8; 4]| tables.iter().find(|(t, _)| t == tag).unwrap().1;
+        let face = Face::parse(font, 0).unwrap();
+        let index_format = u16::from_be_bytes(table(b"head")[50..52].try_into().unwrap());
+        let number_of_h_metrics = u16::from_be_bytes(table(b"hhea")[34..36].try_into().unwrap());
+
+        // The side bearings are all left out when they are the left edges of their glyphs.
+        let x_min = |glyph: u16| {
+            face.glyph_bounding_box(GlyphId(glyph))
+                .map_or(0, |bbox| bbox.x_min)
+        };
+        let derived = (0..face.number_of_glyphs())
+            .all(|glyph| face.glyph_hor_side_bearing(GlyphId(glyph)) == Some(x_min(glyph)));
+        let hmtx = table(b"hmtx");
+        let mut transformed_hmtx = vec![if derived { 3 } else { 0 }];
+        for metric in hmtx[..usize::from(number_of_h_metrics) * 4].chunks(4) {
+            transformed_hmtx.extend(&metric[..2]);
+        }
+        if !derived {
+            for metric in hmtx[..usize::from(number_of_h_metrics) * 4].chunks(4) {
+                transformed_hmtx.extend(&metric[2..]);
+            }
+            transformed_hmtx.extend(&hmtx[usize::from(number_of_h_metrics) * 4..]);
+        }
+
+        let mut directory = Vec::new();
+        let mut stream = Vec::new();
+        for (tag, data) in &tables {
+            let (flags, stored) = match tag {
+                b"glyf" => (10, transform_glyf(data, table(b"loca"), index_format)),
+                b"loca" => (11, Vec::new()),
+                b"hmtx" => (3 | 0x40, transformed_hmtx.clone()),
+                _ => (63, data.to_vec()),
+            };
+            directory.push(flags);
+            if flags == 63 {
+                directory.extend(tag);
+            }
+            directory.extend(base128(data.len() as u32));
+            if matches!(tag, b"glyf" | b"loca" | b"hmtx") {
+                directory.extend(base128(stored.len() as u32));
+            }
+            stream.extend(stored);
+        }
+        let compressed = brotli(&stream);
+
+        let mut woff2 = Vec::new();
+        woff2.extend(b"wOF2");
+        woff2.extend(&font[..4]);
+        woff2.extend(0_u32.to_be_bytes());
+        woff2.extend((tables.len() as u16).to_be_bytes());
+        woff2.extend([0; 6]);
+        woff2.extend((compressed.len() as u32).to_be_bytes());
+        woff2.extend([0; 24]);
+        woff2.extend(directory);
+        woff2.extend(compressed);
+        woff2
+    }
+
+    /// Records the outline of a glyph.
+    struct Outline(String);
+
+    impl ttf_parser::OutlineBuilder for Outline {
+        fn move_to(&mut self, x: f32, y: f32) {
+            let _ = write!(self.0, "M{x},{y}");
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            let _ = write!(self.0, "L{x},{y}");
+        }
+
+        fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+            let _ = write!(self.0, "Q{x1},{y1},{x},{y}");
+        }
+
+        fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+            let _ = write!(self.0, "C{x1},{y1},{x2},{y2},{x},{y}");
+        }
+
+        fn close(&mut self) {
+            self.0.push('Z');
+        }
+    }
+
+    #[test]
+    fn woff_tables_are_decompressed() {
+        let font = codicon();
+        let tables = tables(&font);
+
+        let mut directory = Vec::new();
+        let mut data = Vec::new();
+        let start = 44 + tables.len() * 20;
+        for (index, (tag, table)) in tables.iter().enumerate() {
+            // Every other table is stored as it is, like the ones compression doesn't shrink.
+            let compressed = miniz_oxide::deflate::compress_to_vec_zlib(table, 6);
+            let stored = if index % 2 == 0 && compressed.len() < table.len() {
+                compressed
+            } else {
+                table.to_vec()
+            };
+            directory.extend(tag);
+            directory.extend(((start + data.len()) as u32).to_be_bytes());
+            directory.extend((stored.len() as u32).to_be_bytes());
+            directory.extend((table.len() as u32).to_be_bytes());
+            directory.extend(0_u32.to_be_bytes());
+            data.extend(stored);
+            data.resize(data.len().next_multiple_of(4), 0);
+        }
+
+        let mut woff = Vec::new();
+        woff.extend(b"wOFF");
+        woff.extend(&font[..4]);
+        woff.extend(0_u32.to_be_bytes());
+        woff.extend((tables.len() as u16).to_be_bytes());
+        woff.extend([0; 30]);
+        woff.extend(directory);
+        woff.extend(data);
+
+        let decoded = decode(&woff).unwrap();
+        let mut decoded = self::tables(&decoded);
+        let mut original = tables;
+        decoded.sort_by_key(|(tag, _)| *tag);
+        original.sort_by_key(|(tag, _)| *tag);
+        // `head` only differs in its checksum adjustment.
+        for ((tag, decoded), (_, original)) in decoded.iter().zip(&original) {
+            if tag != b"head" {
+                assert_eq!(decoded, original, "{}", super::tag_name(*tag));
+            }
+        }
+        assert_eq!(decoded.len(), original.len());
+    }
+
+    #[test]
+    fn woff2_glyphs_are_rebuilt() {
+        let font = codicon();
+        let decoded = decode(&woff2(&font)).unwrap();
+
+        let original = Face::parse(&font, 0).unwrap();
+        let decoded = Face::parse(&decoded, 0).unwrap();
+        assert_eq!(decoded.number_of_glyphs(), original.number_of_glyphs());
+        for glyph in (0..original.number_of_glyphs()).map(GlyphId) {
+            let outline = |face: &Face<'_>| {
+                let mut outline = Outline(String::new());
+                let bbox = face.outline_glyph(glyph, &mut outline);
+                (bbox, outline.0)
+            };
+            assert_eq!(outline(&decoded), outline(&original), "{glyph:?}");
+            assert_eq!(
+                decoded.glyph_hor_advance(glyph),
+                original.glyph_hor_advance(glyph)
+            );
+            assert_eq!(
+                decoded.glyph_hor_side_bearing(glyph),
+                original.glyph_hor_side_bearing(glyph)
+            );
+        }
+        for c in ['\u{ea60}', '\u{eb99}', 'a'] {
+            assert_eq!(decoded.glyph_index(c), original.glyph_index(c));
+        }
+    }
+
+    #[test]
+    fn broken_web_fonts_are_errors() {
+        let woff2 = woff2(&codicon());
+        assert!(decode(&woff2[..woff2.len() / 2]).is_err());
+        assert!(decode(b"wOFF").is_err());
+        assert!(decode(&codicon()).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! `cargo iced-fonts`, writing the module of a font to a file that is committed with the crate,
+//! for reviewable code instead of a macro.
+
+use std::path::PathBuf;
+use std::process::{Command, ExitCode};
+
+use iced_fonts_build::Generator;
+
+const USAGE: &str = "\
+Usage: cargo iced-fonts generate <FONT> --module <NAME> [OPTIONS]
+
+Writes the module of the font to `src/<NAME>.rs`, with the functions, the `Icon` enum and the
+docs the `generate_icon_functions!` macro would generate.
+
+Options:
+    --module <NAME>       Name of the generated module.
+    --font <CONST>        The `Font` constant at the crate root drawing the icons.
+                          Defaults to the module name in upper case with `_FONT`, e.g. `MY_ICONS_FONT`.
+    --output <FILE>       Where to write the module, `src/<NAME>.rs` by default.
+    --link <TEMPLATE>     Link of every icon's documentation, like the macro's link argument.
+    --manifest <FILE>     Also writes the icons as JSON to the file, for tools outside of Rust.
+    --option <OPTION>     A macro option as written in the macro, e.g. `--option 'digits = keep'`.
+                          Can be given several times.";
+
+fn main() -> ExitCode {
+    // Cargo passes the name of the subcommand along, running the binary directly doesn't.
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .skip_while(|arg| arg == "iced-fonts")
+        .collect();
+
+    let result = match args.split_first() {
+        Some((command, args)) if command == "generate" => generate(args),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(args: &[String]) -> Result<(), String> {
+    let mut font_path = None;
+    let mut module = None;
+    let mut font = None;
+    let mut output = None;
+    let mut link = None;
+    let mut manifest = None;
+    let mut options = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("`{arg}` needs a value\n\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--module" => module = Some(value()?),
+            "--font" => font = Some(value()?),
+            "--output" => output = Some(PathBuf::from(value()?)),
+            "--link" => link = Some(value()?),
+            "--manifest" => manifest = Some(PathBuf::from(value()?)),
+            "--option" => options.push(value()?),
+            _ if arg.starts_with("--") => {
+                return Err(format!("unknown option `{arg}`\n\n{USAGE}"));
+            }
+            _ if font_path.is_none() => font_path = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument `{arg}`\n\n{USAGE}")),
+        }
+    }
+
+    let font_path = font_path.ok_or_else(|| format!("missing the font\n\n{USAGE}"))?;
+    let module = module.ok_or_else(|| format!("missing `--module`\n\n{USAGE}"))?;
+    let font = font.unwrap_or_else(|| format!("{}_FONT", module.to_uppercase()));
+    let output = output.unwrap_or_else(|| PathBuf::from(format!("src/{module}.rs")));
+
+    let mut generator = Generator::new(&font_path, &module, &font);
+    if let Some(link) = link {
+        generator = generator.link(link);
+    }
+    for option in options {
+        generator = generator.option(option);
+    }
+    let source = generator.generate_file().map_err(|e| e.to_string())?;
+
+    let header = format!(
+        "// Generated by `cargo iced-fonts generate` from `{font_path}`, run it again instead of \
+         editing this file.\n// The crate root needs the `{font}` constant, and the module a \
+         `mod {module};` declaration.\n\n"
+    );
+    std::fs::write(&output, header + &source)
+        .map_err(|e| format!("failed to write `{}`: {e}", output.display()))?;
+
+    // The generated code is a single line, rustfmt makes it reviewable.
+    match Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .arg(&output)
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        _ => eprintln!(
+            "warning: `rustfmt` failed, `{}` is left unformatted",
+            output.display()
+        ),
+    }
+
+    println!("wrote `{}`", output.display());
+    for warning in generator.warnings().map_err(|e| e.to_string())? {
+        eprintln!("warning: {warning}");
+    }
+
+    if let Some(manifest) = manifest {
+        let json = generator.generate_manifest().map_err(|e| e.to_string())?;
+        std::fs::write(&manifest, json)
+            .map_err(|e| format!("failed to write `{}`: {e}", manifest.display()))?;
+        println!("wrote `{}`", manifest.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+//! A module generated with the `iced_0_12` feature, which only builds when the generated code
+//! fits the API of iced 0.12.
+
+use iced_widget::core::{Font, text};
+use iced_widget::text::{StyleSheet, Text};
+
+pub const BOOTSTRAP_FONT: Font = Font::with_name("bootstrap-icons");
+
+iced_fonts::generate_icon_functions!(
+    "../../fonts/bootstrap.ttf",
+    bootstrap,
+    BOOTSTRAP_FONT,
+    advanced_text = true,
+    number_badges = "{}_circle_fill",
+);
+
+/// The icon functions and the `Icon` enum have to make the widgets of iced 0.12.
+pub fn house<'a, Theme: StyleSheet + 'a, Renderer: text::Renderer<Font = Font>>()
+-> [Text<'a, Theme, Renderer>; 3] {
+    [
+        bootstrap::house(),
+        bootstrap::Icon::House.text(),
+        bootstrap::number_badge(42),
+    ]
+}
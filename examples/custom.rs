@@ -12,13 +12,13 @@ use iced_aw_font::{COUNT, cancel, down_open, left_open, ok, right_open, up_open}
 // What we need to bootstrap the library to generate and use the font.
 pub const ICED_AW_FONT_BYTES: &[u8] = include_bytes!("../fonts/iced_aw.ttf");
 pub const ICED_AW_FONT: Font = Font::with_name("iced_aw");
-// 1st parameter &str font path
+// 1st parameter &str font path, relative to the Cargo.toml of your crate or absolute.
 // 2nd parameter literal name for the module the macro creates.
 // 3rd parameter literal name of the font created one line above.
-// 4th parameter literal name of the type of iced's text shaping you need. If unsure set to basic to avoid
-// the cost of advanced shaping.
-// 5th Optional parameter &str of where documenation exists for this font.
-generate_icon_functions!("fonts/iced_aw.ttf", iced_aw_font, ICED_AW_FONT, "basic");
+// 4th Optional parameter &str link to the documentation of every icon, `{name}`, `{raw}` and
+// `{codepoint:x}` are replaced with the function name, glyph name and codepoint.
+// Basic shaping is used unless the `shaping = advanced` option is given.
+generate_icon_functions!("fonts/iced_aw.ttf", iced_aw_font, ICED_AW_FONT);
 
 pub fn main() -> iced::Result {
     iced::application("Custom Font", App::update, App::view).run_with(App::new)
@@ -0,0 +1,196 @@
+//! Every icon of every enabled font, e.g. `cargo run --example gallery --features full`.
+//! Hovering an icon previews it with its name and codepoint, clicking it copies its function call.
+//! The icons are in an `IconGrid` of `PreviewCell`s, which only makes the cells in view, so even
+//! the ten thousand icons of the Nerd Font scroll smoothly.
+
+// Without any font feature the font list stays empty.
+#![allow(dead_code)]
+
+use iced::{
+    Element, Font, Task, clipboard, font,
+    widget::{Row, button, column, scrollable, text, text_input},
+};
+use iced_fonts::picker::{icon_grid, preview_cell};
+
+pub fn main() -> iced::Result {
+    iced::application("Gallery", App::update, App::view).run_with(App::new)
+}
+
+/// An icon of any of the fonts.
+struct Glyph {
+    name: &'static str,
+    char: char,
+    font: Font,
+}
+
+/// An enabled font, with its amount of icons and its search.
+struct IconFont {
+    module: &'static str,
+    count: usize,
+    search: fn(&str) -> Vec<Glyph>,
+}
+
+macro_rules! fonts {
+    ($($feature:literal $module:ident $bytes:ident),* $(,)?) => {
+        fn fonts() -> Vec<IconFont> {
+            vec![$(
+                #[cfg(feature = $feature)]
+                IconFont {
+                    module: stringify!($module),
+                    count: iced_fonts::$module::COUNT,
+                    search: |query| {
+                        iced_fonts::$module::Icon::search(query)
+                            .map(|icon| Glyph {
+                                name: icon.name(),
+                                char: icon.char(),
+                                font: icon.font(),
+                            })
+                            .collect()
+                    },
+                },
+            )*]
+        }
+
+        fn load_fonts() -> Task<Message> {
+            Task::batch([$(
+                #[cfg(feature = $feature)]
+                font::load(iced_fonts::$bytes).map(Message::FontLoaded),
+            )*])
+        }
+    };
+}
+
+fonts! {
+    "bootstrap" bootstrap BOOTSTRAP_FONT_BYTES,
+    "codicon" codicon CODICON_FONT_BYTES,
+    "devicon" devicon DEVICON_FONT_BYTES,
+    "fontawesome" fontawesome FONTAWESOME_FONT_BYTES,
+    "lucide" lucide LUCIDE_FONT_BYTES,
+    "nerd" nerd NERD_FONT_BYTES,
+    "octicons" octicons OCTICONS_FONT_BYTES,
+    "pomicons" pomicons POMICONS_FONT_BYTES,
+}
+
+/// The call of the function of the icon named `name` of `module`.
+fn call(module: &str, name: &str) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("{module}::r#{name}()")
+    } else {
+        format!("{module}::{name}()")
+    }
+}
+
+/// Keywords can only be function names as raw identifiers.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+struct App {
+    fonts: Vec<IconFont>,
+    selected: usize,
+    search: String,
+    copied: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    FontLoaded(Result<(), font::Error>),
+    Select(usize),
+    Search(String),
+    Copy(String),
+}
+
+impl App {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                fonts: fonts(),
+                selected: 0,
+                search: String::new(),
+                copied: None,
+            },
+            load_fonts(),
+        )
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::FontLoaded(result) => {
+                if let Err(error) = result {
+                    eprintln!("failed to load a font: {error:?}");
+                }
+                Task::none()
+            }
+            Message::Select(selected) => {
+                self.selected = selected;
+                Task::none()
+            }
+            Message::Search(search) => {
+                self.search = search;
+                Task::none()
+            }
+            Message::Copy(call) => {
+                let task = clipboard::write(call.clone());
+                self.copied = Some(call);
+                task
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let Some(font) = self.fonts.get(self.selected) else {
+            return text("No font is enabled, run the gallery with `--features full`.").into();
+        };
+
+        let tabs = Row::with_children(self.fonts.iter().enumerate().map(|(i, font)| {
+            let tab = button(text(font.module));
+            if i == self.selected {
+                tab.into()
+            } else {
+                tab.style(button::secondary)
+                    .on_press(Message::Select(i))
+                    .into()
+            }
+        }))
+        .spacing(8)
+        .wrap();
+
+        let module = font.module;
+        let matches = (font.search)(&self.search);
+
+        // Clicks go to the cells, Enter on the focused grid picks the icon under its cursor.
+        let icons = icon_grid(
+            matches
+                .iter()
+                .map(|glyph| (glyph.name, glyph.char, glyph.font)),
+        )
+        .cell_size(56)
+        .cell(move |icon| {
+            preview_cell(icon.name, icon.char, icon.font)
+                .cell_size(56)
+                .size(32)
+                .on_press(Message::Copy(call(module, icon.name)))
+                .into()
+        })
+        .on_select(move |index| Message::Copy(call(module, matches[index].name)));
+
+        let status = match &self.copied {
+            Some(call) => format!("Copied `{call}`."),
+            None => format!("{} icons, click one to copy its call.", font.count),
+        };
+
+        column![
+            tabs,
+            text_input("Search by name or tag", &self.search).on_input(Message::Search),
+            text(status),
+            scrollable(icons),
+        ]
+        .spacing(12)
+        .padding(12)
+        .into()
+    }
+}
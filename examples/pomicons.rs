@@ -1,30 +1,28 @@
 #![allow(unused_must_use)]
 
-use iced::{
-    Center, Element, Length, Task, font,
-    widget::{column, row},
-};
-use iced_fonts::{POMICONS_FONT_BYTES, pomicons::*};
+use iced::{Element, Task, font};
+use iced_fonts::{POMICONS_FONT_BYTES, pomicons::Icon};
 
 pub fn main() -> iced::Result {
     iced::application("pomicons", App::update, App::view).run_with(App::new)
 }
 
 #[derive(Default)]
-struct App {}
+struct App {
+    search: String,
+}
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 enum Message {
     FontLoaded(Result<(), font::Error>),
+    Search(String),
 }
 
 impl App {
     fn new() -> (Self, Task<Message>) {
         (
-            Self {},
-            Task::batch(vec![
-                font::load(POMICONS_FONT_BYTES).map(Message::FontLoaded),
-            ]),
+            Self::default(),
+            font::load(POMICONS_FONT_BYTES).map(Message::FontLoaded),
         )
     }
 
@@ -33,30 +31,11 @@ impl App {
             Message::FontLoaded(result) => {
                 dbg!(result);
             }
+            Message::Search(search) => self.search = search,
         }
     }
 
     fn view(&self) -> Element<Message> {
-        column![
-            row![
-                space(),
-                CLEAN_CODE(),
-                POMODORO_DONE(),
-                POMODORO_ESTIMATED(),
-                POMODORO_TICKING(),
-                POMODORO_SQUASHED(),
-                SHORT_PAUSE(),
-                LONG_PAUSE(),
-                AWAY(),
-                PAIR_PROGRAMMING(),
-                INTERNAL_INTERRUPTION(),
-                EXTERNAL_INTERRUPTION(),
-            ]
-            .padding(12)
-            .spacing(20)
-            .width(Length::Fill)
-            .align_y(Center),
-        ]
-        .into()
+        Icon::gallery(&self.search, Message::Search)
     }
 }
@@ -0,0 +1,244 @@
+//! Turning glyph names into Rust identifiers, the way `iced_fonts` names the functions and
+//! `Icon` variants of the icons.
+//!
+//! The macros, the build script generator and the CLI all name icons with these functions, so
+//! tools generating bindings or docs next to them, like TypeScript types for a web frontend, get
+//! the exact same names by calling them too.
+//!
+//! ```
+//! use iced_fonts_names::{Digits, sanitize, variant};
+//!
+//! let name = sanitize("arrowLeft-4k", Digits::Spell).unwrap();
+//! assert_eq!(name, "arrow_left_fourk");
+//! assert_eq!(variant(&name), "ArrowLeftFourk");
+//! ```
+//!
+//! Names given with the `rename` option and `prefix` and `suffix` are up to the options of the
+//! module, these are only the rules for a single name and, with [`Taken`], the numbering of names
+//! that repeat like `house_2`.
+
+use std::collections::HashMap;
+
+/// How digits in glyph names end up in function names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Digits {
+    /// `4k` becomes `fourk`.
+    #[default]
+    Spell,
+    /// `4k` becomes `_4k`, identifiers can't start with a digit.
+    Keep,
+}
+
+/// The words digits are spelled as with [`Digits::Spell`].
+pub const DIGIT_NAMES: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Turns the name of a glyph into the name of its function, `None` when the name can't be used.
+///
+/// Fonts name their glyphs in all kinds of styles, `arrow-left`, `arrowLeft`, `ARROW_LEFT` or
+/// `uniE001`. All of them end up as lower snake_case, `arrow_left` and `u_e001`. Emoji fonts name
+/// glyphs after their codepoint like `u1F600`, which becomes `u_1f600`.
+#[must_use]
+pub fn sanitize(raw_name: &str, digits: Digits) -> Option<String> {
+    // Most fonts have a `.notdef` and `.null` glyph that don't draw anything.
+    if matches!(raw_name, ".notdef" | ".null") {
+        return None;
+    }
+
+    // Glyphs named after their codepoint get the same name as glyphs without a name.
+    if let Some(hex) = raw_name.strip_prefix("uni")
+        && hex.len() == 4
+        && hex.chars().all(|c| matches!(c, '0'..='9' | 'A'..='F'))
+    {
+        return Some(format!("u_{}", hex.to_ascii_lowercase()));
+    }
+
+    // Emoji fonts name them `u1F600` or `u1f600`, outside the BMP too. Names that already are
+    // `u_1f600` stay as they are, so sanitizing again doesn't spell out their digits.
+    if let Some(hex) = raw_name
+        .strip_prefix("u_")
+        .or_else(|| raw_name.strip_prefix('u'))
+        && (4..=6).contains(&hex.len())
+        && hex.chars().all(|c| c.is_ascii_hexdigit())
+        && hex.chars().any(|c| c.is_ascii_digit())
+    {
+        return Some(format!("u_{}", hex.to_ascii_lowercase()));
+    }
+
+    let chars: Vec<char> = raw_name.chars().collect();
+    let mut processed_name = String::with_capacity(raw_name.len());
+    let mut replaced_punctuation = false;
+    for (i, &c) in chars.iter().enumerate() {
+        // Word boundaries of camelCase, `arrowLeft` and `HTMLFile` become `arrow_left` and
+        // `html_file`.
+        if c.is_ascii_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next = chars.get(i + 1).copied().unwrap_or_default();
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next.is_ascii_lowercase())
+            {
+                processed_name.push('_');
+            }
+        }
+
+        match c {
+            '-' => processed_name.push('_'),
+            '0'..='9' if digits == Digits::Spell => {
+                processed_name.push_str(DIGIT_NAMES[c as usize - '0' as usize]);
+            }
+            'a'..='z' | '0'..='9' | '_' => processed_name.push(c),
+            'A'..='Z' => processed_name.push(c.to_ascii_lowercase()),
+            _ => {
+                replaced_punctuation = true;
+                processed_name.push_str(punctuation(c));
+            }
+        }
+    }
+
+    // Material font edge case, a glyph named `_` itself. Other single characters that aren't
+    // part of identifiers, like `.` or `é`, have no word left and are skipped below.
+    if raw_name == "_" {
+        return Some(String::from("underscore"));
+    }
+
+    // Punctuation turns `c++` into `c_plus__plus_`, clean that up to `c_plus_plus`.
+    if replaced_punctuation {
+        processed_name = processed_name
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("_");
+    }
+
+    // Without a letter or digit, like `__`, there's no word left for the variant.
+    if processed_name.chars().all(|c| c == '_') {
+        return None;
+    }
+
+    if processed_name.starts_with(|c: char| c.is_ascii_digit()) {
+        processed_name.insert(0, '_');
+    }
+
+    // The only keywords that can't be raw identifiers.
+    if matches!(processed_name.as_str(), "self" | "super" | "crate") {
+        processed_name.push('_');
+    }
+
+    Some(processed_name)
+}
+
+/// What a character that can't be part of an identifier is replaced with.
+fn punctuation(c: char) -> &'static str {
+    match c {
+        '+' => "_plus_",
+        '&' => "_and_",
+        '@' => "_at_",
+        '#' => "_sharp_",
+        '%' => "_percent_",
+        '*' => "_star_",
+        '=' => "_equals_",
+        '$' => "_dollar_",
+        '!' => "_bang_",
+        '?' => "_question_",
+        '<' => "_less_",
+        '>' => "_greater_",
+        '~' => "_tilde_",
+        '^' => "_caret_",
+        '|' => "_pipe_",
+        _ => "_",
+    }
+}
+
+/// Turns a function name into the name of its `Icon` variant, `arrow_left` becomes `ArrowLeft`.
+/// Parts starting with a digit keep their underscore, `arrow_2` becomes `Arrow_2`, while
+/// `u_e001` becomes `UE001`. Doubled, leading and trailing underscores stay too, so `arrow__left`
+/// and `a_` don't end up as the variants of `arrow_left` and `a`.
+#[must_use]
+pub fn variant(function_name: &str) -> String {
+    let mut variant = String::with_capacity(function_name.len());
+    let mut parts = function_name.split('_');
+    // `_4k` has the underscore a part starting with a digit gets anyway, it stays `_4k`.
+    if function_name
+        .strip_prefix('_')
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+    {
+        let _ = parts.next();
+    }
+    for part in parts {
+        let mut chars = part.chars();
+        match chars.next() {
+            Some(first) if first.is_ascii_digit() => {
+                variant.push('_');
+                variant.push_str(part);
+            }
+            Some(first) => {
+                variant.push(first.to_ascii_uppercase());
+                variant.extend(chars);
+            }
+            None => variant.push('_'),
+        }
+    }
+
+    // `self_` would become `Self`, the one keyword that can't be a raw identifier.
+    if variant == "Self" {
+        variant.push('_');
+    }
+
+    variant
+}
+
+/// The names of the icons of a module so far, numbering the ones that repeat.
+///
+/// ```
+/// use iced_fonts_names::Taken;
+///
+/// let mut taken = Taken::default();
+/// assert_eq!(taken.take("house"), "house");
+/// assert_eq!(taken.take("house"), "house_2");
+/// assert_eq!(taken.take("house"), "house_3");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Taken {
+    /// The names given so far, with the last number given to a repetition of them.
+    names: HashMap<String, u32>,
+}
+
+impl Taken {
+    /// Gives out `name`, or when it's taken the first of `name_2`, `name_3`... that isn't. Glyphs
+    /// should be taken in the order of their codepoints, which decides the one keeping the name.
+    pub fn take(&mut self, name: &str) -> String {
+        let Some(&amount) = self.names.get(name) else {
+            self.names.insert(name.to_owned(), 1);
+            return name.to_owned();
+        };
+
+        let mut number = amount + 1;
+        let mut numbered = format!("{name}_{number}");
+        while self.names.contains_key(&numbered) {
+            number += 1;
+            numbered = format!("{name}_{number}");
+        }
+
+        self.names.insert(name.to_owned(), number);
+        self.names.insert(numbered.clone(), 1);
+        numbered
+    }
+}
+
+/// The name of a glyph the font has no name for, built from its codepoint, e.g. `u_f0123`.
+#[must_use]
+pub fn fallback(codepoint: char) -> String {
+    format!("u_{:04x}", u32::from(codepoint))
+}
+
+/// A name or alias the way `Icon::search` compares it, lowercase without spaces, dashes and
+/// underscores, `Arrow-Up` becomes `arrowup`.
+#[must_use]
+pub fn normalize_search(term: &str) -> String {
+    term.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,101 @@
+//! The names of every icon of the bundled fonts against the snapshots of the `snapshot` option, so
+//! changes to the sanitizer that rename icons fail here too, without expanding the macros.
+//!
+//! A snapshot has a line per icon, the codepoint in hex, a tab and the function name. The bundled
+//! fonts have no renames or prefixes, so names only get numbered with [`Taken`] like the macros
+//! do it. Building the crate with `ICED_FONTS_SNAPSHOT=overwrite` writes the snapshots.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use iced_fonts_names::{Digits, Taken, fallback, sanitize};
+
+fn check(font: &str, snapshot: &str) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let data = std::fs::read(root.join("fonts").join(font)).unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+
+    // The glyphs by codepoint, from the first Unicode subtable listing the codepoint.
+    let mut glyphs = BTreeMap::new();
+    for subtable in face.tables().cmap.unwrap().subtables {
+        if subtable.is_unicode() {
+            subtable.codepoints(|c| {
+                if let Some(codepoint) = char::from_u32(c) {
+                    glyphs
+                        .entry(codepoint)
+                        .or_insert_with(|| subtable.glyph_index(c));
+                }
+            });
+        }
+    }
+
+    let mut taken = Taken::default();
+    let mut current = String::new();
+    for (codepoint, glyph) in glyphs {
+        let Some(glyph) = glyph else {
+            continue;
+        };
+        let name = match face.glyph_name(glyph) {
+            Some(raw) => match sanitize(raw, Digits::Spell) {
+                Some(name) => name,
+                None => continue,
+            },
+            None => fallback(codepoint),
+        };
+
+        let name = taken.take(&name);
+        current.push_str(&format!("{:x}\t{name}\n", u32::from(codepoint)));
+    }
+
+    let expected = std::fs::read_to_string(root.join("snapshots").join(snapshot)).unwrap();
+    let changed: Vec<_> = expected
+        .lines()
+        .zip(current.lines())
+        .filter(|(expected, current)| expected != current)
+        .take(20)
+        .collect();
+    assert!(
+        changed.is_empty() && expected.lines().count() == current.lines().count(),
+        "the names of `{font}` changed, the first differences: {changed:#?}"
+    );
+}
+
+#[test]
+fn bootstrap() {
+    check("bootstrap.ttf", "bootstrap.txt");
+}
+
+#[test]
+fn codicon() {
+    check("codicon.ttf", "codicon.txt");
+}
+
+#[test]
+fn devicon() {
+    check("devicons.otf", "devicon.txt");
+}
+
+#[test]
+fn fontawesome() {
+    check("FontAwesome.otf", "fontawesome.txt");
+}
+
+#[test]
+fn lucide() {
+    check("lucide.ttf", "lucide.txt");
+}
+
+#[test]
+fn nerd() {
+    check("nerd.ttf", "nerd.txt");
+}
+
+#[test]
+fn octicons() {
+    check("octicons.otf", "octicons.txt");
+}
+
+#[test]
+fn pomicons() {
+    check("pomicons.otf", "pomicons.txt");
+}
@@ -0,0 +1,99 @@
+//! A case for every pass of the sanitizer, so a change to one of them fails the test naming it
+//! rather than somewhere in the snapshots of the bundled fonts.
+
+use iced_fonts_names::{Digits, sanitize, variant};
+
+fn spelled(raw: &str) -> Option<String> {
+    sanitize(raw, Digits::Spell)
+}
+
+fn kept(raw: &str) -> Option<String> {
+    sanitize(raw, Digits::Keep)
+}
+
+#[test]
+fn glyphs_that_draw_nothing_are_skipped() {
+    assert_eq!(spelled(".notdef"), None);
+    assert_eq!(spelled(".null"), None);
+}
+
+#[test]
+fn codepoint_names_become_fallback_names() {
+    assert_eq!(spelled("uniE001").as_deref(), Some("u_e001"));
+    assert_eq!(spelled("u1F600").as_deref(), Some("u_1f600"));
+    assert_eq!(spelled("u1f600").as_deref(), Some("u_1f600"));
+    assert_eq!(spelled("u_1f600").as_deref(), Some("u_1f600"));
+    // Only upper case hex after `uni`, and words that happen to be hex stay words.
+    assert_eq!(spelled("unie001").as_deref(), Some("uniezerozeroone"));
+    assert_eq!(spelled("ubeef").as_deref(), Some("ubeef"));
+}
+
+#[test]
+fn camel_case_is_split_into_words() {
+    assert_eq!(spelled("arrowLeft").as_deref(), Some("arrow_left"));
+    assert_eq!(spelled("HTMLFile").as_deref(), Some("html_file"));
+    assert_eq!(kept("h2Heading").as_deref(), Some("h2_heading"));
+    assert_eq!(spelled("ARROW_LEFT").as_deref(), Some("arrow_left"));
+}
+
+#[test]
+fn dashes_become_underscores() {
+    assert_eq!(spelled("arrow-left").as_deref(), Some("arrow_left"));
+    assert_eq!(spelled("arrow--left").as_deref(), Some("arrow__left"));
+}
+
+#[test]
+fn digits_are_spelled_or_kept() {
+    assert_eq!(spelled("4k").as_deref(), Some("fourk"));
+    assert_eq!(spelled("h1").as_deref(), Some("hone"));
+    assert_eq!(kept("h1").as_deref(), Some("h1"));
+}
+
+#[test]
+fn leading_digits_get_an_underscore() {
+    assert_eq!(kept("4k").as_deref(), Some("_4k"));
+    assert_eq!(kept("360-view").as_deref(), Some("_360_view"));
+}
+
+#[test]
+fn punctuation_is_spelled_out_and_cleaned_up() {
+    assert_eq!(spelled("c++").as_deref(), Some("c_plus_plus"));
+    assert_eq!(spelled("arrow.left").as_deref(), Some("arrow_left"));
+    assert_eq!(spelled("...").as_deref(), None);
+}
+
+#[test]
+fn a_lone_underscore_is_named() {
+    assert_eq!(spelled("_").as_deref(), Some("underscore"));
+    assert_eq!(spelled("__"), None);
+    // Only the underscore itself, other lone punctuation isn't named after it.
+    for raw in [".", ",", "/", "é", "-"] {
+        assert_eq!(spelled(raw), None, "{raw:?}");
+    }
+}
+
+#[test]
+fn keywords_that_cant_be_raw_get_an_underscore() {
+    assert_eq!(spelled("self").as_deref(), Some("self_"));
+    assert_eq!(spelled("super").as_deref(), Some("super_"));
+    assert_eq!(spelled("crate").as_deref(), Some("crate_"));
+    // The others are written as raw identifiers.
+    assert_eq!(spelled("type").as_deref(), Some("type"));
+    assert_eq!(variant("self_"), "Self_");
+}
+
+#[test]
+fn variants_are_camel_case() {
+    assert_eq!(variant("arrow_left"), "ArrowLeft");
+    assert_eq!(variant("u_e001"), "UE001");
+    assert_eq!(variant("arrow_2"), "Arrow_2");
+    assert_eq!(variant("_4k"), "_4k");
+}
+
+#[test]
+fn underscores_that_separate_nothing_stay_in_variants() {
+    assert_eq!(variant("arrow__left"), "Arrow_Left");
+    assert_eq!(variant("a_"), "A_");
+    assert_eq!(variant("_a"), "_A");
+    assert_eq!(variant("__4k"), "___4k");
+}
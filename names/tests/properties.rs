@@ -0,0 +1,160 @@
+//! Properties of the sanitizer over many generated names, the glyph names fonts come up with are
+//! too varied for a list of examples to cover.
+
+use iced_fonts_names::{Digits, normalize_search, sanitize, variant};
+
+/// How many names every property is checked with.
+const CASES: usize = 100_000;
+
+/// A xorshift generator, the same names on every run so failures can be reproduced.
+struct Names(u64);
+
+impl Names {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.next() as usize % items.len()]
+    }
+
+    /// Anything, with the characters glyph names tend to have more likely than others.
+    fn any(&mut self) -> String {
+        const CHARS: &[char] = &[
+            'a', 'b', 'e', 'k', 'u', 'z', 'A', 'E', 'L', 'Z', '0', '1', '4', '9', '_', '-', '.',
+            ' ', '+', '&', '#', '@', '!', '|', '/', 'é', 'ß', '中', '🏠', '\u{0}', '\u{301}',
+        ];
+        let length = self.next() % 12;
+        (0..length).map(|_| self.pick(CHARS)).collect()
+    }
+
+    /// Kebab case words of lowercase letters and digits, like `arrow-up-2`. Words can be empty,
+    /// for the doubled, leading and trailing dashes of names like `arrow--up` and `arrow-`.
+    fn kebab(&mut self, digits: bool) -> String {
+        let alphabet: &[char] = if digits {
+            &['a', 'b', 'c', 'f', 's', 'x', '0', '1', '4']
+        } else {
+            &['a', 'b', 'c', 'f', 's', 'x', 'e', 'l', 'r']
+        };
+        let words = 1 + self.next() % 3;
+        (0..words)
+            .map(|_| {
+                let length = self.next() % 5;
+                (0..length).map(|_| self.pick(alphabet)).collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+/// A function name that can be written as a raw identifier, `r#name`.
+fn is_function_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && name != "_"
+        && !matches!(name, "self" | "super" | "crate")
+}
+
+/// A variant name that can be written as a raw identifier.
+fn is_variant_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_uppercase() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "_"
+        && name != "Self"
+}
+
+#[test]
+fn names_are_identifiers() {
+    let mut names = Names(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..CASES {
+        let raw = names.any();
+        for digits in [Digits::Spell, Digits::Keep] {
+            let Some(name) = sanitize(&raw, digits) else {
+                continue;
+            };
+
+            assert!(
+                is_function_name(&name),
+                "{raw:?} became {name:?} with {digits:?}"
+            );
+            assert!(
+                is_variant_name(&variant(&name)),
+                "{raw:?} became {name:?} and {:?} with {digits:?}",
+                variant(&name)
+            );
+        }
+    }
+}
+
+#[test]
+fn names_stay_the_same_when_sanitized_again() {
+    let mut names = Names(0x2545_f491_4f6c_dd1d);
+    for _ in 0..CASES {
+        let raw = names.any();
+        for digits in [Digits::Spell, Digits::Keep] {
+            if let Some(name) = sanitize(&raw, digits) {
+                assert_eq!(
+                    sanitize(&name, digits).as_deref(),
+                    Some(name.as_str()),
+                    "{raw:?} with {digits:?}"
+                );
+            }
+        }
+    }
+}
+
+/// Names that sanitize the same, like `4k` and `-4k` with kept digits, get numbered by
+/// [`Taken`](iced_fonts_names::Taken), every other name needs a variant of its own.
+#[test]
+fn different_names_get_different_variants() {
+    let mut names = Names(0xda94_2042_e4dd_58b5);
+    for (digits, with_digits) in [(Digits::Keep, true), (Digits::Spell, false)] {
+        let mut seen = std::collections::HashMap::new();
+        for _ in 0..CASES {
+            let raw = names.kebab(with_digits);
+            let Some(name) = sanitize(&raw, digits) else {
+                assert!(raw.chars().all(|c| c == '-'), "{raw:?} wasn't usable");
+                continue;
+            };
+            let variant = variant(&name);
+
+            if let Some((other, other_raw)) = seen.insert(variant.clone(), (name.clone(), raw)) {
+                assert_eq!(
+                    other, name,
+                    "{other_raw:?} and {name:?} both became {variant:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn search_terms_are_found_however_they_are_written() {
+    let mut names = Names(0x6a09_e667_f3bc_c908);
+    for _ in 0..CASES {
+        let raw = names.any();
+        let term = normalize_search(&raw);
+
+        assert_eq!(normalize_search(&term), term, "{raw:?}");
+        assert!(
+            !term.contains(|c: char| c.is_whitespace() || matches!(c, '-' | '_')),
+            "{raw:?} became {term:?}"
+        );
+        for written in [
+            raw.to_ascii_uppercase(),
+            raw.replace('_', " "),
+            raw.replace('-', "_"),
+        ] {
+            assert_eq!(normalize_search(&written), term, "{raw:?} as {written:?}");
+        }
+    }
+}
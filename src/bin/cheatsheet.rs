@@ -0,0 +1,189 @@
+//! Writes a cheat sheet of every enabled font, with the picture, function and codepoint of each
+//! icon, matching the `iced_fonts` version it was built from.
+//!
+//! `cargo install iced_fonts --features cheatsheet,full` and run `iced-fonts-cheatsheet`.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: iced-fonts-cheatsheet [OPTIONS] [FONT...]
+
+Writes a cheat sheet of every icon of the fonts, all enabled ones by default, to `<FONT>.md`.
+
+Options:
+    --html                Writes `<FONT>.html` pages instead of Markdown.
+    --output <DIR>        Where to write the cheat sheets, the current directory by default.";
+
+/// An enabled font, as its module name, bytes and icons as function names and characters.
+struct IconFont {
+    module: &'static str,
+    bytes: &'static [u8],
+    icons: Vec<(&'static str, char)>,
+}
+
+macro_rules! fonts {
+    ($($feature:literal $module:ident $bytes:ident),* $(,)?) => {
+        fn fonts() -> Vec<IconFont> {
+            vec![$(
+                #[cfg(feature = $feature)]
+                IconFont {
+                    module: stringify!($module),
+                    bytes: iced_fonts::$bytes,
+                    icons: iced_fonts::$module::Icon::ALL
+                        .into_iter()
+                        .map(|icon| (icon.name(), icon.char()))
+                        .collect(),
+                },
+            )*]
+        }
+    };
+}
+
+fonts! {
+    "bootstrap" bootstrap BOOTSTRAP_FONT_BYTES,
+    "codicon" codicon CODICON_FONT_BYTES,
+    "devicon" devicon DEVICON_FONT_BYTES,
+    "fontawesome" fontawesome FONTAWESOME_FONT_BYTES,
+    "lucide" lucide LUCIDE_FONT_BYTES,
+    "nerd" nerd NERD_FONT_BYTES,
+    "octicons" octicons OCTICONS_FONT_BYTES,
+    "pomicons" pomicons POMICONS_FONT_BYTES,
+}
+
+/// Keywords can only be function names as raw identifiers.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let mut html = false;
+    let mut output = PathBuf::from(".");
+    let mut selected = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--html" => html = true,
+            "--output" => {
+                output = args
+                    .next()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| format!("`--output` needs a value\n\n{USAGE}"))?;
+            }
+            "--help" | "-h" => return Err(USAGE.to_string()),
+            _ if arg.starts_with("--") => {
+                return Err(format!("unknown option `{arg}`\n\n{USAGE}"));
+            }
+            _ => selected.push(arg),
+        }
+    }
+
+    let fonts = fonts();
+    if fonts.is_empty() {
+        return Err("no font is enabled, install with `--features cheatsheet,full`".into());
+    }
+    if let Some(unknown) = selected
+        .iter()
+        .find(|name| !fonts.iter().any(|font| font.module == *name))
+    {
+        let enabled: Vec<&str> = fonts.iter().map(|font| font.module).collect();
+        return Err(format!(
+            "`{unknown}` isn't an enabled font, these are: {}",
+            enabled.join(", ")
+        ));
+    }
+
+    std::fs::create_dir_all(&output)
+        .map_err(|e| format!("failed to create `{}`: {e}", output.display()))?;
+
+    for font in fonts
+        .iter()
+        .filter(|font| selected.is_empty() || selected.iter().any(|name| name == font.module))
+    {
+        let (extension, contents) = if html {
+            ("html", html_page(font))
+        } else {
+            ("md", markdown(font))
+        };
+        let path = output.join(format!("{}.{extension}", font.module));
+        std::fs::write(&path, contents)
+            .map_err(|e| format!("failed to write `{}`: {e}", path.display()))?;
+        println!("wrote `{}`", path.display());
+    }
+
+    Ok(())
+}
+
+/// The rows of a cheat sheet, as the picture's data URI, the function call and the codepoint.
+fn rows(font: &IconFont) -> impl Iterator<Item = (Option<String>, String, String)> + '_ {
+    let codepoints = font.icons.iter().map(|&(_, c)| c);
+    let mut previews = iced_fonts_build::glyph_previews(font.bytes, codepoints);
+
+    font.icons.iter().map(move |&(name, c)| {
+        let call = if KEYWORDS.contains(&name) {
+            format!("{}::r#{name}()", font.module)
+        } else {
+            format!("{}::{name}()", font.module)
+        };
+        (previews.remove(&c), call, format!("U+{:04X}", c as u32))
+    })
+}
+
+fn markdown(font: &IconFont) -> String {
+    let mut markdown = format!(
+        "# {}\n\n{} icons of `iced_fonts` {}.\n\n\
+         | Icon | Function | Codepoint |\n| :---: | --- | --- |\n",
+        font.module,
+        font.icons.len(),
+        env!("CARGO_PKG_VERSION"),
+    );
+
+    for (preview, call, codepoint) in rows(font) {
+        let picture = preview.map(|uri| format!("![]({uri})")).unwrap_or_default();
+        let _ = writeln!(markdown, "| {picture} | `{call}` | {codepoint} |");
+    }
+
+    markdown
+}
+
+fn html_page(font: &IconFont) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{module}</title>\n\
+         <style>body {{ font-family: sans-serif; }} td {{ padding: 4px 12px; }} \
+         code {{ user-select: all; }}</style>\n</head>\n<body>\n<h1>{module}</h1>\n\
+         <p>{count} icons of <code>iced_fonts</code> {version}.</p>\n<table>\n\
+         <tr><th>Icon</th><th>Function</th><th>Codepoint</th></tr>\n",
+        module = font.module,
+        count = font.icons.len(),
+        version = env!("CARGO_PKG_VERSION"),
+    );
+
+    for (preview, call, codepoint) in rows(font) {
+        let picture = preview
+            .map(|uri| format!("<img src=\"{uri}\" alt=\"\">"))
+            .unwrap_or_default();
+        let _ = writeln!(
+            html,
+            "<tr><td>{picture}</td><td><code>{call}</code></td><td>{codepoint}</td></tr>"
+        );
+    }
+
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
@@ -0,0 +1,187 @@
+//! An icon widget that lines its glyph up with the pixel grid, for small sizes where plain text
+//! comes out blurry or a pixel off.
+//!
+//! Text is placed wherever the layout puts it, often between two pixels, and the glyph sits in its
+//! line of text by the font's ascender instead of in the middle. [`Crisp`] takes a fixed square,
+//! centers the glyph's outline in it, and moves the glyph's origin onto a device pixel.
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text::{self, Text};
+use iced_core::widget::Tree;
+use iced_core::{Color, Element, Font, Length, Pixels, Point, Rectangle, Size, Widget, alignment};
+
+use crate::FontIcon;
+
+/// An icon with the size and position of its glyph, which centering it needs. Both are in the
+/// `Icon::metrics()` of a generated `Icon`.
+pub trait Glyph: FontIcon {
+    /// The bounding box of the outline in ems, relative to the glyph's origin on the baseline with
+    /// y pointing down.
+    fn bounds(self) -> Rectangle;
+
+    /// The distance from the top of the line to the baseline in ems.
+    fn baseline(self) -> f32;
+}
+
+macro_rules! glyph {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl Glyph for crate::$module::Icon {
+                fn bounds(self) -> Rectangle {
+                    self.metrics().bounds
+                }
+
+                fn baseline(self) -> f32 {
+                    self.metrics().baseline
+                }
+            }
+        )*
+    };
+}
+
+glyph! {
+    "bootstrap" bootstrap,
+    "codicon" codicon,
+    "devicon" devicon,
+    "fontawesome" fontawesome,
+    "lucide" lucide,
+    "nerd" nerd,
+    "octicons" octicons,
+    "pomicons" pomicons,
+}
+
+/// Creates a [`Crisp`] icon in a square of `size`.
+///
+/// ```ignore
+/// crisp(bootstrap::Icon::House, 16)
+/// ```
+pub fn crisp(icon: impl Glyph, size: impl Into<Pixels>) -> Crisp {
+    Crisp::new(icon, size)
+}
+
+/// An icon drawn in the middle of a square, with its position and size rounded to device pixels.
+#[derive(Debug, Clone, Copy)]
+pub struct Crisp {
+    char: char,
+    font: Font,
+    shaping: text::Shaping,
+    bounds: Rectangle,
+    baseline: f32,
+    size: f32,
+    scale_factor: f32,
+    color: Option<Color>,
+}
+
+impl Crisp {
+    /// Creates a [`Crisp`] icon in a square of `size`.
+    pub fn new(icon: impl Glyph, size: impl Into<Pixels>) -> Self {
+        Self {
+            char: icon.char(),
+            font: icon.font(),
+            shaping: icon.shaping(),
+            bounds: icon.bounds(),
+            baseline: icon.baseline(),
+            size: size.into().0,
+            scale_factor: 1.0,
+            color: None,
+        }
+    }
+
+    /// Sets the color of the icon, the text color of the parent widget by default.
+    #[must_use]
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets how many device pixels make a logical pixel, 1 by default. Widgets don't get to know
+    /// it, so on a display scaled by 1.5 pass 1.5 to round to its pixels instead of logical ones.
+    #[must_use]
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor.max(f32::EPSILON);
+        self
+    }
+
+    /// `value` rounded to the nearest device pixel.
+    fn snap(&self, value: f32) -> f32 {
+        (value * self.scale_factor).round() / self.scale_factor
+    }
+
+    /// The side of the square, a whole number of device pixels and at least one.
+    fn side(&self) -> f32 {
+        self.snap(self.size).max(1.0 / self.scale_factor)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Crisp
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.side()), Length::Fixed(self.side()))
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let side = self.side();
+
+        layout::Node::new(Size::new(side, side))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let square = layout.bounds();
+        if !square.intersects(viewport) {
+            return;
+        }
+
+        // The outline in pixels, centered in the square, then the origin it needs rounded to a
+        // pixel so edges on the font's grid land on pixel edges too.
+        let side = self.side();
+        let glyph = self.bounds;
+        let x = self.snap(square.x + (side - glyph.width * side) / 2.0 - glyph.x * side);
+        let baseline = self.snap(square.y + (side - glyph.height * side) / 2.0 - glyph.y * side);
+
+        renderer.fill_text(
+            Text {
+                content: self.char.to_string(),
+                bounds: Size::new(f32::INFINITY, f32::INFINITY),
+                size: Pixels(side),
+                // A line of one em, so the top of the line is an ascender above the baseline.
+                line_height: text::LineHeight::Relative(1.0),
+                font: self.font,
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Top,
+                shaping: self.shaping,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(x, baseline - self.baseline * side),
+            self.color.unwrap_or(style.text_color),
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Crisp> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(crisp: Crisp) -> Self {
+        Element::new(crisp)
+    }
+}
@@ -0,0 +1,67 @@
+//! The icons and colors of diagnostics, the way VS Code shows errors and warnings.
+
+use iced_core::{Color, Theme};
+#[cfg(feature = "codicon")]
+use iced_core::{Font, text};
+#[cfg(feature = "codicon")]
+use iced_widget::text::{Catalog, Style, Text};
+
+/// How serious a diagnostic of a compiler, linter or language server is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// Something is wrong.
+    Error,
+    /// Something is likely wrong.
+    Warning,
+    /// Something worth knowing.
+    Info,
+    /// A suggestion, like a quick fix.
+    Hint,
+}
+
+impl Severity {
+    /// The color of the severity in `theme`, its danger, warning, primary and secondary color.
+    #[must_use]
+    pub fn color(self, theme: &Theme) -> Color {
+        let palette = theme.extended_palette();
+
+        match self {
+            Severity::Error => palette.danger.base.color,
+            Severity::Warning => palette.warning.base.color,
+            Severity::Info => palette.primary.base.color,
+            Severity::Hint => palette.secondary.base.color,
+        }
+    }
+
+    /// The Codicon VS Code shows for the severity.
+    #[cfg(feature = "codicon")]
+    #[must_use]
+    pub const fn codicon(self) -> crate::codicon::Icon {
+        match self {
+            Severity::Error => crate::codicon::Icon::Error,
+            Severity::Warning => crate::codicon::Icon::Warning,
+            Severity::Info => crate::codicon::Icon::Info,
+            Severity::Hint => crate::codicon::Icon::Lightbulb,
+        }
+    }
+}
+
+/// The Codicon of `severity`, in the text color.
+#[cfg(feature = "codicon")]
+#[must_use]
+pub fn icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+    severity: Severity,
+) -> Text<'a, Theme, Renderer> {
+    severity.codicon().text()
+}
+
+/// The Codicon of `severity` in its color of the theme.
+#[cfg(feature = "codicon")]
+#[must_use]
+pub fn themed_icon<'a, Renderer: text::Renderer<Font = Font>>(
+    severity: Severity,
+) -> Text<'a, Theme, Renderer> {
+    icon(severity).style(move |theme: &Theme| Style {
+        color: Some(severity.color(theme)),
+    })
+}
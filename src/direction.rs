@@ -0,0 +1,158 @@
+//! Arrows and chevrons by the way they point, instead of a match over four icons in every app.
+
+use crate::FontIcon;
+
+/// The way an arrow or chevron points.
+///
+/// `Start` and `End` follow the reading direction, they point left and right unless resolved for
+/// a right-to-left layout with [`Direction::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Up.
+    Up,
+    /// Down.
+    Down,
+    /// Left.
+    Left,
+    /// Right.
+    Right,
+    /// Where lines of text start, left in left-to-right layouts.
+    Start,
+    /// Where lines of text end, right in left-to-right layouts.
+    End,
+}
+
+/// Which way lines of text run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LayoutDirection {
+    /// Like English, `Start` is left.
+    #[default]
+    LeftToRight,
+    /// Like Arabic or Hebrew, `Start` is right.
+    RightToLeft,
+}
+
+impl Direction {
+    /// The direction on screen, turning `Start` and `End` into `Left` or `Right` for `layout`.
+    #[must_use]
+    pub const fn resolve(self, layout: LayoutDirection) -> Self {
+        match (self, layout) {
+            (Direction::Start, LayoutDirection::LeftToRight)
+            | (Direction::End, LayoutDirection::RightToLeft) => Direction::Left,
+            (Direction::End, LayoutDirection::LeftToRight)
+            | (Direction::Start, LayoutDirection::RightToLeft) => Direction::Right,
+            (direction, _) => direction,
+        }
+    }
+
+    /// The index in the `ARROWS` and `CHEVRONS` of a generated `Icon`.
+    const fn index(self) -> usize {
+        match self.resolve(LayoutDirection::LeftToRight) {
+            Direction::Up => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Right => 3,
+            // Resolved into `Left` and `Right` above.
+            Direction::Start | Direction::End => unreachable!(),
+        }
+    }
+}
+
+/// Icons with arrows and chevrons pointing up, down, left and right, which `Start` and `End` pick
+/// from too. The `arrows` and `chevrons` options generate them from a pattern like `arrow_{}`.
+///
+/// ```ignore
+/// bootstrap::Icon::chevron(Direction::End.resolve(layout)).text()
+/// ```
+pub trait Directional: FontIcon {
+    /// The arrows pointing up, down, left and right.
+    fn arrows() -> [Self; 4];
+
+    /// The chevrons pointing up, down, left and right.
+    fn chevrons() -> [Self; 4];
+
+    /// The arrow pointing `direction`.
+    fn arrow(direction: Direction) -> Self {
+        Self::arrows()[direction.index()]
+    }
+
+    /// The chevron pointing `direction`.
+    fn chevron(direction: Direction) -> Self {
+        Self::chevrons()[direction.index()]
+    }
+}
+
+macro_rules! directional {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl Directional for crate::$module::Icon {
+                fn arrows() -> [Self; 4] {
+                    Self::ARROWS
+                }
+
+                fn chevrons() -> [Self; 4] {
+                    Self::CHEVRONS
+                }
+            }
+        )*
+    };
+}
+
+directional! {
+    "bootstrap" bootstrap,
+    "codicon" codicon,
+    "fontawesome" fontawesome,
+    "lucide" lucide,
+    "nerd" nerd,
+    "octicons" octicons,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, LayoutDirection};
+
+    #[test]
+    fn start_and_end_follow_the_layout() {
+        let resolved = |layout| {
+            [
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+                Direction::Start,
+                Direction::End,
+            ]
+            .map(|direction| direction.resolve(layout))
+        };
+
+        assert_eq!(
+            resolved(LayoutDirection::LeftToRight),
+            [
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+                Direction::Left,
+                Direction::Right,
+            ]
+        );
+        assert_eq!(
+            resolved(LayoutDirection::RightToLeft),
+            [
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+                Direction::Right,
+                Direction::Left,
+            ]
+        );
+    }
+
+    #[test]
+    fn start_and_end_index_like_left_and_right() {
+        assert_eq!(Direction::Start.index(), Direction::Left.index());
+        assert_eq!(Direction::End.index(), Direction::Right.index());
+    }
+}
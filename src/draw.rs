@@ -0,0 +1,471 @@
+//! An icon drawing itself stroke by stroke, for onboarding and empty-state screens.
+//!
+//! The outline of the glyph is traced contour after contour with a growing dash, like a pen
+//! going over it, and filled in once it's done.
+
+use iced_core::mouse;
+use iced_core::theme;
+use iced_core::time::{Duration, Instant};
+use iced_core::window;
+use iced_core::{Color, Element, Event, Length, Pixels, Point, Rectangle};
+use iced_widget::canvas::{self, Canvas, Frame, Geometry, LineCap, LineDash, LineJoin, Path};
+use iced_widget::graphics::geometry;
+use ttf_parser::OutlineBuilder;
+
+use crate::inspect::Inspect;
+
+/// Creates an [`AnimatedDraw`] of `icon`, tracing its outline over `duration`.
+///
+/// ```ignore
+/// animated_draw(bootstrap::Icon::Rocket, Duration::from_secs(2)).size(96)
+/// ```
+pub fn animated_draw(icon: impl Inspect, duration: Duration) -> AnimatedDraw {
+    AnimatedDraw::new(icon, duration)
+}
+
+/// A widget tracing the outline of an icon once, from when it's first drawn.
+#[derive(Debug, Clone)]
+pub struct AnimatedDraw {
+    contours: Vec<Contour>,
+    length: f32,
+    duration: Duration,
+    size: f32,
+    width: f32,
+    color: Option<Color>,
+    fill: bool,
+}
+
+impl AnimatedDraw {
+    /// Creates an [`AnimatedDraw`] of `icon`, tracing its outline over `duration`, at 64 pixels
+    /// with lines of 1.5 pixels.
+    pub fn new(icon: impl Inspect, duration: Duration) -> Self {
+        let contours = contours(icon);
+        let length = contours.iter().map(|contour| contour.length).sum();
+
+        Self {
+            contours,
+            length,
+            duration,
+            size: 64.0,
+            width: 1.5,
+            color: None,
+            fill: true,
+        }
+    }
+
+    /// Sets the size of the icon, which takes a square of it.
+    #[must_use]
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the width of the traced lines.
+    #[must_use]
+    pub fn stroke_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the color of the icon, the text color of the theme by default.
+    #[must_use]
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets whether the icon is filled in once its outline is traced, which it is by default.
+    /// Without it, the icon stays an outline.
+    #[must_use]
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// How far along the outline the pen is, from 0 to 1 once it traced all of it.
+    fn progress(&self, state: &State) -> f32 {
+        match (state.started, state.now) {
+            (Some(started), Some(now)) if !self.duration.is_zero() => {
+                ((now - started).as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+            }
+            (Some(_), Some(_)) => 1.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// When the icon was first drawn and the time of the last frame.
+#[derive(Debug, Default)]
+pub struct State {
+    started: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl<Message, Theme, Renderer> canvas::Program<Message, Theme, Renderer> for AnimatedDraw
+where
+    Theme: theme::Base,
+    Renderer: geometry::Renderer,
+{
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: &Event,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<Message>> {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return None;
+        };
+        let _ = state.started.get_or_insert(*now);
+        state.now = Some(*now);
+
+        (self.progress(state) < 1.0).then(canvas::Action::request_redraw)
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let color = self.color.unwrap_or_else(|| theme.base().text_color);
+        let progress = self.progress(state);
+        // Half a line of padding keeps the strokes on the edges inside the square.
+        let scale = (self.size - self.width).max(0.0);
+        let offset = self.width / 2.0;
+        let point = |(x, y): (f32, f32)| Point::new(x * scale + offset, y * scale + offset);
+
+        if progress >= 1.0 && self.fill {
+            let glyph = Path::new(|builder| {
+                for contour in &self.contours {
+                    contour.trace(builder, point);
+                }
+            });
+            frame.fill(&glyph, color);
+        }
+
+        // Contours are traced one after another, the pen is at `traced` along all of them.
+        let mut traced = progress * self.length;
+        for contour in &self.contours {
+            if traced <= 0.0 {
+                break;
+            }
+            let visible = traced.min(contour.length);
+            traced -= contour.length;
+
+            let path = Path::new(|builder| contour.trace(builder, point));
+            let segments = [visible * scale, contour.length * scale];
+            let stroke = canvas::Stroke {
+                line_dash: if visible < contour.length {
+                    LineDash {
+                        segments: &segments,
+                        offset: 0,
+                    }
+                } else {
+                    LineDash::default()
+                },
+                ..canvas::Stroke::default()
+                    .with_color(color)
+                    .with_width(self.width)
+                    .with_line_cap(LineCap::Round)
+                    .with_line_join(LineJoin::Round)
+            };
+            frame.stroke(&path, stroke);
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AnimatedDraw> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: theme::Base + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(draw: AnimatedDraw) -> Self {
+        let size = draw.size;
+
+        Canvas::new(draw)
+            .width(Length::Fixed(size))
+            .height(Length::Fixed(size))
+            .into()
+    }
+}
+
+/// A closed piece of the outline of a glyph, in a square of 1 with the y axis pointing down.
+#[derive(Debug, Clone)]
+struct Contour {
+    start: (f32, f32),
+    segments: Vec<Segment>,
+    length: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Segment {
+    Line((f32, f32)),
+    Quad((f32, f32), (f32, f32)),
+    Cubic((f32, f32), (f32, f32), (f32, f32)),
+}
+
+impl Contour {
+    fn trace(&self, builder: &mut canvas::path::Builder, point: impl Fn((f32, f32)) -> Point) {
+        builder.move_to(point(self.start));
+        for segment in &self.segments {
+            match *segment {
+                Segment::Line(to) => builder.line_to(point(to)),
+                Segment::Quad(control, to) => builder.quadratic_curve_to(point(control), point(to)),
+                Segment::Cubic(first, second, to) => {
+                    builder.bezier_curve_to(point(first), point(second), point(to));
+                }
+            }
+        }
+        builder.close();
+    }
+}
+
+/// The contours of the glyph of `icon`, scaled to fit a square of 1 and centered in it. Empty
+/// for an icon without an outline.
+fn contours<I: Inspect>(icon: I) -> Vec<Contour> {
+    let face = I::face();
+    let Some(id) = face.glyph_index(icon.char()) else {
+        return Vec::new();
+    };
+    let mut builder = Contours::default();
+    let Some(bounds) = face.outline_glyph(id, &mut builder) else {
+        return Vec::new();
+    };
+    builder.finish();
+
+    let width = f32::from(bounds.width());
+    let height = f32::from(bounds.height());
+    let scale = 1.0 / width.max(height).max(1.0);
+    let x_offset = (1.0 - width * scale) / 2.0;
+    let y_offset = (1.0 - height * scale) / 2.0;
+    // Fonts point y up, iced points it down.
+    let transform = |(x, y): (f32, f32)| {
+        (
+            (x - f32::from(bounds.x_min)) * scale + x_offset,
+            (f32::from(bounds.y_max) - y) * scale + y_offset,
+        )
+    };
+
+    builder
+        .contours
+        .into_iter()
+        .map(|contour| {
+            let start = transform(contour.start);
+            let segments: Vec<Segment> = contour
+                .segments
+                .into_iter()
+                .map(|segment| match segment {
+                    Segment::Line(to) => Segment::Line(transform(to)),
+                    Segment::Quad(control, to) => Segment::Quad(transform(control), transform(to)),
+                    Segment::Cubic(first, second, to) => {
+                        Segment::Cubic(transform(first), transform(second), transform(to))
+                    }
+                })
+                .collect();
+            let length = length(start, &segments);
+
+            Contour {
+                start,
+                segments,
+                length,
+            }
+        })
+        .collect()
+}
+
+/// The length of a closed contour, measured along its curves split into lines.
+fn length(start: (f32, f32), segments: &[Segment]) -> f32 {
+    let distance = |a: (f32, f32), b: (f32, f32)| (a.0 - b.0).hypot(a.1 - b.1);
+
+    let mut length = 0.0;
+    let mut current = start;
+    for segment in segments {
+        let steps = match segment {
+            Segment::Line(_) => 1,
+            Segment::Quad(..) | Segment::Cubic(..) => 16,
+        };
+        let mut previous = current;
+        for i in 1..=steps {
+            let next = segment.at(current, i as f32 / steps as f32);
+            length += distance(previous, next);
+            previous = next;
+        }
+        current = previous;
+    }
+
+    length + distance(current, start)
+}
+
+impl Segment {
+    /// The point at `t`, from 0 to 1, along the segment starting at `from`.
+    fn at(self, from: (f32, f32), t: f32) -> (f32, f32) {
+        let u = 1.0 - t;
+        match self {
+            Segment::Line(to) => (u * from.0 + t * to.0, u * from.1 + t * to.1),
+            Segment::Quad(c, to) => (
+                u * u * from.0 + 2.0 * u * t * c.0 + t * t * to.0,
+                u * u * from.1 + 2.0 * u * t * c.1 + t * t * to.1,
+            ),
+            Segment::Cubic(a, b, to) => (
+                u * u * u * from.0
+                    + 3.0 * u * u * t * a.0
+                    + 3.0 * u * t * t * b.0
+                    + t * t * t * to.0,
+                u * u * u * from.1
+                    + 3.0 * u * u * t * a.1
+                    + 3.0 * u * t * t * b.1
+                    + t * t * t * to.1,
+            ),
+        }
+    }
+}
+
+/// Collects the contours of a glyph in font units.
+#[derive(Default)]
+struct Contours {
+    contours: Vec<Contour>,
+    current: Option<Contour>,
+}
+
+impl Contours {
+    fn push(&mut self, segment: Segment) {
+        if let Some(contour) = &mut self.current {
+            contour.segments.push(segment);
+        }
+    }
+
+    /// Finishes the current contour, fonts that don't close their contours start the next one.
+    fn finish(&mut self) {
+        self.contours.extend(self.current.take());
+    }
+}
+
+impl OutlineBuilder for Contours {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish();
+        self.current = Some(Contour {
+            start: (x, y),
+            segments: Vec::new(),
+            length: 0.0,
+        });
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push(Segment::Line((x, y)));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.push(Segment::Quad((x1, y1), (x, y)));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.push(Segment::Cubic((x1, y1), (x2, y2), (x, y)));
+    }
+
+    fn close(&mut self) {
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::time::{Duration, Instant};
+    use ttf_parser::OutlineBuilder;
+
+    use super::{AnimatedDraw, Contours, State, length};
+
+    fn traced(builder: impl FnOnce(&mut Contours)) -> Vec<f32> {
+        let mut contours = Contours::default();
+        builder(&mut contours);
+        contours.finish();
+        contours
+            .contours
+            .iter()
+            .map(|contour| length(contour.start, &contour.segments))
+            .collect()
+    }
+
+    fn animated(duration: Duration) -> AnimatedDraw {
+        AnimatedDraw {
+            contours: Vec::new(),
+            length: 0.0,
+            duration,
+            size: 64.0,
+            width: 1.5,
+            color: None,
+            fill: true,
+        }
+    }
+
+    #[test]
+    fn squares_are_as_long_as_their_sides() {
+        // The last side is the one closing the contour.
+        let lengths = traced(|builder| {
+            builder.move_to(0.0, 0.0);
+            builder.line_to(2.0, 0.0);
+            builder.line_to(2.0, 2.0);
+            builder.line_to(0.0, 2.0);
+            builder.close();
+        });
+        assert_eq!(lengths, [8.0]);
+    }
+
+    #[test]
+    fn circles_are_as_long_as_their_circumference() {
+        // A circle of radius 1 out of four cubic arcs, the usual approximation.
+        let k = 0.552_284_8;
+        let lengths = traced(|builder| {
+            builder.move_to(1.0, 0.0);
+            builder.curve_to(1.0, k, k, 1.0, 0.0, 1.0);
+            builder.curve_to(-k, 1.0, -1.0, k, -1.0, 0.0);
+            builder.curve_to(-1.0, -k, -k, -1.0, 0.0, -1.0);
+            builder.curve_to(k, -1.0, 1.0, -k, 1.0, 0.0);
+            builder.close();
+        });
+        assert_eq!(lengths.len(), 1);
+        assert!(
+            (lengths[0] - std::f32::consts::TAU).abs() < 0.01,
+            "{lengths:?}"
+        );
+    }
+
+    #[test]
+    fn unclosed_contours_end_at_the_next_one() {
+        let lengths = traced(|builder| {
+            builder.move_to(0.0, 0.0);
+            builder.line_to(1.0, 0.0);
+            builder.move_to(5.0, 5.0);
+            builder.line_to(5.0, 8.0);
+        });
+        assert_eq!(lengths, [2.0, 6.0]);
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        let started = Instant::now();
+        let state = |elapsed: Duration| State {
+            started: Some(started),
+            now: Some(started + elapsed),
+        };
+
+        let draw = animated(Duration::from_secs(2));
+        assert_eq!(draw.progress(&State::default()), 0.0);
+        assert_eq!(draw.progress(&state(Duration::from_secs(1))), 0.5);
+        assert_eq!(draw.progress(&state(Duration::from_secs(3))), 1.0);
+
+        // Without a duration there is nothing to trace, it's done from the first frame.
+        let draw = animated(Duration::ZERO);
+        assert_eq!(draw.progress(&State::default()), 0.0);
+        assert_eq!(draw.progress(&state(Duration::ZERO)), 1.0);
+        assert_eq!(draw.progress(&state(Duration::from_secs(1))), 1.0);
+    }
+}
@@ -0,0 +1,50 @@
+//! What drawing any icon takes, the trait the traits of roles, states and kinds of icons build on.
+
+use iced_core::{Font, text};
+
+/// An icon of a generated `Icon`, a character of a font drawn with the shaping the font needs.
+///
+/// Every generated `Icon` has `char`, `font` and `shaping` functions to implement it with, the
+/// bundled fonts do.
+pub trait FontIcon: Copy {
+    /// The character of the icon.
+    fn char(self) -> char;
+
+    /// The font the icon is drawn with.
+    fn font(self) -> Font;
+
+    /// The shaping the font needs, advanced for the modules generated with `shaping = advanced`.
+    fn shaping(self) -> text::Shaping;
+}
+
+macro_rules! font_icon {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl FontIcon for crate::$module::Icon {
+                fn char(self) -> char {
+                    self.char()
+                }
+
+                fn font(self) -> Font {
+                    self.font()
+                }
+
+                fn shaping(self) -> text::Shaping {
+                    self.shaping()
+                }
+            }
+        )*
+    };
+}
+
+font_icon! {
+    "bootstrap" bootstrap,
+    "codicon" codicon,
+    "devicon" devicon,
+    "fontawesome" fontawesome,
+    "lucide" lucide,
+    "nerd" nerd,
+    "octicons" octicons,
+    "pomicons" pomicons,
+}
@@ -0,0 +1,171 @@
+//! Icon themes, the icons of common actions and states from a font picked at runtime, for "icon
+//! pack" settings.
+
+use iced_core::{Font, text};
+use iced_widget::text::{Catalog, Text};
+
+use crate::{FontIcon, IconFont, Role};
+
+/// Icons a theme is made of, with [`IconTheme::of`], those of the [`Role`]s the font has an icon
+/// for. The `roles` option generates them as `Icon::ROLES`.
+pub trait ThemeIcons: FontIcon {
+    /// The icons of the roles, in the order of [`Role::ALL`], `None` for the roles the font has no
+    /// icon for.
+    fn role_icons() -> [Option<Self>; 100];
+}
+
+macro_rules! theme_icons {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl ThemeIcons for crate::$module::Icon {
+                fn role_icons() -> [Option<Self>; 100] {
+                    Self::ROLES
+                }
+            }
+        )*
+    };
+}
+
+theme_icons! {
+    "bootstrap" bootstrap,
+    "codicon" codicon,
+    "fontawesome" fontawesome,
+    "lucide" lucide,
+    "nerd" nerd,
+    "octicons" octicons,
+}
+
+/// The icons of the [`Role`]s from a font, a value apps keep in their state and switch at
+/// runtime.
+///
+/// ```ignore
+/// let theme = IconTheme::builtin(IconFont::Lucide).unwrap();
+/// button(theme.icon(Role::Save)).on_press(Message::Save)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconTheme {
+    name: &'static str,
+    icons: [Option<(char, Font, text::Shaping)>; 100],
+}
+
+impl IconTheme {
+    /// The theme of the icons of `I`, shown as `name` in settings.
+    #[must_use]
+    pub fn of<I: ThemeIcons>(name: &'static str) -> Self {
+        Self {
+            name,
+            icons: I::role_icons()
+                .map(|icon| icon.map(|icon| (icon.char(), icon.font(), icon.shaping()))),
+        }
+    }
+
+    /// The theme with the icons of `other` for the roles this one has none for.
+    #[must_use]
+    pub fn or(mut self, other: Self) -> Self {
+        for (icon, other) in self.icons.iter_mut().zip(other.icons) {
+            *icon = icon.or(other);
+        }
+        self
+    }
+
+    /// The built-in theme of `font`, with the icons of the other enabled fonts of
+    /// [`IconFont::DEFAULT_ORDER`] for the roles it has none for. `None` when the font isn't
+    /// enabled or has no roles, like Devicons and Pomicons.
+    #[must_use]
+    pub fn builtin(font: IconFont) -> Option<Self> {
+        let theme = Self::only(font)?;
+
+        Some(
+            IconFont::DEFAULT_ORDER
+                .into_iter()
+                .filter(|other| *other != font)
+                .filter_map(Self::only)
+                .fold(theme, Self::or),
+        )
+    }
+
+    /// The theme of the icons of `font` alone.
+    fn only(font: IconFont) -> Option<Self> {
+        macro_rules! builtin {
+            ($($font:ident $feature:literal $module:ident $name:literal),* $(,)?) => {
+                match font {
+                    $(
+                        #[cfg(feature = $feature)]
+                        IconFont::$font => Some(Self::of::<crate::$module::Icon>($name)),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            };
+        }
+
+        builtin! {
+            Bootstrap "bootstrap" bootstrap "Bootstrap",
+            Codicon "codicon" codicon "Codicons",
+            Fontawesome "fontawesome" fontawesome "Font Awesome",
+            Lucide "lucide" lucide "Lucide",
+            Nerd "nerd" nerd "Nerd Font",
+            Octicons "octicons" octicons "Octicons",
+        }
+    }
+
+    /// The built-in themes of the enabled fonts, in the order of [`IconFont::DEFAULT_ORDER`], for
+    /// a theme picker.
+    #[must_use]
+    pub fn builtins() -> Vec<Self> {
+        IconFont::DEFAULT_ORDER
+            .into_iter()
+            .filter_map(Self::builtin)
+            .collect()
+    }
+
+    /// The name of the theme.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Whether the theme has an icon for `role`.
+    #[must_use]
+    pub const fn has(&self, role: Role) -> bool {
+        self.icons[role as usize].is_some()
+    }
+
+    /// The character of the icon of `role`, `None` when the theme has none.
+    #[must_use]
+    pub fn char(&self, role: Role) -> Option<char> {
+        self.icons[role as usize].map(|(c, _, _)| c)
+    }
+
+    /// The font of the icon of `role`, `None` when the theme has none.
+    #[must_use]
+    pub fn font(&self, role: Role) -> Option<Font> {
+        self.icons[role as usize].map(|(_, font, _)| font)
+    }
+
+    /// The shaping of the icon of `role`, `None` when the theme has none.
+    #[must_use]
+    pub fn shaping(&self, role: Role) -> Option<text::Shaping> {
+        self.icons[role as usize].map(|(_, _, shaping)| shaping)
+    }
+
+    /// The widget of the icon of `role`, empty when the theme has none.
+    #[must_use]
+    pub fn icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+        &self,
+        role: Role,
+    ) -> Text<'a, Theme, Renderer> {
+        match self.icons[role as usize] {
+            Some((c, font, shaping)) => Text::new(c.to_string()).font(font).shaping(shaping),
+            None => Text::new(""),
+        }
+    }
+}
+
+/// The name of the theme, so themes go straight into a `pick_list`.
+impl std::fmt::Display for IconTheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)
+    }
+}
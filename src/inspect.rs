@@ -0,0 +1,119 @@
+//! The parsed font files of icons, for kerning, outlines and metrics iced doesn't expose.
+//!
+//! The faces are parsed from the bytes the fonts are already embedded with, `ttf-parser` doesn't
+//! copy them and only reads the table directory up front. The faces of the bundled fonts are
+//! parsed once, the first time one of their icons is inspected, or by [`crate::warm_up`].
+
+use ttf_parser::{Face, GlyphId};
+
+use crate::FontIcon;
+
+/// The version of `ttf-parser` the faces are from.
+pub use ttf_parser;
+
+/// Icons whose font file can be inspected with `ttf-parser`, which only takes the bytes the font is
+/// embedded with.
+pub trait Inspect: FontIcon {
+    /// The font file the icons are drawn from.
+    fn font_bytes() -> &'static [u8];
+
+    /// The parsed font file. The bundled fonts parse it once and hand out copies, a font of your
+    /// own parses it on every call unless it overrides this.
+    ///
+    /// # Panics
+    ///
+    /// When the font file isn't a font, which the bundled fonts always are.
+    fn face() -> Face<'static> {
+        Face::parse(Self::font_bytes(), 0).expect("the font file of the icons is a font")
+    }
+
+    /// The glyph of the icon in [`Inspect::face`], for its outline, advance and kerning.
+    fn glyph_id(self) -> Option<GlyphId> {
+        Self::face().glyph_index(self.char())
+    }
+}
+
+/// The parsed faces of the bundled fonts, in the order of the `inspect!` list, empty until first
+/// used. Only the impls of the enabled fonts read them.
+#[cfg(any(
+    feature = "bootstrap",
+    feature = "codicon",
+    feature = "devicon",
+    feature = "fontawesome",
+    feature = "lucide",
+    feature = "nerd",
+    feature = "octicons",
+    feature = "pomicons",
+))]
+static FACES: [std::sync::OnceLock<Face<'static>>; 8] = [const { std::sync::OnceLock::new() }; 8];
+
+macro_rules! inspect {
+    ($($feature:literal $module:ident $bytes:ident $index:literal),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl Inspect for crate::$module::Icon {
+                fn font_bytes() -> &'static [u8] {
+                    crate::$bytes
+                }
+
+                fn face() -> Face<'static> {
+                    FACES[$index]
+                        .get_or_init(|| {
+                            Face::parse(crate::$bytes, 0)
+                                .expect("the font file of the icons is a font")
+                        })
+                        .clone()
+                }
+            }
+        )*
+
+        /// Parses the faces of the enabled fonts that aren't parsed yet.
+        pub(crate) fn warm_up() {
+            $(
+                #[cfg(feature = $feature)]
+                let _ = <crate::$module::Icon as Inspect>::face();
+            )*
+        }
+    };
+}
+
+inspect! {
+    "bootstrap" bootstrap BOOTSTRAP_FONT_BYTES 0,
+    "codicon" codicon CODICON_FONT_BYTES 1,
+    "devicon" devicon DEVICON_FONT_BYTES 2,
+    "fontawesome" fontawesome FONTAWESOME_FONT_BYTES 3,
+    "lucide" lucide LUCIDE_FONT_BYTES 4,
+    "nerd" nerd NERD_FONT_BYTES 5,
+    "octicons" octicons OCTICONS_FONT_BYTES 6,
+    "pomicons" pomicons POMICONS_FONT_BYTES 7,
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "bootstrap",
+        feature = "codicon",
+        feature = "devicon",
+        feature = "fontawesome",
+        feature = "lucide",
+        feature = "nerd",
+        feature = "octicons",
+        feature = "pomicons",
+    )
+))]
+mod tests {
+    use super::FACES;
+
+    // The only test touching the faces, so nothing parses them before it looks.
+    #[test]
+    fn faces_are_parsed_on_first_use() {
+        assert!(FACES.iter().all(|face| face.get().is_none()));
+
+        crate::warm_up();
+
+        #[cfg(feature = "nerd")]
+        assert!(FACES[5].get().is_some());
+        #[cfg(not(feature = "devicon"))]
+        assert!(FACES[2].get().is_none());
+    }
+}
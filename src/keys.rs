@@ -0,0 +1,203 @@
+//! Glyphs of keyboard keys, for key binding editors and shortcut hints.
+//!
+//! `keys::key_icon(&key)` draws the glyph of a named key like Enter or Shift from the first of the
+//! Nerd Font and Bootstrap that is enabled and has one, and a text label for every other key.
+
+use iced_core::keyboard::Key;
+use iced_core::keyboard::key::Named;
+use iced_core::{Font, text};
+use iced_widget::text::{Catalog, Text};
+
+use crate::FontIcon;
+
+/// Icons with glyphs of named keys. Fonts rarely have a glyph for every key, so the keys without
+/// one are `None`.
+pub trait KeyIcons: FontIcon {
+    /// The glyph of `named`, if the font has one.
+    fn key(named: Named) -> Option<Self>;
+}
+
+#[cfg(feature = "nerd")]
+impl KeyIcons for crate::nerd::Icon {
+    fn key(named: Named) -> Option<Self> {
+        use crate::nerd::Icon;
+
+        Some(match named {
+            Named::ArrowUp => Icon::MdArrowUp,
+            Named::ArrowDown => Icon::MdArrowDown,
+            Named::ArrowLeft => Icon::MdArrowLeft,
+            Named::ArrowRight => Icon::MdArrowRight,
+            Named::Enter => Icon::MdKeyboardReturn,
+            Named::Backspace => Icon::MdKeyboardBackspace,
+            Named::Delete => Icon::MdBackspaceReverse,
+            Named::Tab => Icon::MdKeyboardTab,
+            Named::Escape => Icon::MdKeyboardEsc,
+            Named::Space => Icon::MdKeyboardSpace,
+            Named::Shift => Icon::MdAppleKeyboardShift,
+            Named::Control => Icon::MdAppleKeyboardControl,
+            Named::Alt => Icon::MdAppleKeyboardOption,
+            Named::Super | Named::Meta if cfg!(target_os = "macos") => Icon::MdAppleKeyboardCommand,
+            Named::Super | Named::Meta => Icon::MdMicrosoftWindows,
+            Named::CapsLock => Icon::MdAppleKeyboardCaps,
+            Named::F1 => Icon::MdKeyboardFone,
+            Named::F2 => Icon::MdKeyboardFtwo,
+            Named::F3 => Icon::MdKeyboardFthree,
+            Named::F4 => Icon::MdKeyboardFfour,
+            Named::F5 => Icon::MdKeyboardFfive,
+            Named::F6 => Icon::MdKeyboardFsix,
+            Named::F7 => Icon::MdKeyboardFseven,
+            Named::F8 => Icon::MdKeyboardFeight,
+            Named::F9 => Icon::MdKeyboardFnine,
+            Named::F10 => Icon::MdKeyboardFonezero,
+            Named::F11 => Icon::MdKeyboardFoneone,
+            Named::F12 => Icon::MdKeyboardFonetwo,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(feature = "bootstrap")]
+impl KeyIcons for crate::bootstrap::Icon {
+    fn key(named: Named) -> Option<Self> {
+        use crate::bootstrap::Icon;
+
+        Some(match named {
+            Named::ArrowUp => Icon::ArrowUp,
+            Named::ArrowDown => Icon::ArrowDown,
+            Named::ArrowLeft => Icon::ArrowLeft,
+            Named::ArrowRight => Icon::ArrowRight,
+            Named::Enter => Icon::ArrowReturnLeft,
+            Named::Backspace => Icon::Backspace,
+            Named::Delete => Icon::BackspaceReverse,
+            Named::Escape => Icon::Escape,
+            Named::Shift => Icon::Shift,
+            Named::Alt => Icon::Option,
+            Named::Super | Named::Meta if cfg!(target_os = "macos") => Icon::Command,
+            Named::Super | Named::Meta => Icon::Windows,
+            Named::CapsLock => Icon::Capslock,
+            _ => return None,
+        })
+    }
+}
+
+/// The glyph of a key from one of the enabled fonts with key glyphs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Glyph {
+    #[cfg(feature = "nerd")]
+    Nerd(crate::nerd::Icon),
+    #[cfg(feature = "bootstrap")]
+    Bootstrap(crate::bootstrap::Icon),
+}
+
+/// The glyph of `named` from the first of the Nerd Font and Bootstrap that is enabled and has one.
+fn glyph(named: Named) -> Option<Glyph> {
+    #[cfg(feature = "nerd")]
+    if let Some(icon) = crate::nerd::Icon::key(named) {
+        return Some(Glyph::Nerd(icon));
+    }
+    #[cfg(feature = "bootstrap")]
+    if let Some(icon) = crate::bootstrap::Icon::key(named) {
+        return Some(Glyph::Bootstrap(icon));
+    }
+
+    let _ = named;
+    None
+}
+
+/// The glyph of `key` from the first enabled font with one, or its [`label`] in the default font.
+#[must_use]
+pub fn key_icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+    key: &Key,
+) -> Text<'a, Theme, Renderer> {
+    let glyph = match key {
+        Key::Named(named) => glyph(*named),
+        _ => None,
+    };
+
+    match glyph {
+        #[cfg(feature = "nerd")]
+        Some(Glyph::Nerd(icon)) => icon.text(),
+        #[cfg(feature = "bootstrap")]
+        Some(Glyph::Bootstrap(icon)) => icon.text(),
+        None => Text::new(label(key)),
+    }
+}
+
+/// The text label of `key`, the way keyboards print it: `"A"`, `"Enter"`, `"Ctrl"` or `"F5"`.
+#[must_use]
+pub fn label(key: &Key) -> String {
+    match key {
+        Key::Named(named) => match named {
+            Named::Control => "Ctrl".to_owned(),
+            Named::Escape => "Esc".to_owned(),
+            Named::Delete => "Del".to_owned(),
+            Named::Insert => "Ins".to_owned(),
+            Named::PageUp => "PgUp".to_owned(),
+            Named::PageDown => "PgDn".to_owned(),
+            Named::Super | Named::Meta if cfg!(target_os = "macos") => "Cmd".to_owned(),
+            Named::Super | Named::Meta => "Super".to_owned(),
+            Named::ArrowUp => "↑".to_owned(),
+            Named::ArrowDown => "↓".to_owned(),
+            Named::ArrowLeft => "←".to_owned(),
+            Named::ArrowRight => "→".to_owned(),
+            named => format!("{named:?}"),
+        },
+        Key::Character(character) => character.to_uppercase(),
+        Key::Unidentified => "?".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::keyboard::Key;
+    use iced_core::keyboard::key::Named;
+
+    use super::label;
+
+    #[test]
+    fn keys_are_labeled_the_way_keyboards_print_them() {
+        assert_eq!(label(&Key::Character("a".into())), "A");
+        assert_eq!(label(&Key::Named(Named::Control)), "Ctrl");
+        assert_eq!(label(&Key::Named(Named::PageDown)), "PgDn");
+        assert_eq!(label(&Key::Named(Named::ArrowLeft)), "←");
+        assert_eq!(label(&Key::Named(Named::Enter)), "Enter");
+        assert_eq!(label(&Key::Named(Named::F5)), "F5");
+        assert_eq!(label(&Key::Unidentified), "?");
+    }
+
+    #[cfg(feature = "nerd")]
+    #[test]
+    fn the_nerd_font_comes_first() {
+        use super::{Glyph, glyph};
+        use crate::nerd::Icon;
+
+        assert_eq!(
+            glyph(Named::Enter),
+            Some(Glyph::Nerd(Icon::MdKeyboardReturn))
+        );
+        assert_eq!(glyph(Named::Insert), None);
+    }
+
+    #[cfg(feature = "bootstrap")]
+    #[test]
+    fn bootstrap_has_the_keys_the_nerd_font_lacks() {
+        use super::{Glyph, KeyIcons, glyph};
+        use crate::bootstrap::Icon;
+
+        for named in [
+            Named::Enter,
+            Named::Escape,
+            Named::Shift,
+            Named::Alt,
+            Named::CapsLock,
+            Named::F1,
+            Named::Insert,
+        ] {
+            #[cfg(feature = "nerd")]
+            if crate::nerd::Icon::key(named).is_some() {
+                continue;
+            }
+            assert_eq!(glyph(named), Icon::key(named).map(Glyph::Bootstrap));
+        }
+    }
+}
@@ -1,6 +1,134 @@
 #[allow(unused_imports)]
 use iced_core::Font;
-pub use iced_fonts_macros::{generate_icon_advanced_functions, generate_icon_functions};
+use iced_core::text;
+use iced_widget::text::{Catalog, Text};
+
+pub use iced_fonts_macros::{
+    HasIcon, IconDefault, generate_icon_advanced_functions, generate_icon_functions, icon,
+};
+
+// `iced_0_12` is for the modules apps generate with the macros, the bundled ones are made for the
+// iced this crate depends on.
+#[cfg(all(
+    feature = "iced_0_12",
+    any(
+        feature = "bootstrap",
+        feature = "codicon",
+        feature = "devicon",
+        feature = "fontawesome",
+        feature = "lucide",
+        feature = "nerd",
+        feature = "octicons",
+        feature = "pomicons",
+    )
+))]
+compile_error!(
+    "the `iced_0_12` feature can't be combined with the bundled fonts, generate the modules of \
+     their font files with `generate_icon_functions!` instead"
+);
+
+pub mod crisp;
+pub mod diagnostics;
+mod direction;
+#[cfg(feature = "animated_draw")]
+pub mod draw;
+mod font_icon;
+mod icon_theme;
+#[cfg(feature = "inspect")]
+pub mod inspect;
+pub mod keys;
+pub mod media;
+pub mod mime;
+#[cfg(feature = "lyon")]
+pub mod outline;
+pub mod picker;
+pub mod platform;
+pub mod prelude;
+#[cfg(feature = "raster")]
+pub mod raster;
+mod resolve;
+mod role;
+mod selection;
+mod size;
+pub mod spinner;
+mod style;
+pub mod vcs;
+
+pub use crisp::{Crisp, crisp};
+pub use direction::{Direction, Directional, LayoutDirection};
+#[cfg(feature = "animated_draw")]
+pub use draw::{AnimatedDraw, animated_draw};
+pub use font_icon::FontIcon;
+pub use icon_theme::{IconTheme, ThemeIcons};
+pub use keys::KeyIcons;
+pub use media::{Media, MediaIcons};
+pub use mime::{FileIcons, FileKind};
+pub use platform::{Os, PlatformIcons};
+pub use resolve::{IconFont, resolve, resolve_in, resolve_text};
+pub use role::Role;
+pub use selection::{Selectable, Selection};
+pub use size::IconSize;
+pub use spinner::{Spinner, spinner};
+pub use style::{IconStyle, Styled, styled};
+pub use vcs::{GitStatus, VcsIcons};
+
+/// Does the work the icons otherwise do on first use, so it doesn't land on a frame.
+///
+/// The names, search terms and codepoints of the icons are tables built at compile time, so
+/// enabling many fonts costs no time at startup. What's left is parsing the font files for the
+/// `inspect` feature, which this does for the enabled fonts. Call it while the app starts, or on
+/// a background thread, when the first frame inspects icons.
+pub fn warm_up() {
+    #[cfg(feature = "inspect")]
+    inspect::warm_up();
+}
+
+/// The bytes of every enabled font, for loading them into iced.
+pub const ALL_FONT_BYTES: &[&[u8]] = &[
+    #[cfg(feature = "bootstrap")]
+    BOOTSTRAP_FONT_BYTES,
+    #[cfg(feature = "codicon")]
+    CODICON_FONT_BYTES,
+    #[cfg(feature = "devicon")]
+    DEVICON_FONT_BYTES,
+    #[cfg(feature = "fontawesome")]
+    FONTAWESOME_FONT_BYTES,
+    #[cfg(feature = "lucide")]
+    LUCIDE_FONT_BYTES,
+    #[cfg(feature = "nerd")]
+    NERD_FONT_BYTES,
+    #[cfg(feature = "octicons")]
+    OCTICONS_FONT_BYTES,
+    #[cfg(feature = "pomicons")]
+    POMICONS_FONT_BYTES,
+];
+
+/// Loads every enabled font with `load`, which is iced's `font::load`:
+/// ```ignore
+/// Task::batch(iced_fonts::load_all(font::load)).map(Message::FontLoaded)
+/// ```
+pub fn load_all<T>(load: impl FnMut(&'static [u8]) -> T) -> impl Iterator<Item = T> {
+    ALL_FONT_BYTES.iter().copied().map(load)
+}
+
+/// A value that is shown with an icon, like the pages of a navigation bar.
+///
+/// Usually derived, with the icon function of every variant in an `#[icon(...)]` attribute:
+/// ```ignore
+/// #[derive(HasIcon)]
+/// enum Page {
+///     #[icon(bootstrap::house)]
+///     Home,
+///     #[icon(bootstrap::gear)]
+///     Settings,
+/// }
+/// ```
+pub trait HasIcon {
+    /// The icon of the value.
+    fn icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+        &self,
+    ) -> Text<'a, Theme, Renderer>;
+}
 
 #[cfg(feature = "bootstrap")]
 /// The default icon font bytes for loading the font into iced.
@@ -13,7 +141,50 @@ generate_icon_functions!(
     "fonts/bootstrap.ttf",
     bootstrap,
     BOOTSTRAP_FONT,
-    "https://icons.getbootstrap.com/icons",
+    "https://icons.getbootstrap.com/icons/{raw}",
+    expect = 2050,
+    snapshot = "snapshots/bootstrap.txt",
+    changes = "changes/bootstrap.txt",
+    fallbacks = "fallbacks/bootstrap.toml",
+    number_badges = "{}_circle_fill",
+    spinners = { hourglass = ["hourglass_top", "hourglass_split", "hourglass_bottom"] },
+    arrows = "arrow_{}",
+    chevrons = "chevron_{}",
+    selection = {
+        checked = "check_square_fill",
+        unchecked = "square",
+        indeterminate = "dash_square_fill",
+        radio_on = "record_circle",
+        radio_off = "circle",
+    },
+    media = {
+        play = "play_fill",
+        pause = "pause_fill",
+        stop = "stop_fill",
+        next = "skip_end_fill",
+        previous = "skip_start_fill",
+        shuffle = "shuffle",
+        repeat = "repeat",
+        volume_mute = "volume_mute_fill",
+        volume_low = "volume_down_fill",
+        volume_high = "volume_up_fill",
+    },
+    file_kinds = {
+        file = "file_earmark",
+        text = "file_earmark_text",
+        code = "file_earmark_code",
+        pdf = "file_earmark_pdf",
+        document = "file_earmark_word",
+        spreadsheet = "file_earmark_spreadsheet",
+        presentation = "file_earmark_slides",
+        image = "file_earmark_image",
+        audio = "file_earmark_music",
+        video = "file_earmark_play",
+        archive = "file_earmark_zip",
+        font = "file_earmark_font",
+        binary = "file_earmark_binary",
+    },
+    roles = "roles/bootstrap.toml",
 );
 
 #[cfg(feature = "codicon")]
@@ -23,25 +194,115 @@ pub const CODICON_FONT_BYTES: &[u8] = include_bytes!("../fonts/codicon.ttf");
 /// The codicon font.
 pub const CODICON_FONT: Font = Font::with_name("codicon");
 #[cfg(feature = "codicon")]
-generate_icon_functions!("fonts/codicon.ttf", codicon, CODICON_FONT);
+generate_icon_functions!(
+    "fonts/codicon.ttf",
+    codicon,
+    CODICON_FONT,
+    expect = 439,
+    snapshot = "snapshots/codicon.txt",
+    changes = "changes/codicon.txt",
+    fallbacks = "fallbacks/codicon.toml",
+    arrows = "arrow_{}",
+    chevrons = "chevron_{}",
+    vcs = {
+        modified = "diff_modified",
+        added = "diff_added",
+        deleted = "diff_removed",
+        renamed = "diff_renamed",
+        conflicted = "warning",
+        untracked = "question",
+    },
+    roles = "roles/codicon.toml",
+);
 
 #[cfg(feature = "devicon")]
 /// The default icon font bytes for loading the font into iced.
 pub const DEVICON_FONT_BYTES: &[u8] = include_bytes!("../fonts/devicons.otf");
 #[cfg(feature = "devicon")]
 /// The devicon icon font.
-pub const DEVICON_FONT: Font = Font::with_name("Devicons-NerdFont-Regular");
+pub const DEVICON_FONT: Font = Font::with_name("Devicons Nerd Font");
 #[cfg(feature = "devicon")]
-generate_icon_advanced_functions!("fonts/devicons.otf", devicon, DEVICON_FONT);
+generate_icon_functions!(
+    "fonts/devicons.otf",
+    devicon,
+    DEVICON_FONT,
+    expect = 497,
+    snapshot = "snapshots/devicon.txt",
+    changes = "changes/devicon.txt",
+    min_size = 16,
+    fallbacks = "fallbacks/devicon.toml",
+    shaping = advanced,
+    platforms = {
+        linux = "linux",
+        windows = "windows",
+        macos = "apple",
+        android = "android",
+        ios = "apple",
+    }
+);
 
 #[cfg(feature = "fontawesome")]
 /// The default icon font bytes for loading the font into iced.
 pub const FONTAWESOME_FONT_BYTES: &[u8] = include_bytes!("../fonts/FontAwesome.otf");
 #[cfg(feature = "fontawesome")]
 /// The fontawesome icon font.
-pub const FONTAWESOME_FONT: Font = Font::with_name("FA-NerdFont-Regular");
+pub const FONTAWESOME_FONT: Font = Font::with_name("FA Nerd Font");
 #[cfg(feature = "fontawesome")]
-generate_icon_advanced_functions!("fonts/FontAwesome.otf", fontawesome, FONTAWESOME_FONT,);
+generate_icon_functions!(
+    "fonts/FontAwesome.otf",
+    fontawesome,
+    FONTAWESOME_FONT,
+    expect = 1476,
+    snapshot = "snapshots/fontawesome.txt",
+    changes = "changes/fontawesome.txt",
+    fallbacks = "fallbacks/fontawesome.toml",
+    shaping = advanced,
+    spinners = { hourglass = ["hourglass_start", "hourglass_half", "hourglass_end"] },
+    arrows = "arrow_{}",
+    chevrons = "chevron_{}",
+    selection = {
+        checked = "square_check",
+        unchecked = "square_o",
+        indeterminate = "square_minus",
+        radio_on = "circle_dot",
+        radio_off = "circle_o",
+    },
+    media = {
+        play = "play",
+        pause = "pause",
+        stop = "stop",
+        next = "forward_step",
+        previous = "backward_step",
+        shuffle = "shuffle",
+        repeat = "repeat_alt",
+        volume_mute = "volume_xmark",
+        volume_low = "volume_low",
+        volume_high = "volume_high",
+    },
+    file_kinds = {
+        file = "file",
+        text = "file_lines",
+        code = "file_code",
+        pdf = "file_pdf",
+        document = "file_word",
+        spreadsheet = "file_excel",
+        presentation = "file_powerpoint",
+        image = "file_image",
+        audio = "file_audio",
+        video = "file_video",
+        archive = "file_zipper",
+        font = "font",
+        binary = "file",
+    },
+    platforms = {
+        linux = "linux",
+        windows = "windows",
+        macos = "apple",
+        android = "android",
+        ios = "app_store_ios",
+    },
+    roles = "roles/fontawesome.toml",
+);
 
 #[cfg(feature = "lucide")]
 /// The default icon font bytes for loading the font into iced.
@@ -54,7 +315,50 @@ generate_icon_functions!(
     "fonts/lucide.ttf",
     lucide,
     LUCIDE_FONT,
-    "https://lucide.dev/icons"
+    "https://lucide.dev/icons/{raw}",
+    expect = 1585,
+    snapshot = "snapshots/lucide.txt",
+    changes = "changes/lucide.txt",
+    min_size = 16,
+    fallbacks = "fallbacks/lucide.toml",
+    translations = { de = "translations/lucide.de.toml" },
+    arrows = "arrow_{}",
+    chevrons = "chevron_{}",
+    selection = {
+        checked = "square_check",
+        unchecked = "square",
+        indeterminate = "square_minus",
+        radio_on = "circle_dot",
+        radio_off = "circle",
+    },
+    media = {
+        play = "play",
+        pause = "pause",
+        stop = "square",
+        next = "skip_forward",
+        previous = "skip_back",
+        shuffle = "shuffle",
+        repeat = "repeat",
+        volume_mute = "volume_x",
+        volume_low = "volume_one",
+        volume_high = "volume_two",
+    },
+    file_kinds = {
+        file = "file",
+        text = "file_text",
+        code = "file_code",
+        pdf = "file_text",
+        document = "file_text",
+        spreadsheet = "file_spreadsheet",
+        presentation = "presentation",
+        image = "file_image",
+        audio = "file_audio",
+        video = "file_video",
+        archive = "file_archive",
+        font = "file_type",
+        binary = "file_digit",
+    },
+    roles = "roles/lucide.toml",
 );
 
 #[cfg(feature = "nerd")]
@@ -64,16 +368,117 @@ pub const NERD_FONT_BYTES: &[u8] = include_bytes!("../fonts/nerd.ttf");
 /// The nerd icon font.
 pub const NERD_FONT: Font = Font::with_name("Symbols Nerd Font Mono");
 #[cfg(feature = "nerd")]
-generate_icon_functions!("fonts/nerd.ttf", nerd, NERD_FONT);
+generate_icon_functions!(
+    "fonts/nerd.ttf",
+    nerd,
+    NERD_FONT,
+    expect = 10397,
+    snapshot = "snapshots/nerd.txt",
+    changes = "changes/nerd.txt",
+    min_size = 16,
+    deprecated = "deprecated/nerd.toml",
+    number_badges = "md_numeric_{}_box",
+    spinners = {
+        fira = ["u_ee06", "u_ee07", "u_ee08", "u_ee09", "u_ee0a", "u_ee0b"],
+        circle = [
+            "md_circle_slice_one", "md_circle_slice_two", "md_circle_slice_three",
+            "md_circle_slice_four", "md_circle_slice_five", "md_circle_slice_six",
+            "md_circle_slice_seven", "md_circle_slice_eight",
+        ],
+        clock = [
+            "md_clock_time_twelve", "md_clock_time_one", "md_clock_time_two",
+            "md_clock_time_three", "md_clock_time_four", "md_clock_time_five",
+            "md_clock_time_six", "md_clock_time_seven", "md_clock_time_eight",
+            "md_clock_time_nine", "md_clock_time_ten", "md_clock_time_eleven",
+        ],
+        hourglass = ["md_timer_sand_full", "md_timer_sand", "md_timer_sand_empty"],
+    },
+    submodules = [
+        "cod", "custom", "dev", "fa", "fae", "iec", "linux", "md", "oct", "pl", "ple", "pom",
+        "seti", "weather",
+    ],
+    arrows = "md_arrow_{}",
+    chevrons = "md_chevron_{}",
+    selection = {
+        checked = "md_checkbox_marked",
+        unchecked = "md_checkbox_blank_outline",
+        indeterminate = "md_checkbox_intermediate",
+        radio_on = "md_radiobox_marked",
+        radio_off = "md_checkbox_blank_circle_outline",
+    },
+    media = {
+        play = "md_play",
+        pause = "md_pause",
+        stop = "md_stop",
+        next = "md_skip_next",
+        previous = "md_skip_previous",
+        shuffle = "md_shuffle",
+        repeat = "md_repeat",
+        volume_mute = "md_volume_off",
+        volume_low = "md_volume_low",
+        volume_high = "md_volume_high",
+    },
+    vcs = {
+        modified = "oct_diff_modified",
+        added = "oct_diff_added",
+        deleted = "oct_diff_removed",
+        renamed = "oct_diff_renamed",
+        conflicted = "oct_alert",
+        untracked = "oct_question",
+    },
+    file_kinds = {
+        file = "md_file",
+        text = "md_file_document",
+        code = "md_file_code",
+        pdf = "md_file_pdf_box",
+        document = "md_file_word",
+        spreadsheet = "md_file_excel",
+        presentation = "md_file_powerpoint",
+        image = "md_file_image",
+        audio = "md_file_music",
+        video = "md_file_video",
+        archive = "md_zip_box",
+        font = "md_format_font",
+        binary = "md_file_cog",
+    },
+    platforms = {
+        linux = "linux_tux",
+        windows = "md_microsoft_windows",
+        macos = "linux_apple",
+        android = "md_android",
+        ios = "md_apple_ios",
+    },
+    roles = "roles/nerd.toml",
+);
 
 #[cfg(feature = "octicons")]
 /// The default icon font bytes for loading the font into iced.
 pub const OCTICONS_FONT_BYTES: &[u8] = include_bytes!("../fonts/octicons.otf");
 #[cfg(feature = "octicons")]
 /// The octicon icon font.
-pub const OCTICONS_FONT: Font = Font::with_name("OcticonsNerdFont-Regular");
+pub const OCTICONS_FONT: Font = Font::with_name("Octicons Nerd Font");
 #[cfg(feature = "octicons")]
-generate_icon_advanced_functions!("fonts/octicons.otf", octicons, OCTICONS_FONT);
+generate_icon_functions!(
+    "fonts/octicons.otf",
+    octicons,
+    OCTICONS_FONT,
+    expect = 311,
+    snapshot = "snapshots/octicons.txt",
+    changes = "changes/octicons.txt",
+    fallbacks = "fallbacks/octicons.toml",
+    shaping = advanced,
+    arrows = "arrow_{}",
+    chevrons = "chevron_{}",
+    vcs = {
+        modified = "diff_modified",
+        added = "diff_added",
+        deleted = "diff_removed",
+        renamed = "diff_renamed",
+        conflicted = "alert",
+        untracked = "question",
+    },
+    roles = "roles/octicons.toml",
+);
 
 #[cfg(feature = "pomicons")]
 /// The default icon font bytes for loading the font into iced.
@@ -82,4 +487,208 @@ pub const POMICONS_FONT_BYTES: &[u8] = include_bytes!("../fonts/pomicons.otf");
 /// The pomicon icon font.
 pub const POMICONS_FONT: Font = Font::with_name("Pomicons");
 #[cfg(feature = "pomicons")]
-generate_icon_functions!("fonts/pomicons.otf", pomicons, POMICONS_FONT);
+generate_icon_functions!(
+    "fonts/pomicons.otf",
+    pomicons,
+    POMICONS_FONT,
+    expect = 13,
+    snapshot = "snapshots/pomicons.txt",
+    changes = "changes/pomicons.txt",
+    fallbacks = "fallbacks/pomicons.toml",
+    deprecated = "deprecated/pomicons.toml"
+);
+
+#[cfg(all(
+    test,
+    any(
+        feature = "bootstrap",
+        feature = "codicon",
+        feature = "devicon",
+        feature = "fontawesome",
+        feature = "lucide",
+        feature = "nerd",
+        feature = "octicons",
+        feature = "pomicons",
+    )
+))]
+mod tests {
+    // Apps store the ids, so they stay the same and no two icons of the bundled fonts share one.
+    #[test]
+    fn ids_are_stable_and_unique() {
+        let mut ids = std::collections::HashSet::new();
+        let mut count = 0;
+
+        macro_rules! fonts {
+            ($($feature:literal $module:ident $icon:ident $id:literal),* $(,)?) => {
+                $(
+                    #[cfg(feature = $feature)]
+                    {
+                        use crate::$module::{COUNT, Icon};
+
+                        assert_eq!(Icon::$icon.id(), $id);
+                        ids.extend(Icon::ALL.iter().map(|icon| icon.id()));
+                        count += COUNT;
+                    }
+                )*
+            };
+        }
+
+        fonts! {
+            "bootstrap" bootstrap AlarmFill 0xf420_f101,
+            "codicon" codicon Add 0x6860_ea60,
+            "devicon" devicon Space 0x5640_0020,
+            "fontawesome" fontawesome Space 0xb520_0020,
+            "lucide" lucide Activity 0x4c20_e038,
+            "nerd" nerd IecPower 0x67a0_23fb,
+            "octicons" octicons Space 0xbc40_0020,
+            "pomicons" pomicons Space 0xe760_0020,
+        }
+        assert_eq!(ids.len(), count);
+    }
+
+    #[cfg(feature = "octicons")]
+    #[test]
+    fn shortcodes_are_replaced_in_text() {
+        use crate::octicons::{Icon, replace_shortcodes};
+
+        // `:hearts:` is the emoji at Octicons' heart, `:heart:` its function name.
+        let spans = replace_shortcodes::<()>("Ship it :hearts: at 10:30 :nope: :zap::heart:!");
+        let spans: Vec<(&str, bool)> = spans
+            .iter()
+            .map(|span| (span.text.as_ref(), span.font.is_some()))
+            .collect();
+
+        assert_eq!(
+            spans,
+            [
+                ("Ship it ", false),
+                ("\u{2665}", true),
+                (" at 10:30 :nope: ", false),
+                ("\u{26a1}", true),
+                ("\u{2665}", true),
+                ("!", false),
+            ]
+        );
+        assert_eq!(Icon::from_shortcode(":zap:"), Some(Icon::Zap));
+        assert_eq!(Icon::from_shortcode(":tada:"), None);
+        assert_eq!(Icon::from_shortcode("zap"), None);
+    }
+
+    #[cfg(all(feature = "bootstrap", feature = "fontawesome"))]
+    #[test]
+    fn icons_are_drawn_with_the_shaping_of_their_font() {
+        use crate::{IconFont, IconTheme, Role};
+        use iced_widget::core::text::Shaping;
+
+        assert_eq!(crate::bootstrap::Icon::House.shaping(), Shaping::Basic);
+        assert_eq!(crate::fontawesome::Icon::House.shaping(), Shaping::Advanced);
+        assert_eq!(
+            crate::FontIcon::shaping(crate::fontawesome::Icon::House),
+            Shaping::Advanced
+        );
+
+        let theme = IconTheme::builtin(IconFont::Fontawesome).unwrap();
+        assert_eq!(theme.shaping(Role::Save), Some(Shaping::Advanced));
+        let theme = IconTheme::builtin(IconFont::Bootstrap).unwrap();
+        assert_eq!(theme.shaping(Role::Save), Some(Shaping::Basic));
+    }
+
+    #[cfg(all(feature = "bootstrap", feature = "fontawesome", feature = "lucide"))]
+    #[test]
+    fn names_resolve_to_the_first_font_with_them() {
+        use crate::{BOOTSTRAP_FONT, FONTAWESOME_FONT, IconFont, LUCIDE_FONT, resolve, resolve_in};
+
+        // All three have a house, the default order starts with Lucide.
+        assert_eq!(
+            resolve("house"),
+            Some((crate::lucide::Icon::House.char(), LUCIDE_FONT))
+        );
+        assert_eq!(
+            resolve_in(&[IconFont::Bootstrap, IconFont::Lucide], "house"),
+            Some((crate::bootstrap::Icon::House.char(), BOOTSTRAP_FONT))
+        );
+        // Only Bootstrap has it, or only Font Awesome after the ones without it.
+        assert_eq!(
+            resolve("alarm_fill"),
+            Some((crate::bootstrap::Icon::AlarmFill.char(), BOOTSTRAP_FONT))
+        );
+        assert_eq!(
+            resolve("hourglass_start"),
+            Some((
+                crate::fontawesome::Icon::HourglassStart.char(),
+                FONTAWESOME_FONT
+            ))
+        );
+        assert_eq!(resolve_in(&[IconFont::Bootstrap], "hourglass_start"), None);
+        assert_eq!(resolve("no_such_icon"), None);
+    }
+
+    #[cfg(all(
+        feature = "bootstrap",
+        feature = "codicon",
+        feature = "devicon",
+        feature = "fontawesome",
+        feature = "lucide",
+        feature = "nerd",
+        feature = "octicons",
+        feature = "pomicons"
+    ))]
+    #[test]
+    fn fonts_have_the_shaping_of_their_module() {
+        use crate::IconFont;
+
+        assert_eq!(
+            IconFont::Bootstrap.shaping(),
+            crate::bootstrap::Icon::ALL[0].shaping()
+        );
+        assert_eq!(
+            IconFont::Codicon.shaping(),
+            crate::codicon::Icon::ALL[0].shaping()
+        );
+        assert_eq!(
+            IconFont::Devicon.shaping(),
+            crate::devicon::Icon::ALL[0].shaping()
+        );
+        assert_eq!(
+            IconFont::Fontawesome.shaping(),
+            crate::fontawesome::Icon::ALL[0].shaping()
+        );
+        assert_eq!(
+            IconFont::Lucide.shaping(),
+            crate::lucide::Icon::ALL[0].shaping()
+        );
+        assert_eq!(
+            IconFont::Nerd.shaping(),
+            crate::nerd::Icon::ALL[0].shaping()
+        );
+        assert_eq!(
+            IconFont::Octicons.shaping(),
+            crate::octicons::Icon::ALL[0].shaping()
+        );
+        assert_eq!(
+            IconFont::Pomicons.shaping(),
+            crate::pomicons::Icon::ALL[0].shaping()
+        );
+    }
+
+    // The checkboxes and radio buttons of Nerd Fonts are all Material Design icons.
+    #[cfg(feature = "nerd")]
+    #[test]
+    fn nerd_selection_is_material_design() {
+        for icon in crate::nerd::Icon::SELECTION {
+            assert!(icon.name().starts_with("md_"), "{}", icon.name());
+        }
+    }
+
+    #[cfg(feature = "lucide")]
+    #[test]
+    fn lucide_has_german_names() {
+        use crate::lucide::Icon;
+
+        assert_eq!(Icon::House.display_name("de"), "Haus");
+        assert_eq!(Icon::House.display_name("de-AT"), "Haus");
+        assert_eq!(Icon::House.display_name("fr"), "house");
+        // Icons without a German name keep their function name.
+        assert_eq!(Icon::Anchor.display_name("de"), "anchor");
+    }
+}
@@ -0,0 +1,154 @@
+//! Media player controls from whichever enabled font has them.
+//!
+//! `media::play()` draws the play icon of the first of Bootstrap, Font Awesome, Lucide and the Nerd
+//! Font that is enabled, and all the other controls come from the same font so they match.
+
+#[cfg(any(
+    feature = "bootstrap",
+    feature = "fontawesome",
+    feature = "lucide",
+    feature = "nerd"
+))]
+use iced_core::{Font, text};
+#[cfg(any(
+    feature = "bootstrap",
+    feature = "fontawesome",
+    feature = "lucide",
+    feature = "nerd"
+))]
+use iced_widget::text::{Catalog, Text};
+
+use crate::FontIcon;
+
+/// A control of a music or video player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Media {
+    /// Start playing.
+    Play,
+    /// Pause playing.
+    Pause,
+    /// Stop playing.
+    Stop,
+    /// Skip to the next track.
+    Next,
+    /// Go back to the previous track.
+    Previous,
+    /// Play in random order.
+    Shuffle,
+    /// Play again from the start when done.
+    Repeat,
+    /// A muted volume.
+    VolumeMute,
+    /// A low volume.
+    VolumeLow,
+    /// A high volume.
+    VolumeHigh,
+}
+
+impl Media {
+    /// The volume icon of `level` between 0 and 1, muted at 0 and low below half.
+    #[must_use]
+    pub fn volume(level: f32) -> Self {
+        if level <= 0.0 {
+            Media::VolumeMute
+        } else if level < 0.5 {
+            Media::VolumeLow
+        } else {
+            Media::VolumeHigh
+        }
+    }
+}
+
+/// Icons with all the controls of a media player, so a player doesn't mix the play button of one
+/// font with the pause button of another. The `media` option generates them as `Icon::MEDIA`.
+pub trait MediaIcons: FontIcon {
+    /// The icons of every [`Media`] control, in the order of its variants.
+    fn media_icons() -> [Self; 10];
+
+    /// The icon of `media`.
+    fn media(media: Media) -> Self {
+        Self::media_icons()[media as usize]
+    }
+}
+
+macro_rules! media_icons {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl MediaIcons for crate::$module::Icon {
+                fn media_icons() -> [Self; 10] {
+                    Self::MEDIA
+                }
+            }
+        )*
+    };
+}
+
+media_icons! {
+    "bootstrap" bootstrap,
+    "fontawesome" fontawesome,
+    "lucide" lucide,
+    "nerd" nerd,
+}
+
+// The first enabled font with media controls supplies all of them.
+#[cfg(feature = "bootstrap")]
+type Resolved = crate::bootstrap::Icon;
+#[cfg(all(feature = "fontawesome", not(feature = "bootstrap")))]
+type Resolved = crate::fontawesome::Icon;
+#[cfg(all(
+    feature = "lucide",
+    not(any(feature = "bootstrap", feature = "fontawesome"))
+))]
+type Resolved = crate::lucide::Icon;
+#[cfg(all(
+    feature = "nerd",
+    not(any(feature = "bootstrap", feature = "fontawesome", feature = "lucide"))
+))]
+type Resolved = crate::nerd::Icon;
+
+/// The icon of `media` from the first enabled font with media controls.
+#[cfg(any(
+    feature = "bootstrap",
+    feature = "fontawesome",
+    feature = "lucide",
+    feature = "nerd"
+))]
+#[must_use]
+pub fn icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+    media: Media,
+) -> Text<'a, Theme, Renderer> {
+    Resolved::media(media).text()
+}
+
+macro_rules! controls {
+    ($($name:ident $media:ident $doc:literal),* $(,)?) => {
+        $(
+            #[doc = $doc]
+            #[cfg(any(
+                feature = "bootstrap",
+                feature = "fontawesome",
+                feature = "lucide",
+                feature = "nerd"
+            ))]
+            #[must_use]
+            pub fn $name<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+            ) -> Text<'a, Theme, Renderer> {
+                icon(Media::$media)
+            }
+        )*
+    };
+}
+
+controls! {
+    play Play "The play icon.",
+    pause Pause "The pause icon.",
+    stop Stop "The stop icon.",
+    next Next "The icon skipping to the next track.",
+    previous Previous "The icon going back to the previous track.",
+    shuffle Shuffle "The shuffle icon.",
+    repeat Repeat "The repeat icon.",
+    volume_mute VolumeMute "The icon of a muted volume.",
+    volume_low VolumeLow "The icon of a low volume.",
+    volume_high VolumeHigh "The icon of a high volume.",
+}
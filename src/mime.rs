@@ -0,0 +1,290 @@
+//! File type icons by MIME type, for file pickers and download managers.
+//!
+//! A MIME type is looked up exactly first, then by its structured syntax suffix like `+json`, then
+//! by its top-level type like `image/*`, and anything else is a generic file.
+
+#[cfg(any(
+    feature = "bootstrap",
+    feature = "fontawesome",
+    feature = "lucide",
+    feature = "nerd"
+))]
+use iced_core::{Font, text};
+#[cfg(any(
+    feature = "bootstrap",
+    feature = "fontawesome",
+    feature = "lucide",
+    feature = "nerd"
+))]
+use iced_widget::text::{Catalog, Text};
+
+use crate::FontIcon;
+
+/// What a file holds, as far as its icon goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    /// Any other file.
+    File,
+    /// Plain text.
+    Text,
+    /// Source code and structured data like JSON.
+    Code,
+    /// A PDF.
+    Pdf,
+    /// A word processor document.
+    Document,
+    /// A spreadsheet or table like CSV.
+    Spreadsheet,
+    /// Slides.
+    Presentation,
+    /// A picture.
+    Image,
+    /// Music and other sound.
+    Audio,
+    /// A movie.
+    Video,
+    /// A compressed archive.
+    Archive,
+    /// A font.
+    Font,
+    /// A program or other binary data.
+    Binary,
+}
+
+impl FileKind {
+    /// The kind of files of `mime`, like `"application/pdf"` or `"text/csv; charset=utf-8"`.
+    /// Parameters and case don't matter.
+    #[must_use]
+    pub fn from_mime(mime: &str) -> Self {
+        let essence = mime.split(';').next().unwrap_or_default().trim();
+        let essence = essence.to_ascii_lowercase();
+
+        if let Some(kind) = exact(&essence) {
+            return kind;
+        }
+
+        // Structured syntax suffixes name the format the type is built on.
+        let suffix = essence.rsplit_once('+').map(|(_, suffix)| suffix);
+        match suffix {
+            Some("json" | "xml" | "yaml" | "toml") => return FileKind::Code,
+            Some("zip" | "gzip") => return FileKind::Archive,
+            _ => {}
+        }
+
+        match essence.split_once('/').map(|(top, _)| top) {
+            Some("text") => FileKind::Text,
+            Some("image") => FileKind::Image,
+            Some("audio") => FileKind::Audio,
+            Some("video") => FileKind::Video,
+            Some("font") => FileKind::Font,
+            _ => FileKind::File,
+        }
+    }
+}
+
+/// The types whose kind doesn't follow from their top-level type.
+fn exact(essence: &str) -> Option<FileKind> {
+    Some(match essence {
+        "application/pdf" => FileKind::Pdf,
+
+        "application/msword"
+        | "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+        | "application/vnd.oasis.opendocument.text"
+        | "application/vnd.apple.pages"
+        | "application/rtf"
+        | "text/rtf"
+        | "application/epub+zip" => FileKind::Document,
+
+        "application/vnd.ms-excel"
+        | "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+        | "application/vnd.oasis.opendocument.spreadsheet"
+        | "application/vnd.apple.numbers"
+        | "text/csv"
+        | "text/tab-separated-values" => FileKind::Spreadsheet,
+
+        "application/vnd.ms-powerpoint"
+        | "application/vnd.openxmlformats-officedocument.presentationml.presentation"
+        | "application/vnd.oasis.opendocument.presentation"
+        | "application/vnd.apple.keynote" => FileKind::Presentation,
+
+        "application/zip"
+        | "application/x-zip-compressed"
+        | "application/gzip"
+        | "application/x-gzip"
+        | "application/x-tar"
+        | "application/x-bzip"
+        | "application/x-bzip2"
+        | "application/x-xz"
+        | "application/zstd"
+        | "application/x-7z-compressed"
+        | "application/vnd.rar"
+        | "application/x-rar-compressed"
+        | "application/java-archive"
+        | "application/x-apple-diskimage" => FileKind::Archive,
+
+        "application/json"
+        | "application/ld+json"
+        | "application/xml"
+        | "text/xml"
+        | "application/javascript"
+        | "text/javascript"
+        | "application/typescript"
+        | "text/css"
+        | "text/html"
+        | "application/x-sh"
+        | "text/x-shellscript"
+        | "text/x-python"
+        | "text/x-rust"
+        | "text/x-c"
+        | "text/x-c++"
+        | "text/x-java"
+        | "text/x-go"
+        | "application/toml"
+        | "application/yaml"
+        | "application/x-yaml"
+        | "text/yaml"
+        | "application/sql" => FileKind::Code,
+
+        "application/font-woff"
+        | "application/x-font-ttf"
+        | "application/x-font-otf"
+        | "application/vnd.ms-fontobject" => FileKind::Font,
+
+        "application/octet-stream"
+        | "application/x-executable"
+        | "application/x-elf"
+        | "application/x-sharedlib"
+        | "application/x-mach-binary"
+        | "application/x-msdownload"
+        | "application/vnd.microsoft.portable-executable"
+        | "application/wasm" => FileKind::Binary,
+
+        "application/ogg" => FileKind::Audio,
+
+        _ => return None,
+    })
+}
+
+/// Icons with a file icon for every [`FileKind`], looked up by MIME type with [`FileIcons::mime`].
+/// Kinds the font has no icon of their own for share the generic file, the way the `file_kinds`
+/// option generates `Icon::FILE_KINDS`.
+pub trait FileIcons: FontIcon {
+    /// The icons of every [`FileKind`], in the order of its variants.
+    fn file_icons() -> [Self; 13];
+
+    /// The icon of `kind`.
+    fn file(kind: FileKind) -> Self {
+        Self::file_icons()[kind as usize]
+    }
+
+    /// The icon of the files of `mime`.
+    fn mime(mime: &str) -> Self {
+        Self::file(FileKind::from_mime(mime))
+    }
+}
+
+macro_rules! file_icons {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl FileIcons for crate::$module::Icon {
+                fn file_icons() -> [Self; 13] {
+                    Self::FILE_KINDS
+                }
+            }
+        )*
+    };
+}
+
+file_icons! {
+    "bootstrap" bootstrap,
+    "fontawesome" fontawesome,
+    "lucide" lucide,
+    "nerd" nerd,
+}
+
+// The first enabled font with file icons supplies all of them.
+#[cfg(feature = "bootstrap")]
+type Resolved = crate::bootstrap::Icon;
+#[cfg(all(feature = "fontawesome", not(feature = "bootstrap")))]
+type Resolved = crate::fontawesome::Icon;
+#[cfg(all(
+    feature = "lucide",
+    not(any(feature = "bootstrap", feature = "fontawesome"))
+))]
+type Resolved = crate::lucide::Icon;
+#[cfg(all(
+    feature = "nerd",
+    not(any(feature = "bootstrap", feature = "fontawesome", feature = "lucide"))
+))]
+type Resolved = crate::nerd::Icon;
+
+/// The file icon of `mime`, like `"application/pdf"`, from the first enabled font with file icons.
+#[cfg(any(
+    feature = "bootstrap",
+    feature = "fontawesome",
+    feature = "lucide",
+    feature = "nerd"
+))]
+#[must_use]
+pub fn mime_icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+    mime: &str,
+) -> Text<'a, Theme, Renderer> {
+    Resolved::mime(mime).text()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileKind;
+
+    #[test]
+    fn exact_types_come_first() {
+        assert_eq!(FileKind::from_mime("application/pdf"), FileKind::Pdf);
+        assert_eq!(FileKind::from_mime("text/csv"), FileKind::Spreadsheet);
+        // Not an archive despite its suffix, nor text despite its top-level type.
+        assert_eq!(
+            FileKind::from_mime("application/epub+zip"),
+            FileKind::Document
+        );
+        assert_eq!(FileKind::from_mime("text/rtf"), FileKind::Document);
+    }
+
+    #[test]
+    fn parameters_and_case_are_ignored() {
+        assert_eq!(
+            FileKind::from_mime("Text/CSV; charset=utf-8"),
+            FileKind::Spreadsheet
+        );
+        assert_eq!(FileKind::from_mime(" APPLICATION/PDF "), FileKind::Pdf);
+        assert_eq!(
+            FileKind::from_mime("text/plain;charset=us-ascii"),
+            FileKind::Text
+        );
+    }
+
+    #[test]
+    fn suffixes_name_the_format() {
+        assert_eq!(
+            FileKind::from_mime("application/vnd.api+json"),
+            FileKind::Code
+        );
+        assert_eq!(
+            FileKind::from_mime("application/vnd.example+zip"),
+            FileKind::Archive
+        );
+        assert_eq!(
+            FileKind::from_mime("application/x-unknown+gzip"),
+            FileKind::Archive
+        );
+    }
+
+    #[test]
+    fn top_level_types_and_the_rest() {
+        assert_eq!(FileKind::from_mime("image/x-unknown"), FileKind::Image);
+        assert_eq!(FileKind::from_mime("audio/x-unknown"), FileKind::Audio);
+        assert_eq!(FileKind::from_mime("font/woff2"), FileKind::Font);
+        assert_eq!(FileKind::from_mime("application/x-unknown"), FileKind::File);
+        assert_eq!(FileKind::from_mime("not a type"), FileKind::File);
+        assert_eq!(FileKind::from_mime(""), FileKind::File);
+    }
+}
@@ -0,0 +1,92 @@
+//! The outlines of icons as `lyon` paths, for renderers of your own on top of wgpu or tiny-skia
+//! and effects iced's text can't do, like drawing an icon stroke by stroke.
+//!
+//! Paths are in ems with the y axis pointing down like in iced, the origin is where the glyph
+//! starts on the baseline. Scaling them by the font size gives pixels.
+
+use lyon_path::Path;
+use lyon_path::math::point;
+use ttf_parser::OutlineBuilder;
+
+use crate::inspect::Inspect;
+
+/// The version of `lyon_path` the outlines are from.
+pub use lyon_path;
+
+/// Icons with an outline, every icon that can be inspected.
+pub trait Outline: Inspect {
+    /// The contours of the icon, a path for each. Empty for an icon without an outline, like a
+    /// space, or a character missing from the font.
+    fn outline(self) -> Vec<Path> {
+        let face = Self::face();
+        let Some(glyph) = face.glyph_index(self.char()) else {
+            return Vec::new();
+        };
+        let mut contours = Contours {
+            scale: 1.0 / f32::from(face.units_per_em()),
+            builder: None,
+            paths: Vec::new(),
+        };
+        let _ = face.outline_glyph(glyph, &mut contours);
+        contours.end(false);
+
+        contours.paths
+    }
+}
+
+impl<T: Inspect> Outline for T {}
+
+/// Builds a path for every contour of a glyph, from font units with the y axis pointing up.
+struct Contours {
+    scale: f32,
+    builder: Option<lyon_path::path::Builder>,
+    paths: Vec<Path>,
+}
+
+impl Contours {
+    fn point(&self, x: f32, y: f32) -> lyon_path::math::Point {
+        point(x * self.scale, -y * self.scale)
+    }
+
+    /// Finishes the current contour, fonts that don't close their contours start the next one.
+    fn end(&mut self, close: bool) {
+        if let Some(mut builder) = self.builder.take() {
+            builder.end(close);
+            self.paths.push(builder.build());
+        }
+    }
+}
+
+impl OutlineBuilder for Contours {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.end(false);
+        let mut builder = Path::builder();
+        builder.begin(self.point(x, y));
+        self.builder = Some(builder);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        if let Some(builder) = &mut self.builder {
+            builder.line_to(to);
+        }
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (control, to) = (self.point(x1, y1), self.point(x, y));
+        if let Some(builder) = &mut self.builder {
+            builder.quadratic_bezier_to(control, to);
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (first, second, to) = (self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        if let Some(builder) = &mut self.builder {
+            builder.cubic_bezier_to(first, second, to);
+        }
+    }
+
+    fn close(&mut self) {
+        self.end(true);
+    }
+}
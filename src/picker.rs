@@ -0,0 +1,1421 @@
+//! Building blocks of icon pickers: a grid of icons to pick from with the mouse or the keyboard,
+//! cells previewing their icon on hover and dragging it out, and the recently used and favorite
+//! icons every picker ends up showing above them.
+//!
+//! Icons are kept by their function names, like `house` or `arrow_left`, which
+//! `Icon::from_name` turns back into icons and which stay the same across releases. With the
+//! `serde` feature, [`History`] is serializable, so it can be saved with the rest of the settings.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::ops::Range;
+
+use iced_core::keyboard::{self, key::Named};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::text::{self, Text};
+use iced_core::theme;
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::Id;
+use iced_core::widget::operation::{self, Operation};
+use iced_core::widget::tree::{self, Tree};
+use iced_core::{
+    Border, Clipboard, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget, alignment,
+};
+
+/// The recently used icons, most recent first and at most [`History::capacity`] of them, and the
+/// favorite icons.
+///
+/// ```ignore
+/// // Message::Picked(icon)
+/// self.history.record(icon.name());
+///
+/// for icon in self.history.recent().filter_map(lucide::Icon::from_name) {
+///     ...
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct History {
+    recent: VecDeque<String>,
+    favorites: BTreeSet<String>,
+    capacity: usize,
+}
+
+impl History {
+    /// The number of recent icons [`History::default`] keeps.
+    pub const DEFAULT_CAPACITY: usize = 24;
+
+    /// Creates an empty [`History`] keeping `capacity` recent icons.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            recent: VecDeque::with_capacity(capacity),
+            favorites: BTreeSet::new(),
+            capacity,
+        }
+    }
+
+    /// The number of recent icons kept.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the number of recent icons kept, forgetting the oldest ones beyond it.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.recent.truncate(capacity);
+    }
+
+    /// Records that the icon named `name` was used, moving it to the front of the recent icons.
+    /// The least recently used icon is forgotten once there are more than the capacity.
+    pub fn record(&mut self, name: &str) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.forget(name);
+        self.recent.truncate(self.capacity - 1);
+        self.recent.push_front(name.to_owned());
+    }
+
+    /// The names of the recently used icons, most recent first.
+    pub fn recent(&self) -> impl Iterator<Item = &str> {
+        self.recent.iter().map(String::as_str)
+    }
+
+    /// Forgets the recently used icon named `name`, after the icon was removed from the font.
+    pub fn forget(&mut self, name: &str) {
+        self.recent.retain(|recent| recent != name);
+    }
+
+    /// Forgets every recently used icon, keeping the favorites.
+    pub fn clear_recent(&mut self) {
+        self.recent.clear();
+    }
+
+    /// Whether the icon named `name` is a favorite.
+    #[must_use]
+    pub fn is_favorite(&self, name: &str) -> bool {
+        self.favorites.contains(name)
+    }
+
+    /// Makes the icon named `name` a favorite or not.
+    pub fn set_favorite(&mut self, name: &str, favorite: bool) {
+        if favorite {
+            let _ = self.favorites.insert(name.to_owned());
+        } else {
+            let _ = self.favorites.remove(name);
+        }
+    }
+
+    /// Toggles whether the icon named `name` is a favorite, for a star button, returning whether
+    /// it is one now.
+    pub fn toggle_favorite(&mut self, name: &str) -> bool {
+        let favorite = !self.is_favorite(name);
+        self.set_favorite(name, favorite);
+        favorite
+    }
+
+    /// The names of the favorite icons, in alphabetical order.
+    pub fn favorites(&self) -> impl Iterator<Item = &str> {
+        self.favorites.iter().map(String::as_str)
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+/// Creates an [`IconGrid`] of `icons`, their function names, characters and fonts.
+///
+/// ```ignore
+/// icon_grid(lucide::Icon::search(&self.search).map(|icon| (icon.name(), icon.char(), icon.font())))
+///     .on_select(Message::Picked)
+/// ```
+pub fn icon_grid<'a, Message, Theme, Renderer>(
+    icons: impl IntoIterator<Item = (&'a str, char, Font)>,
+) -> IconGrid<'a, Message, Theme, Renderer> {
+    IconGrid::new(icons)
+}
+
+/// A grid of icons to pick one from, as many per row as fit.
+///
+/// Once focused, by clicking it or with [`operation::focusable::focus`], it follows the keyboard:
+/// the arrow keys move the cursor, Page Up and Page Down move it by the rows in view, Home and End
+/// go to the first and last icon, Enter and Space pick the icon under the cursor, and typing
+/// jumps to the first icon whose name starts with what was typed. Escape unfocuses it.
+///
+/// Only the cells in view are drawn, so grids of every icon of a font stay fast.
+#[allow(missing_debug_implementations)]
+pub struct IconGrid<'a, Message, Theme = iced_widget::Theme, Renderer = iced_widget::Renderer> {
+    icons: Vec<(&'a str, char, Font)>,
+    id: Option<Id>,
+    selected: Option<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    view_cell: Option<ViewCell<'a, Message, Theme, Renderer>>,
+    /// The cells of the icons in view made by [`IconGrid::cell`], their trees are the children of
+    /// the grid's.
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+    cell: f32,
+    size: f32,
+    shaping: text::Shaping,
+}
+
+/// What [`IconGrid::cell`] draws every cell with.
+type ViewCell<'a, Message, Theme, Renderer> =
+    Box<dyn Fn(GridIcon<'a>) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// An icon of an [`IconGrid`], for drawing its cell with [`IconGrid::cell`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridIcon<'a> {
+    /// The index of the icon in the grid.
+    pub index: usize,
+    /// The function name of the icon.
+    pub name: &'a str,
+    /// The character of the icon.
+    pub char: char,
+    /// The font of the icon.
+    pub font: Font,
+    /// The shaping of the icons of the grid.
+    pub shaping: text::Shaping,
+    /// Whether the icon is the picked one.
+    pub is_selected: bool,
+}
+
+impl<'a, Message, Theme, Renderer> IconGrid<'a, Message, Theme, Renderer> {
+    /// Creates an [`IconGrid`] of `icons`, with cells of 48 pixels and icons of 24.
+    pub fn new(icons: impl IntoIterator<Item = (&'a str, char, Font)>) -> Self {
+        Self {
+            icons: icons.into_iter().collect(),
+            id: None,
+            selected: None,
+            on_select: None,
+            view_cell: None,
+            cells: Vec::new(),
+            cell: 48.0,
+            size: 24.0,
+            shaping: text::Shaping::Basic,
+        }
+    }
+
+    /// Sets what every cell shows instead of its icon, for badges, ownership markers or labels.
+    /// The grid still lays the cells out, draws the highlights behind them and handles picking;
+    /// the contents are centered in their cells. Only the cells in view are created.
+    ///
+    /// ```ignore
+    /// icon_grid(icons).cell(|icon| {
+    ///     let glyph = text(icon.char).font(icon.font).shaping(icon.shaping).size(24);
+    ///     if used.contains(icon.name) {
+    ///         stack![glyph, badge("In use")].into()
+    ///     } else {
+    ///         glyph.into()
+    ///     }
+    /// })
+    /// ```
+    #[must_use]
+    pub fn cell(
+        mut self,
+        view: impl Fn(GridIcon<'a>) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        self.view_cell = Some(Box::new(view));
+        self
+    }
+
+    /// Sets the [`Id`] of the grid, for focusing it with [`operation::focusable::focus`].
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the message produced with the index of the icon picked by a click or Enter. Without
+    /// one, the icons can be browsed but not picked.
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the index of the picked icon, which is highlighted and where the cursor starts.
+    #[must_use]
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the side of the square of every icon.
+    #[must_use]
+    pub fn cell_size(mut self, cell: impl Into<Pixels>) -> Self {
+        self.cell = cell.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the size of the icons, unless [`IconGrid::cell`] draws them.
+    #[must_use]
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the shaping of the icons, `Icon::shaping()` of their module, basic by default. Grids
+    /// of the icons of several fonts take advanced shaping, which draws all of them.
+    #[must_use]
+    pub fn shaping(mut self, shaping: text::Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// The number of icons per row in `width`, at least one.
+    fn columns(&self, width: f32) -> usize {
+        ((width / self.cell) as usize).max(1)
+    }
+
+    /// The square of the icon at `index`.
+    fn cell_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let columns = self.columns(bounds.width);
+        Rectangle {
+            x: bounds.x + (index % columns) as f32 * self.cell,
+            y: bounds.y + (index / columns) as f32 * self.cell,
+            width: self.cell,
+            height: self.cell,
+        }
+    }
+
+    /// The indices of the icons whose cells are at least partly in `viewport`.
+    fn visible(&self, bounds: Rectangle, viewport: &Rectangle) -> Range<usize> {
+        let columns = self.columns(bounds.width);
+        let top = ((viewport.y - bounds.y) / self.cell).floor().max(0.0) as usize;
+        let bottom = ((viewport.y + viewport.height - bounds.y) / self.cell)
+            .ceil()
+            .max(0.0) as usize;
+
+        (top * columns).min(self.icons.len())..(bottom * columns).min(self.icons.len())
+    }
+
+    /// Makes the cells of the icons in view when [`IconGrid::cell`] draws them, keeping the trees
+    /// of the cells that were in view already, and lays them out in a grid of `size`.
+    fn layout_cells(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        size: Size,
+    ) -> Vec<layout::Node>
+    where
+        Renderer: iced_core::Renderer,
+    {
+        let state = tree.state.downcast_mut::<State>();
+        let Some(view_cell) = &self.view_cell else {
+            state.cells = 0..0;
+            tree.children.clear();
+            return Vec::new();
+        };
+        let visible =
+            state.visible.start.min(self.icons.len())..state.visible.end.min(self.icons.len());
+        let previous = std::mem::replace(&mut state.cells, visible.clone());
+        let mut trees: Vec<Option<Tree>> = std::mem::take(&mut tree.children)
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        let mut cells = Vec::with_capacity(visible.len());
+        let mut nodes = Vec::with_capacity(visible.len());
+        for index in visible {
+            let (name, c, font) = self.icons[index];
+            let mut element = view_cell(GridIcon {
+                index,
+                name,
+                char: c,
+                font,
+                shaping: self.shaping,
+                is_selected: self.selected == Some(index),
+            });
+            let kept = index
+                .checked_sub(previous.start)
+                .and_then(|kept| trees.get_mut(kept))
+                .and_then(Option::take);
+            let mut cell_tree = match kept {
+                Some(mut cell_tree) => {
+                    cell_tree.diff(&element);
+                    cell_tree
+                }
+                None => Tree::new(&element),
+            };
+
+            let cell = self.cell_bounds(Rectangle::with_size(size), index);
+            let node = element.as_widget_mut().layout(
+                &mut cell_tree,
+                renderer,
+                &layout::Limits::new(Size::ZERO, cell.size()),
+            );
+            let node_size = node.size();
+            nodes.push(node.move_to(Point::new(
+                cell.x + (cell.width - node_size.width) / 2.0,
+                cell.y + (cell.height - node_size.height) / 2.0,
+            )));
+            cells.push(element);
+            tree.children.push(cell_tree);
+        }
+
+        self.cells = cells;
+        nodes
+    }
+
+    /// The index of the icon at `position`, relative to the grid.
+    fn index_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        let columns = self.columns(bounds.width);
+        let column = (position.x / self.cell) as usize;
+        let index = (position.y / self.cell) as usize * columns + column;
+
+        (column < columns && index < self.icons.len()).then_some(index)
+    }
+
+    /// Where `key` moves the cursor from `cursor`, `None` for keys that don't move it. A page is
+    /// the rows of the grid in `viewport`.
+    fn moved(
+        &self,
+        key: &keyboard::Key,
+        cursor: usize,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Option<usize> {
+        let columns = self.columns(bounds.width);
+        let last = self.icons.len().checked_sub(1)?;
+        let page = columns * ((viewport.height / self.cell) as usize).max(1);
+
+        let moved = match key {
+            keyboard::Key::Named(Named::ArrowLeft) => cursor.saturating_sub(1),
+            keyboard::Key::Named(Named::ArrowRight) => cursor + 1,
+            keyboard::Key::Named(Named::ArrowUp) => cursor.checked_sub(columns).unwrap_or(cursor),
+            // The last row can be shorter, moving down from above its end stays put.
+            keyboard::Key::Named(Named::ArrowDown) if cursor + columns > last => cursor,
+            keyboard::Key::Named(Named::ArrowDown) => cursor + columns,
+            keyboard::Key::Named(Named::PageUp) => {
+                cursor.checked_sub(page).unwrap_or(cursor % columns)
+            }
+            keyboard::Key::Named(Named::PageDown) => cursor + page,
+            keyboard::Key::Named(Named::Home) => 0,
+            keyboard::Key::Named(Named::End) => last,
+            _ => return None,
+        };
+
+        Some(moved.min(last))
+    }
+
+    /// The first icon whose name starts with `prefix`, ignoring case.
+    fn starting_with(&self, prefix: &str) -> Option<usize> {
+        self.icons.iter().position(|(name, _, _)| {
+            name.get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+        })
+    }
+
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_select) = &self.on_select {
+            shell.publish(on_select(index));
+        }
+    }
+}
+
+/// How long after the last key typed characters still add to the name jumped to.
+const TYPE_AHEAD: Duration = Duration::from_secs(1);
+
+/// Whether the grid is focused, where its cursor is, what was typed, and which cells are in view.
+#[derive(Debug, Default)]
+struct State {
+    is_focused: bool,
+    cursor: Option<usize>,
+    typed: String,
+    last_typed: Option<Instant>,
+    /// The icons in view at the last update, whose cells the next layout makes.
+    visible: Range<usize>,
+    /// The icons whose cells the last layout made, with the trees of the cells in this order.
+    cells: Range<usize>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for IconGrid<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    // The layout makes the cells, and keeps their trees with them.
+    fn diff(&self, _tree: &mut Tree) {}
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let width = limits.max().width;
+        let rows = self.icons.len().div_ceil(self.columns(width));
+        let size = limits.resolve(
+            Length::Fill,
+            Length::Shrink,
+            Size::new(width, rows as f32 * self.cell),
+        );
+
+        let cells = self.layout_cells(tree, renderer, size);
+        layout::Node::with_children(size, cells)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        // Scrolling brings other cells into view, which the layout makes. Every frame is preceded
+        // by an update, so they're there in time to be drawn.
+        if self.view_cell.is_some() {
+            let visible = self.visible(bounds, viewport);
+            if visible != state.visible {
+                state.visible = visible;
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
+
+        // Interactive contents of cells, like buttons on badges, come first.
+        for ((cell, tree), layout) in self
+            .cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            cell.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+            if shell.is_event_captured() {
+                return;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    state.is_focused = false;
+                    return;
+                };
+                state.is_focused = true;
+                if let Some(index) = self.index_at(bounds, position) {
+                    state.cursor = Some(index);
+                    self.select(index, shell);
+                }
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. }) if state.is_focused => {
+                let current = state.cursor.or(self.selected).unwrap_or(0);
+
+                if let Some(moved) = self.moved(key, current, bounds, viewport) {
+                    state.cursor = Some(moved);
+                    state.typed.clear();
+                } else {
+                    match key {
+                        keyboard::Key::Named(Named::Enter | Named::Space)
+                            if current < self.icons.len() =>
+                        {
+                            self.select(current, shell);
+                        }
+                        keyboard::Key::Named(Named::Escape) => state.is_focused = false,
+                        _ => {
+                            let Some(text) = text.as_deref().filter(|text| {
+                                text.chars().all(|c| !c.is_control() && !c.is_whitespace())
+                            }) else {
+                                return;
+                            };
+                            let now = Instant::now();
+                            if state
+                                .last_typed
+                                .is_none_or(|last| now.duration_since(last) > TYPE_AHEAD)
+                            {
+                                state.typed.clear();
+                            }
+                            state.typed.push_str(text);
+                            state.last_typed = Some(now);
+
+                            if let Some(index) = self.starting_with(&state.typed) {
+                                state.cursor = Some(index);
+                            }
+                        }
+                    }
+                }
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let interaction = self
+            .cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((cell, tree), layout)| {
+                cell.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or(mouse::Interaction::None);
+        if interaction != mouse::Interaction::None {
+            return interaction;
+        }
+
+        let over_icon = cursor
+            .position_in(bounds)
+            .and_then(|position| self.index_at(bounds, position))
+            .is_some();
+
+        if over_icon && self.on_select.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let color = style.text_color;
+        let hovered = cursor
+            .position_in(bounds)
+            .and_then(|position| self.index_at(bounds, position));
+        let focused = state
+            .is_focused
+            .then(|| state.cursor.or(self.selected).unwrap_or(0));
+
+        for index in self.visible(bounds, viewport) {
+            let cell = self.cell_bounds(bounds, index);
+
+            // The picked icon is filled, the hovered one faintly, and the cursor is outlined.
+            let fill = if self.selected == Some(index) {
+                Some(0.16)
+            } else if hovered == Some(index) {
+                Some(0.08)
+            } else {
+                None
+            };
+            let outline = focused == Some(index);
+            if fill.is_some() || outline {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell.shrink(2.0),
+                        border: Border {
+                            color: if outline { color } else { Color::TRANSPARENT },
+                            width: if outline { 2.0 } else { 0.0 },
+                            radius: 4.0.into(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color {
+                        a: color.a * fill.unwrap_or(0.0),
+                        ..color
+                    },
+                );
+            }
+
+            if self.view_cell.is_none() {
+                let (_, c, font) = self.icons[index];
+                renderer.fill_text(
+                    Text {
+                        content: c.to_string(),
+                        bounds: cell.size(),
+                        size: Pixels(self.size),
+                        line_height: text::LineHeight::Relative(1.0),
+                        font,
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: self.shaping,
+                        wrapping: text::Wrapping::None,
+                    },
+                    Point::new(cell.center_x(), cell.center_y()),
+                    color,
+                    *viewport,
+                );
+            }
+        }
+
+        for ((cell, tree), layout) in self.cells.iter().zip(&tree.children).zip(layout.children()) {
+            cell.as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.cells,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<IconGrid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(grid: IconGrid<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(grid)
+    }
+}
+
+/// Creates a [`PreviewCell`] of the icon named `name`.
+///
+/// ```ignore
+/// preview_cell(icon.name(), icon.char(), icon.font()).on_press(Message::Picked(icon))
+/// ```
+pub fn preview_cell<'a, Message>(name: &'a str, c: char, font: Font) -> PreviewCell<'a, Message> {
+    PreviewCell::new(name, c, font)
+}
+
+/// A cell of an icon which, while hovered, shows an enlarged preview of it with its function name
+/// and its `U+XXXX` codepoint in an overlay below it.
+///
+/// With [`PreviewCell::on_drag`], the icon can be dragged out of the cell, onto the canvas of a
+/// form designer for example.
+#[allow(missing_debug_implementations)]
+pub struct PreviewCell<'a, Message> {
+    name: &'a str,
+    char: char,
+    font: Font,
+    on_press: Option<Message>,
+    on_drag: Option<Box<dyn Fn(Drag) -> Message + 'a>>,
+    cell: f32,
+    size: f32,
+    preview: f32,
+}
+
+impl<'a, Message> PreviewCell<'a, Message> {
+    /// Creates a [`PreviewCell`] of the icon named `name`, in a cell of 48 pixels with an icon of
+    /// 24 and a preview of 96.
+    pub fn new(name: &'a str, c: char, font: Font) -> Self {
+        Self {
+            name,
+            char: c,
+            font,
+            on_press: None,
+            on_drag: None,
+            cell: 48.0,
+            size: 24.0,
+            preview: 96.0,
+        }
+    }
+
+    /// Sets the message produced when the cell is clicked. With [`PreviewCell::on_drag`], it's
+    /// produced when the button is released without dragging.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Makes the icon draggable, producing a message for every [`Drag`] event: where it was moved
+    /// to, where it was dropped, or that the drag was canceled with Escape.
+    ///
+    /// ```ignore
+    /// preview_cell(icon.name(), icon.char(), icon.font()).on_drag(Message::Dragged)
+    /// ```
+    #[must_use]
+    pub fn on_drag(mut self, on_drag: impl Fn(Drag) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    fn dragged(&self) -> DraggedIcon {
+        DraggedIcon {
+            name: self.name.to_owned(),
+            char: self.char,
+            font: self.font,
+        }
+    }
+
+    /// Sets the side of the square of the cell.
+    #[must_use]
+    pub fn cell_size(mut self, cell: impl Into<Pixels>) -> Self {
+        self.cell = cell.into().0;
+        self
+    }
+
+    /// Sets the size of the icon in the cell.
+    #[must_use]
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the size of the icon in the preview.
+    #[must_use]
+    pub fn preview_size(mut self, preview: impl Into<Pixels>) -> Self {
+        self.preview = preview.into().0;
+        self
+    }
+}
+
+/// An icon dragged out of a [`PreviewCell`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DraggedIcon {
+    /// The function name of the icon.
+    pub name: String,
+    /// The character of the icon.
+    pub char: char,
+    /// The font of the icon.
+    pub font: Font,
+}
+
+/// What happened to an icon dragged out of a [`PreviewCell`]. Positions are in the coordinates of
+/// the window, so drop targets compare them with their bounds in it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Drag {
+    /// The icon was dragged to the position.
+    Moved(DraggedIcon, Point),
+    /// The icon was dropped at the position.
+    Dropped(DraggedIcon, Point),
+    /// The drag was canceled with Escape.
+    Canceled,
+}
+
+/// How far the cursor has to move with the button held for a click to become a drag.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// Whether the cell is hovered, which shows the preview, and the drag of its icon.
+#[derive(Debug, Default)]
+struct CellState {
+    is_hovered: bool,
+    /// The last position of the cursor in the window, which scrollables around the cell don't
+    /// translate.
+    position: Option<Point>,
+    /// Where the button was pressed on the cell, until it's released.
+    pressed_at: Option<Point>,
+    is_dragging: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for PreviewCell<'_, Message>
+where
+    Message: Clone,
+    Theme: theme::Base,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<CellState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(CellState::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.cell), Length::Fixed(self.cell))
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(Size::new(self.cell, self.cell))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<CellState>();
+
+        if state.is_dragging {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    state.position = Some(*position);
+                    if let Some(on_drag) = &self.on_drag {
+                        shell.publish(on_drag(Drag::Moved(self.dragged(), *position)));
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    state.is_dragging = false;
+                    state.pressed_at = None;
+                    if let (Some(on_drag), Some(position)) = (&self.on_drag, state.position) {
+                        shell.publish(on_drag(Drag::Dropped(self.dragged(), position)));
+                    }
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(Named::Escape),
+                    ..
+                }) => {
+                    state.is_dragging = false;
+                    state.pressed_at = None;
+                    if let Some(on_drag) = &self.on_drag {
+                        shell.publish(on_drag(Drag::Canceled));
+                    }
+                }
+                _ => return,
+            }
+            shell.request_redraw();
+            shell.capture_event();
+            return;
+        }
+
+        let Event::Mouse(event) = event else {
+            return;
+        };
+        let is_hovered = cursor.is_over(layout.bounds());
+
+        if is_hovered != state.is_hovered {
+            state.is_hovered = is_hovered;
+            shell.request_redraw();
+        }
+
+        match event {
+            mouse::Event::CursorMoved { position } => {
+                state.position = Some(*position);
+                let moved_away = state
+                    .pressed_at
+                    .is_some_and(|pressed_at| pressed_at.distance(*position) > DRAG_THRESHOLD);
+
+                if moved_away && let Some(on_drag) = &self.on_drag {
+                    state.is_dragging = true;
+                    state.is_hovered = false;
+                    shell.publish(on_drag(Drag::Moved(self.dragged(), *position)));
+                    shell.request_redraw();
+                }
+            }
+            mouse::Event::ButtonPressed(mouse::Button::Left) if is_hovered => {
+                if self.on_drag.is_some() {
+                    state.pressed_at = state.position;
+                } else if let Some(message) = &self.on_press {
+                    shell.publish(message.clone());
+                }
+                shell.capture_event();
+            }
+            mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                if state.pressed_at.take().is_some()
+                    && is_hovered
+                    && let Some(message) = &self.on_press
+                {
+                    shell.publish(message.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !cursor.is_over(layout.bounds()) {
+            mouse::Interaction::None
+        } else if self.on_drag.is_some() {
+            mouse::Interaction::Grab
+        } else if self.on_press.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let color = style.text_color;
+
+        if tree.state.downcast_ref::<CellState>().is_hovered {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds.shrink(2.0),
+                    border: Border {
+                        radius: 4.0.into(),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color {
+                    a: color.a * 0.08,
+                    ..color
+                },
+            );
+        }
+
+        renderer.fill_text(
+            centered(self.char.to_string(), self.size, self.font, bounds),
+            bounds.center(),
+            color,
+            *viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<CellState>();
+        if state.is_dragging {
+            return Some(overlay::Element::new(Box::new(Ghost {
+                char: self.char,
+                font: self.font,
+                size: self.size,
+                position: state.position?,
+            })));
+        }
+        if !state.is_hovered {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Preview {
+            name: self.name,
+            char: self.char,
+            font: self.font,
+            size: self.preview,
+            cell: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PreviewCell<'a, Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: theme::Base + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(cell: PreviewCell<'a, Message>) -> Self {
+        Element::new(cell)
+    }
+}
+
+/// The size of the name and codepoint in the preview.
+const LABEL_SIZE: f32 = 12.0;
+/// The height of a line of the name and codepoint, at the default line height of 1.3.
+const LINE: f32 = LABEL_SIZE * 1.3;
+/// The space around and between the parts of the preview.
+const PADDING: f32 = 8.0;
+
+/// The enlarged icon, its name and its codepoint, below the hovered cell or above it when the
+/// window ends below.
+struct Preview<'a> {
+    name: &'a str,
+    char: char,
+    font: Font,
+    size: f32,
+    cell: Rectangle,
+}
+
+impl Preview<'_> {
+    /// The widths of the name and codepoint lines, which may wrap the name once.
+    fn label_width(&self) -> f32 {
+        (self.size * 1.5).max(120.0)
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Preview<'_>
+where
+    Theme: theme::Base,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let width = self.label_width() + 2.0 * PADDING;
+        // The icon, two lines of name and one of codepoint.
+        let height = self.size + 3.0 * LINE + 3.0 * PADDING;
+
+        let x = (self.cell.center_x() - width / 2.0).clamp(0.0, (bounds.width - width).max(0.0));
+        let below = self.cell.y + self.cell.height;
+        let y = if below + height <= bounds.height {
+            below
+        } else {
+            (self.cell.y - height).max(0.0)
+        };
+
+        layout::Node::new(Size::new(width, height)).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let base = theme.base();
+        let color = base.text_color;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: Color {
+                        a: color.a * 0.2,
+                        ..color
+                    },
+                    width: 1.0,
+                    radius: 6.0.into(),
+                },
+                ..renderer::Quad::default()
+            },
+            base.background_color,
+        );
+
+        let icon = Rectangle {
+            y: bounds.y + PADDING,
+            height: self.size,
+            ..bounds
+        };
+        renderer.fill_text(
+            centered(self.char.to_string(), self.size, self.font, icon),
+            icon.center(),
+            color,
+            bounds,
+        );
+
+        let name = Rectangle {
+            y: icon.y + icon.height + PADDING,
+            height: 2.0 * LINE,
+            ..bounds.shrink(PADDING)
+        };
+        renderer.fill_text(
+            Text {
+                wrapping: text::Wrapping::Glyph,
+                align_y: alignment::Vertical::Top,
+                ..centered(self.name.to_owned(), LABEL_SIZE, Font::DEFAULT, name)
+            },
+            Point::new(name.center_x(), name.y),
+            color,
+            bounds,
+        );
+
+        let codepoint = Rectangle {
+            y: name.y + name.height,
+            height: LINE,
+            ..name
+        };
+        renderer.fill_text(
+            centered(
+                format!("U+{:04X}", u32::from(self.char)),
+                LABEL_SIZE,
+                Font::MONOSPACE,
+                codepoint,
+            ),
+            codepoint.center(),
+            Color {
+                a: color.a * 0.7,
+                ..color
+            },
+            bounds,
+        );
+    }
+}
+
+/// The icon of a drag, following the cursor.
+struct Ghost {
+    char: char,
+    font: Font,
+    size: f32,
+    position: Point,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Ghost
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, _bounds: Size) -> layout::Node {
+        layout::Node::new(Size::new(self.size, self.size)).move_to(Point::new(
+            self.position.x - self.size / 2.0,
+            self.position.y - self.size / 2.0,
+        ))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let color = style.text_color;
+
+        renderer.fill_text(
+            centered(self.char.to_string(), self.size, self.font, bounds),
+            bounds.center(),
+            Color {
+                a: color.a * 0.7,
+                ..color
+            },
+            bounds,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::Grabbing
+    }
+}
+
+/// `content` centered in `bounds`, on one line of the default height.
+fn centered(content: String, size: f32, font: Font, bounds: Rectangle) -> Text<String, Font> {
+    Text {
+        content,
+        bounds: bounds.size(),
+        size: Pixels(size),
+        line_height: text::LineHeight::default(),
+        font,
+        align_x: text::Alignment::Center,
+        align_y: alignment::Vertical::Center,
+        shaping: text::Shaping::Basic,
+        wrapping: text::Wrapping::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::keyboard::{Key, key::Named};
+    use iced_core::{Font, Point, Rectangle};
+
+    use super::{History, IconGrid};
+
+    fn recent(history: &History) -> Vec<&str> {
+        history.recent().collect()
+    }
+
+    #[test]
+    fn recent_icons_are_most_recent_first() {
+        let mut history = History::new(3);
+        for name in ["house", "star", "gear"] {
+            history.record(name);
+        }
+        assert_eq!(recent(&history), ["gear", "star", "house"]);
+
+        // Using an icon again moves it to the front instead of adding it twice.
+        history.record("house");
+        assert_eq!(recent(&history), ["house", "gear", "star"]);
+    }
+
+    #[test]
+    fn the_least_recent_icons_are_forgotten_beyond_the_capacity() {
+        let mut history = History::new(3);
+        for name in ["house", "star", "gear", "bell"] {
+            history.record(name);
+        }
+        assert_eq!(recent(&history), ["bell", "gear", "star"]);
+
+        history.set_capacity(2);
+        assert_eq!(recent(&history), ["bell", "gear"]);
+        history.record("star");
+        assert_eq!(recent(&history), ["star", "bell"]);
+
+        history.set_capacity(0);
+        history.record("house");
+        assert_eq!(history.recent().next(), None);
+    }
+
+    #[test]
+    fn favorites_are_kept_apart_from_the_recent_icons() {
+        let mut history = History::default();
+        history.record("house");
+        assert!(history.toggle_favorite("star"));
+        history.set_favorite("bell", true);
+        assert_eq!(history.favorites().collect::<Vec<_>>(), ["bell", "star"]);
+
+        history.clear_recent();
+        assert!(!history.toggle_favorite("star"));
+        assert_eq!(history.recent().next(), None);
+        assert_eq!(history.favorites().collect::<Vec<_>>(), ["bell"]);
+    }
+
+    /// A grid of `count` icons in cells of 48 pixels, three to a row.
+    fn grid(count: usize) -> IconGrid<'static, (), (), ()> {
+        const NAMES: [&str; 10] = [
+            "anchor", "bell", "book", "car", "clock", "gear", "heart", "house", "star", "sun",
+        ];
+        IconGrid::new(
+            NAMES[..count]
+                .iter()
+                .map(|name| (*name, 'x', Font::DEFAULT)),
+        )
+    }
+
+    /// The bounds of the grids, three cells wide.
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 144.0,
+        height: 192.0,
+    };
+
+    /// Where `key` moves the cursor from `cursor`, in view two rows at a time.
+    fn moved(grid: &IconGrid<'static, (), (), ()>, key: Named, cursor: usize) -> Option<usize> {
+        let bounds = BOUNDS;
+        let viewport = Rectangle {
+            height: 96.0,
+            ..bounds
+        };
+        grid.moved(&Key::Named(key), cursor, bounds, &viewport)
+    }
+
+    #[test]
+    fn arrows_stop_at_the_edges_of_the_grid() {
+        // 0 1 2
+        // 3 4 5
+        // 6 7 8
+        // 9
+        let grid = grid(10);
+
+        assert_eq!(moved(&grid, Named::ArrowLeft, 0), Some(0));
+        assert_eq!(moved(&grid, Named::ArrowUp, 1), Some(1));
+        assert_eq!(moved(&grid, Named::ArrowRight, 9), Some(9));
+        assert_eq!(moved(&grid, Named::ArrowDown, 9), Some(9));
+        // Left and right go on in the next and previous row.
+        assert_eq!(moved(&grid, Named::ArrowRight, 2), Some(3));
+        assert_eq!(moved(&grid, Named::ArrowLeft, 3), Some(2));
+        assert_eq!(moved(&grid, Named::ArrowUp, 4), Some(1));
+        assert_eq!(moved(&grid, Named::ArrowDown, 4), Some(7));
+    }
+
+    #[test]
+    fn down_into_a_partial_last_row() {
+        let grid = grid(10);
+
+        assert_eq!(moved(&grid, Named::ArrowDown, 6), Some(9));
+        // Nothing is below 7 and 8, so they stay.
+        assert_eq!(moved(&grid, Named::ArrowDown, 7), Some(7));
+        assert_eq!(moved(&grid, Named::ArrowDown, 8), Some(8));
+        assert_eq!(moved(&grid, Named::PageDown, 1), Some(7));
+        assert_eq!(moved(&grid, Named::PageDown, 8), Some(9));
+        assert_eq!(moved(&grid, Named::PageUp, 8), Some(2));
+        assert_eq!(moved(&grid, Named::PageUp, 4), Some(1));
+
+        // Clicks next to the last icon hit nothing.
+        assert_eq!(grid.index_at(BOUNDS, Point::new(24.0, 168.0)), Some(9));
+        assert_eq!(grid.index_at(BOUNDS, Point::new(72.0, 168.0)), None);
+        assert_eq!(grid.visible(BOUNDS, &BOUNDS), 0..10);
+    }
+
+    #[test]
+    fn home_and_end_go_to_the_first_and_last_icon() {
+        let grid = grid(10);
+
+        assert_eq!(moved(&grid, Named::Home, 7), Some(0));
+        assert_eq!(moved(&grid, Named::End, 1), Some(9));
+        assert_eq!(moved(&grid, Named::End, 9), Some(9));
+        assert_eq!(moved(&grid, Named::Enter, 4), None);
+    }
+
+    #[test]
+    fn an_empty_grid_has_nowhere_to_move() {
+        let grid = grid(0);
+
+        for key in [Named::ArrowRight, Named::ArrowDown, Named::Home, Named::End] {
+            assert_eq!(moved(&grid, key, 0), None);
+        }
+    }
+}
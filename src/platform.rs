@@ -0,0 +1,103 @@
+//! Logos of operating systems, for download pages and launchers offering builds per platform.
+
+#[cfg(any(feature = "fontawesome", feature = "nerd", feature = "devicon"))]
+use iced_core::{Font, text};
+#[cfg(any(feature = "fontawesome", feature = "nerd", feature = "devicon"))]
+use iced_widget::text::{Catalog, Text};
+
+use crate::FontIcon;
+
+/// An operating system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Os {
+    /// Linux.
+    Linux,
+    /// Windows.
+    Windows,
+    /// macOS.
+    MacOs,
+    /// Android.
+    Android,
+    /// iOS.
+    Ios,
+}
+
+impl Os {
+    /// The operating system the program was built for, `None` for others like the BSDs or the
+    /// web.
+    #[must_use]
+    pub const fn current() -> Option<Self> {
+        if cfg!(target_os = "android") {
+            Some(Os::Android)
+        } else if cfg!(target_os = "linux") {
+            Some(Os::Linux)
+        } else if cfg!(target_os = "windows") {
+            Some(Os::Windows)
+        } else if cfg!(target_os = "macos") {
+            Some(Os::MacOs)
+        } else if cfg!(target_os = "ios") {
+            Some(Os::Ios)
+        } else {
+            None
+        }
+    }
+}
+
+/// Icons with a logo for every [`Os`]. Fonts missing one use the closest they have, like the Apple
+/// logo for iOS, when the `platforms` option generates `Icon::PLATFORMS`.
+pub trait PlatformIcons: FontIcon {
+    /// The logos of every [`Os`], in the order of its variants.
+    fn platform_icons() -> [Self; 5];
+
+    /// The logo of `os`.
+    fn platform(os: Os) -> Self {
+        Self::platform_icons()[os as usize]
+    }
+}
+
+macro_rules! platform_icons {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl PlatformIcons for crate::$module::Icon {
+                fn platform_icons() -> [Self; 5] {
+                    Self::PLATFORMS
+                }
+            }
+        )*
+    };
+}
+
+platform_icons! {
+    "devicon" devicon,
+    "fontawesome" fontawesome,
+    "nerd" nerd,
+}
+
+// The first enabled font with logos supplies all of them.
+#[cfg(feature = "fontawesome")]
+type Resolved = crate::fontawesome::Icon;
+#[cfg(all(feature = "nerd", not(feature = "fontawesome")))]
+type Resolved = crate::nerd::Icon;
+#[cfg(all(
+    feature = "devicon",
+    not(any(feature = "fontawesome", feature = "nerd"))
+))]
+type Resolved = crate::devicon::Icon;
+
+/// The logo of `os` from the first enabled font of Font Awesome, the Nerd Font and Devicon.
+#[cfg(any(feature = "fontawesome", feature = "nerd", feature = "devicon"))]
+#[must_use]
+pub fn platform_icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+    os: Os,
+) -> Text<'a, Theme, Renderer> {
+    Resolved::platform(os).text()
+}
+
+/// The logo of the operating system the program was built for, see [`Os::current`].
+#[cfg(any(feature = "fontawesome", feature = "nerd", feature = "devicon"))]
+#[must_use]
+pub fn current_platform_icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>()
+-> Option<Text<'a, Theme, Renderer>> {
+    Os::current().map(platform_icon)
+}
@@ -0,0 +1,28 @@
+//! Everything most apps use, in one import:
+//! ```ignore
+//! use iced_fonts::prelude::*;
+//! ```
+//! The modules and constants of the enabled fonts, `load_all` to load them, the `icon!` macro and
+//! the traits and types styling icons.
+
+pub use crate::{
+    ALL_FONT_BYTES, HasIcon, IconDefault, IconFont, IconSize, IconStyle, IconTheme, Role, Styled,
+    icon, load_all, styled, warm_up,
+};
+
+#[cfg(feature = "bootstrap")]
+pub use crate::{BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES, bootstrap};
+#[cfg(feature = "codicon")]
+pub use crate::{CODICON_FONT, CODICON_FONT_BYTES, codicon};
+#[cfg(feature = "devicon")]
+pub use crate::{DEVICON_FONT, DEVICON_FONT_BYTES, devicon};
+#[cfg(feature = "fontawesome")]
+pub use crate::{FONTAWESOME_FONT, FONTAWESOME_FONT_BYTES, fontawesome};
+#[cfg(feature = "lucide")]
+pub use crate::{LUCIDE_FONT, LUCIDE_FONT_BYTES, lucide};
+#[cfg(feature = "nerd")]
+pub use crate::{NERD_FONT, NERD_FONT_BYTES, nerd};
+#[cfg(feature = "octicons")]
+pub use crate::{OCTICONS_FONT, OCTICONS_FONT_BYTES, octicons};
+#[cfg(feature = "pomicons")]
+pub use crate::{POMICONS_FONT, POMICONS_FONT_BYTES, pomicons};
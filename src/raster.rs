@@ -0,0 +1,334 @@
+//! Drawing icons into pixels without iced's renderer, for window icons and tray icons.
+//!
+//! The glyph outline is scaled to fit a square, centered, and filled with antialiased coverage.
+
+use iced_core::Color;
+use iced_core::window::icon::{self, Icon};
+use ttf_parser::{Face, OutlineBuilder};
+
+use crate::FontIcon;
+
+/// An icon together with the font file it comes from, which drawing it without iced needs.
+pub trait Rasterize: FontIcon {
+    /// The font file the icon is drawn from.
+    fn font_bytes(self) -> &'static [u8];
+}
+
+macro_rules! rasterize {
+    ($($feature:literal $module:ident $bytes:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl Rasterize for crate::$module::Icon {
+                fn font_bytes(self) -> &'static [u8] {
+                    crate::$bytes
+                }
+            }
+        )*
+    };
+}
+
+rasterize! {
+    "bootstrap" bootstrap BOOTSTRAP_FONT_BYTES,
+    "codicon" codicon CODICON_FONT_BYTES,
+    "devicon" devicon DEVICON_FONT_BYTES,
+    "fontawesome" fontawesome FONTAWESOME_FONT_BYTES,
+    "lucide" lucide LUCIDE_FONT_BYTES,
+    "nerd" nerd NERD_FONT_BYTES,
+    "octicons" octicons OCTICONS_FONT_BYTES,
+    "pomicons" pomicons POMICONS_FONT_BYTES,
+}
+
+/// The icon drawn in `color` on a transparent square of `px` pixels, as the window icon of an
+/// application. An icon without an outline, like a space, is fully transparent.
+#[must_use]
+pub fn to_window_icon(icon: impl Rasterize, px: u32, color: Color) -> Icon {
+    let (rgba, width, height) = to_rgba(icon, px, color);
+
+    icon::from_rgba(rgba, width, height).expect("the pixels fill the icon")
+}
+
+/// The icon drawn in `color` on a transparent square of `px` pixels, as RGBA bytes, row by row and
+/// not premultiplied, with the width and height. This is what tray icon crates like `tray-icon`
+/// and `ksni` take, e.g. `tray_icon::Icon::from_rgba(rgba, width, height)`.
+#[must_use]
+pub fn to_rgba(icon: impl Rasterize, px: u32, color: Color) -> (Vec<u8>, u32, u32) {
+    let size = px as usize;
+    let coverage = Face::parse(icon.font_bytes(), 0)
+        .ok()
+        .and_then(|face| coverage(&face, icon.char(), size))
+        .unwrap_or_else(|| vec![0.0; size * size]);
+
+    let [r, g, b, a] = color.into_rgba8();
+    let rgba = coverage
+        .into_iter()
+        .flat_map(|coverage| [r, g, b, (f32::from(a) * coverage).round() as u8])
+        .collect();
+
+    (rgba, px, px)
+}
+
+/// How much of every pixel of a `size` by `size` square the glyph of `c` covers, row by row.
+fn coverage(face: &Face<'_>, c: char, size: usize) -> Option<Vec<f32>> {
+    let id = face.glyph_index(c)?;
+    let mut outline = Outline::default();
+    let bounds = face.outline_glyph(id, &mut outline)?;
+
+    // A pixel of padding keeps antialiasing from spilling over the edges.
+    let padding = 1.0;
+    let width = f32::from(bounds.width());
+    let height = f32::from(bounds.height());
+    let scale = (size as f32 - 2.0 * padding).max(0.0) / width.max(height).max(1.0);
+    let x_offset = padding + (size as f32 - 2.0 * padding - width * scale) / 2.0;
+    let y_offset = padding + (size as f32 - 2.0 * padding - height * scale) / 2.0;
+    // Fonts point y up, pixels point it down.
+    let transform = |(x, y): (f32, f32)| {
+        (
+            (x - f32::from(bounds.x_min)) * scale + x_offset,
+            (f32::from(bounds.y_max) - y) * scale + y_offset,
+        )
+    };
+
+    let mut canvas = Canvas::new(size);
+    for line in outline.lines {
+        canvas.line(transform(line.0), transform(line.1));
+    }
+
+    Some(canvas.coverage())
+}
+
+/// The glyph outline flattened into lines, in font units.
+#[derive(Default)]
+struct Outline {
+    lines: Vec<((f32, f32), (f32, f32))>,
+    start: (f32, f32),
+    current: (f32, f32),
+}
+
+impl Outline {
+    fn line(&mut self, to: (f32, f32)) {
+        self.lines.push((self.current, to));
+        self.current = to;
+    }
+
+    /// Flattens a curve through `point(t)` for `t` in 0 to 1 into lines, more for longer curves.
+    fn curve(&mut self, length: f32, point: impl Fn(f32) -> (f32, f32)) {
+        let segments = (length.sqrt() / 2.0).ceil().clamp(1.0, 32.0) as u32;
+        for i in 1..=segments {
+            self.line(point(i as f32 / segments as f32));
+        }
+    }
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+impl OutlineBuilder for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = (x, y);
+        self.current = (x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.line((x, y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.current;
+        let length = distance(p0, (x1, y1)) + distance((x1, y1), (x, y));
+        self.curve(length, |t| {
+            let u = 1.0 - t;
+            (
+                u * u * p0.0 + 2.0 * u * t * x1 + t * t * x,
+                u * u * p0.1 + 2.0 * u * t * y1 + t * t * y,
+            )
+        });
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = self.current;
+        let length =
+            distance(p0, (x1, y1)) + distance((x1, y1), (x2, y2)) + distance((x2, y2), (x, y));
+        self.curve(length, |t| {
+            let u = 1.0 - t;
+            (
+                u * u * u * p0.0 + 3.0 * u * u * t * x1 + 3.0 * u * t * t * x2 + t * t * t * x,
+                u * u * u * p0.1 + 3.0 * u * u * t * y1 + 3.0 * u * t * t * y2 + t * t * t * y,
+            )
+        });
+    }
+
+    fn close(&mut self) {
+        if self.current != self.start {
+            self.line(self.start);
+        }
+    }
+}
+
+/// Accumulates the signed area every line covers in each pixel, summing it up row by row gives
+/// the coverage with the nonzero fill rule.
+struct Canvas {
+    size: usize,
+    area: Vec<f32>,
+}
+
+impl Canvas {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            // Lines touching the right edge add to the pixel after it.
+            area: vec![0.0; size * size + 1],
+        }
+    }
+
+    fn line(&mut self, from: (f32, f32), to: (f32, f32)) {
+        if from.1 == to.1 {
+            return;
+        }
+        let (direction, from, to) = if from.1 < to.1 {
+            (1.0, from, to)
+        } else {
+            (-1.0, to, from)
+        };
+        let dx_dy = (to.0 - from.0) / (to.1 - from.1);
+
+        let first_row = from.1.max(0.0) as usize;
+        let last_row = (to.1.ceil().max(0.0) as usize).min(self.size);
+        for row in first_row..last_row {
+            let top = (row as f32).max(from.1);
+            let bottom = ((row + 1) as f32).min(to.1);
+            let height = (bottom - top) * direction;
+            let x_top = from.0 + (top - from.1) * dx_dy;
+            let x_bottom = from.0 + (bottom - from.1) * dx_dy;
+            let (left, right) = if x_top < x_bottom {
+                (x_top, x_bottom)
+            } else {
+                (x_bottom, x_top)
+            };
+            let left = left.clamp(0.0, self.size as f32);
+            let right = right.clamp(0.0, self.size as f32);
+            let start = row * self.size;
+
+            // Every pixel the line crosses is partly covered, the ones after it fully. A line on
+            // the right edge crosses the last pixel, so its area lands in the pixel after it.
+            let first = (left.floor() as usize).min(self.size - 1);
+            let last = (right.ceil() as usize).max(first + 1);
+            let mut previous = 0.0;
+            for x in first..last {
+                let covered = height * right_of(x as f32, left, right);
+                self.area[start + x] += covered - previous;
+                previous = covered;
+            }
+            self.area[start + last] += height - previous;
+        }
+    }
+
+    /// The coverage of every pixel, row by row.
+    fn coverage(self) -> Vec<f32> {
+        let mut sum = 0.0;
+        self.area[..self.size * self.size]
+            .iter()
+            .map(|area| {
+                sum += area;
+                f32::abs(sum).min(1.0)
+            })
+            .collect()
+    }
+}
+
+/// The part of the pixel column at `x` right of a line going from `left` to `right` within a
+/// row, averaged over the row.
+fn right_of(x: f32, left: f32, right: f32) -> f32 {
+    let width = right - left;
+    if width < 1e-6 {
+        return x + 1.0 - left.clamp(x, x + 1.0);
+    }
+
+    // Where the line is left of the pixel it's all covered, within the pixel a trapezoid is.
+    let before = (right.min(x) - left).max(0.0);
+    let (start, end) = (left.max(x), right.min(x + 1.0));
+    let within = if end > start {
+        (end - start) * (x + 1.0 - (start + end) / 2.0)
+    } else {
+        0.0
+    };
+
+    (before + within) / width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Canvas, Rasterize, to_rgba};
+    use crate::FontIcon;
+    use iced_core::{Color, Font, text};
+
+    /// Fills the square from `min` to `max` on a canvas of `size`, clockwise in pixels like the
+    /// outlines of fonts once their y axis is flipped.
+    fn square(size: usize, min: f32, max: f32) -> Vec<f32> {
+        let mut canvas = Canvas::new(size);
+        canvas.line((min, min), (max, min));
+        canvas.line((max, min), (max, max));
+        canvas.line((max, max), (min, max));
+        canvas.line((min, max), (min, min));
+        canvas.coverage()
+    }
+
+    #[test]
+    fn filled_square() {
+        let coverage = square(8, 2.0, 6.0);
+
+        for (i, coverage) in coverage.into_iter().enumerate() {
+            let (x, y) = (i % 8, i / 8);
+            let inside = (2..6).contains(&x) && (2..6).contains(&y);
+            assert_eq!(coverage, if inside { 1.0 } else { 0.0 }, "pixel {x}, {y}");
+        }
+    }
+
+    #[test]
+    fn glyph_touching_the_border() {
+        assert!(
+            square(8, 0.0, 8.0)
+                .into_iter()
+                .all(|coverage| coverage == 1.0)
+        );
+
+        // Half a pixel past every edge is clipped.
+        assert!(
+            square(8, -0.5, 8.5)
+                .into_iter()
+                .all(|coverage| coverage == 1.0)
+        );
+    }
+
+    #[derive(Clone, Copy)]
+    struct Space;
+
+    impl FontIcon for Space {
+        fn char(self) -> char {
+            ' '
+        }
+
+        fn font(self) -> Font {
+            Font::DEFAULT
+        }
+
+        fn shaping(self) -> text::Shaping {
+            text::Shaping::Basic
+        }
+    }
+
+    impl Rasterize for Space {
+        fn font_bytes(self) -> &'static [u8] {
+            include_bytes!("../fonts/bootstrap.ttf")
+        }
+    }
+
+    #[test]
+    fn empty_glyph() {
+        let (rgba, width, height) = to_rgba(Space, 16, Color::BLACK);
+
+        assert_eq!((width, height), (16, 16));
+        assert_eq!(rgba.len(), 16 * 16 * 4);
+        assert!(rgba.chunks(4).all(|pixel| pixel[3] == 0));
+    }
+}
@@ -0,0 +1,148 @@
+//! Looking an icon up by name in whichever enabled font has it.
+
+use iced_core::{Font, text};
+use iced_widget::text::{Catalog, Text};
+
+/// The bundled fonts, whether or not their feature is enabled, so orders of them compile with any
+/// features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconFont {
+    /// The `bootstrap` feature.
+    Bootstrap,
+    /// The `codicon` feature.
+    Codicon,
+    /// The `devicon` feature.
+    Devicon,
+    /// The `fontawesome` feature.
+    Fontawesome,
+    /// The `lucide` feature.
+    Lucide,
+    /// The `nerd` feature.
+    Nerd,
+    /// The `octicons` feature.
+    Octicons,
+    /// The `pomicons` feature.
+    Pomicons,
+}
+
+impl IconFont {
+    /// The order [`resolve`] tries the fonts in. General purpose sets come first, the Nerd Font,
+    /// which has a bit of everything under prefixed names, after them, and the fonts of logos and
+    /// single purposes last.
+    pub const DEFAULT_ORDER: [IconFont; 8] = [
+        IconFont::Lucide,
+        IconFont::Bootstrap,
+        IconFont::Fontawesome,
+        IconFont::Codicon,
+        IconFont::Octicons,
+        IconFont::Nerd,
+        IconFont::Devicon,
+        IconFont::Pomicons,
+    ];
+
+    /// Whether the feature of the font is enabled.
+    #[must_use]
+    pub const fn is_enabled(self) -> bool {
+        match self {
+            IconFont::Bootstrap => cfg!(feature = "bootstrap"),
+            IconFont::Codicon => cfg!(feature = "codicon"),
+            IconFont::Devicon => cfg!(feature = "devicon"),
+            IconFont::Fontawesome => cfg!(feature = "fontawesome"),
+            IconFont::Lucide => cfg!(feature = "lucide"),
+            IconFont::Nerd => cfg!(feature = "nerd"),
+            IconFont::Octicons => cfg!(feature = "octicons"),
+            IconFont::Pomicons => cfg!(feature = "pomicons"),
+        }
+    }
+
+    /// The shaping the icons of the font are drawn with, advanced for Devicons, Font Awesome and
+    /// Octicons.
+    #[must_use]
+    pub const fn shaping(self) -> text::Shaping {
+        match self {
+            IconFont::Devicon | IconFont::Fontawesome | IconFont::Octicons => {
+                text::Shaping::Advanced
+            }
+            IconFont::Bootstrap
+            | IconFont::Codicon
+            | IconFont::Lucide
+            | IconFont::Nerd
+            | IconFont::Pomicons => text::Shaping::Basic,
+        }
+    }
+
+    /// The character and font of the icon whose function is named `name`, `None` when the font
+    /// has none or isn't enabled. Draw it with the [`shaping`](Self::shaping) of the font.
+    #[must_use]
+    pub fn find(self, name: &str) -> Option<(char, Font)> {
+        macro_rules! find {
+            ($($font:ident $feature:literal $module:ident),* $(,)?) => {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        IconFont::$font => crate::$module::Icon::from_name(name)
+                            .map(|icon| (icon.char(), icon.font())),
+                    )*
+                    // Without fonts `name` is only used here.
+                    #[allow(unreachable_patterns)]
+                    _ => {
+                        let _ = name;
+                        None
+                    }
+                }
+            };
+        }
+
+        find! {
+            Bootstrap "bootstrap" bootstrap,
+            Codicon "codicon" codicon,
+            Devicon "devicon" devicon,
+            Fontawesome "fontawesome" fontawesome,
+            Lucide "lucide" lucide,
+            Nerd "nerd" nerd,
+            Octicons "octicons" octicons,
+            Pomicons "pomicons" pomicons,
+        }
+    }
+}
+
+/// The character and font of the icon whose function is named `name`, from the first enabled font
+/// of [`IconFont::DEFAULT_ORDER`] that has one. `resolve("trash")` finds a trash can in whichever
+/// fonts the app enabled.
+///
+/// Some of the fonts need advanced shaping, which the tuple doesn't say. [`resolve_text`] draws the
+/// icon with the shaping of the font that had it.
+#[must_use]
+pub fn resolve(name: &str) -> Option<(char, Font)> {
+    resolve_in(&IconFont::DEFAULT_ORDER, name)
+}
+
+/// Like [`resolve`], trying the fonts in `order`. Fonts left out of it aren't tried.
+///
+/// ```ignore
+/// resolve_in(&[IconFont::Nerd, IconFont::Bootstrap], "md_trash_can")
+/// ```
+#[must_use]
+pub fn resolve_in(order: &[IconFont], name: &str) -> Option<(char, Font)> {
+    order.iter().find_map(|font| font.find(name))
+}
+
+/// The widget of the icon [`resolve`] finds, drawn with the shaping of its font.
+///
+/// ```ignore
+/// resolve_text("trash").unwrap_or_else(|| text("Delete"))
+/// ```
+#[must_use]
+pub fn resolve_text<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+    name: &str,
+) -> Option<Text<'a, Theme, Renderer>> {
+    IconFont::DEFAULT_ORDER.into_iter().find_map(|font| {
+        let (c, icon_font) = font.find(name)?;
+
+        Some(
+            Text::new(c.to_string())
+                .font(icon_font)
+                .shaping(font.shaping()),
+        )
+    })
+}
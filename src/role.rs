@@ -0,0 +1,441 @@
+//! Common actions and states of user interfaces, and the icons of every font playing them.
+
+use crate::ThemeIcons;
+
+/// A common action or state apps show an icon for.
+///
+/// The bundled fonts and fonts with the `roles` option know their icon for each role, which is
+/// how [`IconTheme`](crate::IconTheme)s switch fonts and how an icon is found in another font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// Closing a window, tab or dialog.
+    Close,
+    /// Saving a document.
+    Save,
+    /// Searching.
+    Search,
+    /// A warning.
+    Warning,
+    /// An error.
+    Error,
+    /// Information.
+    Info,
+    /// The settings.
+    Settings,
+    /// The start page.
+    Home,
+    /// Adding an item.
+    Add,
+    /// Removing an item.
+    Remove,
+    /// Editing.
+    Edit,
+    /// Deleting for good.
+    Delete,
+    /// Copying.
+    Copy,
+    /// Reloading.
+    Refresh,
+    /// A menu.
+    Menu,
+    /// A folder.
+    Folder,
+    /// A file.
+    File,
+    /// Downloading.
+    Download,
+    /// Uploading.
+    Upload,
+    /// A user or account.
+    User,
+    /// Help.
+    Help,
+    /// Confirming, or something done.
+    Check,
+    /// Going back.
+    Back,
+    /// Going forward.
+    Forward,
+    /// Moving up.
+    Up,
+    /// Moving down.
+    Down,
+    /// Pointing left, for collapsing sidebars and pagers.
+    ChevronLeft,
+    /// Pointing right.
+    ChevronRight,
+    /// Pointing up.
+    ChevronUp,
+    /// Pointing down, for dropdowns.
+    ChevronDown,
+    /// More actions, in a row of dots.
+    More,
+    /// More actions, in a column of dots.
+    MoreVertical,
+    /// Cutting.
+    Cut,
+    /// Pasting.
+    Paste,
+    /// Undoing.
+    Undo,
+    /// Redoing.
+    Redo,
+    /// Sharing.
+    Share,
+    /// Printing.
+    Print,
+    /// Filtering.
+    Filter,
+    /// Sorting.
+    Sort,
+    /// A link.
+    Link,
+    /// Opening outside the app.
+    ExternalLink,
+    /// Attaching a file.
+    Attach,
+    /// Sending a message.
+    Send,
+    /// Replying.
+    Reply,
+    /// Zooming in.
+    ZoomIn,
+    /// Zooming out.
+    ZoomOut,
+    /// Maximizing, or going full screen.
+    Maximize,
+    /// Minimizing, or leaving full screen.
+    Minimize,
+    /// Something locked.
+    Lock,
+    /// Something unlocked.
+    Unlock,
+    /// Signing in.
+    Login,
+    /// Signing out.
+    Logout,
+    /// Playing media.
+    Play,
+    /// Pausing media.
+    Pause,
+    /// Stopping media.
+    Stop,
+    /// Skipping to the next track.
+    SkipNext,
+    /// Skipping to the previous track.
+    SkipPrevious,
+    /// The volume.
+    Volume,
+    /// Muted sound.
+    Mute,
+    /// An open folder.
+    FolderOpen,
+    /// An image.
+    Image,
+    /// A video.
+    Video,
+    /// Music.
+    Music,
+    /// A calendar or date.
+    Calendar,
+    /// A clock or time.
+    Clock,
+    /// A group of users.
+    Users,
+    /// Email.
+    Mail,
+    /// A phone call.
+    Phone,
+    /// A chat or comment.
+    Chat,
+    /// A bookmark.
+    Bookmark,
+    /// A favorite or rating.
+    Star,
+    /// A like.
+    Heart,
+    /// A tag or label.
+    Tag,
+    /// A shopping cart.
+    Cart,
+    /// The web, or a language.
+    Globe,
+    /// A place on a map.
+    Location,
+    /// A camera or photo.
+    Camera,
+    /// A microphone.
+    Microphone,
+    /// The cloud.
+    Cloud,
+    /// A database.
+    Database,
+    /// Source code.
+    Code,
+    /// A terminal.
+    Terminal,
+    /// A key or password.
+    Key,
+    /// Security.
+    Shield,
+    /// Showing something hidden.
+    Show,
+    /// Hiding something.
+    Hide,
+    /// Notifications.
+    Notification,
+    /// Archiving.
+    Archive,
+    /// An inbox.
+    Inbox,
+    /// Flagging or reporting.
+    Flag,
+    /// The history.
+    History,
+    /// A grid view.
+    Grid,
+    /// A list view.
+    List,
+    /// The light color mode.
+    LightMode,
+    /// The dark color mode.
+    DarkMode,
+    /// A wireless network.
+    Wifi,
+    /// Turning off.
+    Power,
+    /// A bug.
+    Bug,
+    /// A keyboard or shortcuts.
+    Keyboard,
+}
+
+impl Role {
+    /// Every role, in the order of the `roles` option.
+    pub const ALL: [Role; 100] = [
+        Role::Close,
+        Role::Save,
+        Role::Search,
+        Role::Warning,
+        Role::Error,
+        Role::Info,
+        Role::Settings,
+        Role::Home,
+        Role::Add,
+        Role::Remove,
+        Role::Edit,
+        Role::Delete,
+        Role::Copy,
+        Role::Refresh,
+        Role::Menu,
+        Role::Folder,
+        Role::File,
+        Role::Download,
+        Role::Upload,
+        Role::User,
+        Role::Help,
+        Role::Check,
+        Role::Back,
+        Role::Forward,
+        Role::Up,
+        Role::Down,
+        Role::ChevronLeft,
+        Role::ChevronRight,
+        Role::ChevronUp,
+        Role::ChevronDown,
+        Role::More,
+        Role::MoreVertical,
+        Role::Cut,
+        Role::Paste,
+        Role::Undo,
+        Role::Redo,
+        Role::Share,
+        Role::Print,
+        Role::Filter,
+        Role::Sort,
+        Role::Link,
+        Role::ExternalLink,
+        Role::Attach,
+        Role::Send,
+        Role::Reply,
+        Role::ZoomIn,
+        Role::ZoomOut,
+        Role::Maximize,
+        Role::Minimize,
+        Role::Lock,
+        Role::Unlock,
+        Role::Login,
+        Role::Logout,
+        Role::Play,
+        Role::Pause,
+        Role::Stop,
+        Role::SkipNext,
+        Role::SkipPrevious,
+        Role::Volume,
+        Role::Mute,
+        Role::FolderOpen,
+        Role::Image,
+        Role::Video,
+        Role::Music,
+        Role::Calendar,
+        Role::Clock,
+        Role::Users,
+        Role::Mail,
+        Role::Phone,
+        Role::Chat,
+        Role::Bookmark,
+        Role::Star,
+        Role::Heart,
+        Role::Tag,
+        Role::Cart,
+        Role::Globe,
+        Role::Location,
+        Role::Camera,
+        Role::Microphone,
+        Role::Cloud,
+        Role::Database,
+        Role::Code,
+        Role::Terminal,
+        Role::Key,
+        Role::Shield,
+        Role::Show,
+        Role::Hide,
+        Role::Notification,
+        Role::Archive,
+        Role::Inbox,
+        Role::Flag,
+        Role::History,
+        Role::Grid,
+        Role::List,
+        Role::LightMode,
+        Role::DarkMode,
+        Role::Wifi,
+        Role::Power,
+        Role::Bug,
+        Role::Keyboard,
+    ];
+
+    /// The name of the role in the `roles` option, like `chevron_left` for
+    /// [`Role::ChevronLeft`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Role::Close => "close",
+            Role::Save => "save",
+            Role::Search => "search",
+            Role::Warning => "warning",
+            Role::Error => "error",
+            Role::Info => "info",
+            Role::Settings => "settings",
+            Role::Home => "home",
+            Role::Add => "add",
+            Role::Remove => "remove",
+            Role::Edit => "edit",
+            Role::Delete => "delete",
+            Role::Copy => "copy",
+            Role::Refresh => "refresh",
+            Role::Menu => "menu",
+            Role::Folder => "folder",
+            Role::File => "file",
+            Role::Download => "download",
+            Role::Upload => "upload",
+            Role::User => "user",
+            Role::Help => "help",
+            Role::Check => "check",
+            Role::Back => "back",
+            Role::Forward => "forward",
+            Role::Up => "up",
+            Role::Down => "down",
+            Role::ChevronLeft => "chevron_left",
+            Role::ChevronRight => "chevron_right",
+            Role::ChevronUp => "chevron_up",
+            Role::ChevronDown => "chevron_down",
+            Role::More => "more",
+            Role::MoreVertical => "more_vertical",
+            Role::Cut => "cut",
+            Role::Paste => "paste",
+            Role::Undo => "undo",
+            Role::Redo => "redo",
+            Role::Share => "share",
+            Role::Print => "print",
+            Role::Filter => "filter",
+            Role::Sort => "sort",
+            Role::Link => "link",
+            Role::ExternalLink => "external_link",
+            Role::Attach => "attach",
+            Role::Send => "send",
+            Role::Reply => "reply",
+            Role::ZoomIn => "zoom_in",
+            Role::ZoomOut => "zoom_out",
+            Role::Maximize => "maximize",
+            Role::Minimize => "minimize",
+            Role::Lock => "lock",
+            Role::Unlock => "unlock",
+            Role::Login => "login",
+            Role::Logout => "logout",
+            Role::Play => "play",
+            Role::Pause => "pause",
+            Role::Stop => "stop",
+            Role::SkipNext => "skip_next",
+            Role::SkipPrevious => "skip_previous",
+            Role::Volume => "volume",
+            Role::Mute => "mute",
+            Role::FolderOpen => "folder_open",
+            Role::Image => "image",
+            Role::Video => "video",
+            Role::Music => "music",
+            Role::Calendar => "calendar",
+            Role::Clock => "clock",
+            Role::Users => "users",
+            Role::Mail => "mail",
+            Role::Phone => "phone",
+            Role::Chat => "chat",
+            Role::Bookmark => "bookmark",
+            Role::Star => "star",
+            Role::Heart => "heart",
+            Role::Tag => "tag",
+            Role::Cart => "cart",
+            Role::Globe => "globe",
+            Role::Location => "location",
+            Role::Camera => "camera",
+            Role::Microphone => "microphone",
+            Role::Cloud => "cloud",
+            Role::Database => "database",
+            Role::Code => "code",
+            Role::Terminal => "terminal",
+            Role::Key => "key",
+            Role::Shield => "shield",
+            Role::Show => "show",
+            Role::Hide => "hide",
+            Role::Notification => "notification",
+            Role::Archive => "archive",
+            Role::Inbox => "inbox",
+            Role::Flag => "flag",
+            Role::History => "history",
+            Role::Grid => "grid",
+            Role::List => "list",
+            Role::LightMode => "light_mode",
+            Role::DarkMode => "dark_mode",
+            Role::Wifi => "wifi",
+            Role::Power => "power",
+            Role::Bug => "bug",
+            Role::Keyboard => "keyboard",
+        }
+    }
+
+    /// The role named `name` in the `roles` option, `None` for an unknown name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|role| role.name() == name)
+    }
+
+    /// The icon of the role in the font of `I`, `None` when the font has none for it.
+    ///
+    /// ```ignore
+    /// Role::Save.icon_in::<lucide::Icon>()
+    /// ```
+    #[must_use]
+    pub fn icon_in<I: ThemeIcons>(self) -> Option<I> {
+        I::role_icons()[self as usize]
+    }
+}
@@ -0,0 +1,77 @@
+//! The icons of checkboxes and radio buttons, the same states in every font.
+
+use crate::FontIcon;
+
+/// The state of a checkbox or radio button in a list or table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Selection {
+    /// A checked checkbox.
+    Checked,
+    /// An unchecked checkbox.
+    Unchecked,
+    /// A checkbox of a group that is partly checked.
+    Indeterminate,
+    /// A selected radio button.
+    RadioOn,
+    /// An unselected radio button.
+    RadioOff,
+}
+
+impl Selection {
+    /// The checkbox of `checked`, `None` for partly checked groups.
+    #[must_use]
+    pub const fn checkbox(checked: Option<bool>) -> Self {
+        match checked {
+            Some(true) => Selection::Checked,
+            Some(false) => Selection::Unchecked,
+            None => Selection::Indeterminate,
+        }
+    }
+
+    /// The radio button of `selected`.
+    #[must_use]
+    pub const fn radio(selected: bool) -> Self {
+        if selected {
+            Selection::RadioOn
+        } else {
+            Selection::RadioOff
+        }
+    }
+}
+
+/// Icons with a checkbox or radio button for every [`Selection`], from one font so they match. The
+/// `selection` option names them and generates `Icon::SELECTION`.
+///
+/// ```ignore
+/// lucide::Icon::selection(Selection::checkbox(Some(done))).text()
+/// ```
+pub trait Selectable: FontIcon {
+    /// The icons of [`Selection::Checked`], `Unchecked`, `Indeterminate`, `RadioOn` and
+    /// `RadioOff`, in that order.
+    fn selection_icons() -> [Self; 5];
+
+    /// The icon of `selection`.
+    fn selection(selection: Selection) -> Self {
+        Self::selection_icons()[selection as usize]
+    }
+}
+
+macro_rules! selectable {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl Selectable for crate::$module::Icon {
+                fn selection_icons() -> [Self; 5] {
+                    Self::SELECTION
+                }
+            }
+        )*
+    };
+}
+
+selectable! {
+    "bootstrap" bootstrap,
+    "fontawesome" fontawesome,
+    "lucide" lucide,
+    "nerd" nerd,
+}
@@ -0,0 +1,41 @@
+//! Icon sizes of a design system, so icons line up with the text next to them.
+
+use iced_core::Pixels;
+
+/// The size of an icon by what it goes with, `Icon::sized` draws it at that size, raised to the
+/// smallest size the icon's font renders well at.
+///
+/// ```ignore
+/// bootstrap::Icon::House.sized(IconSize::Title)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IconSize {
+    /// 16 pixels, for captions and dense lists.
+    Caption,
+    /// 20 pixels, next to body text and in buttons.
+    #[default]
+    Body,
+    /// 24 pixels, for headings and toolbars.
+    Title,
+    /// 32 pixels, for empty states and other large displays.
+    Display,
+}
+
+impl IconSize {
+    /// The size in pixels.
+    #[must_use]
+    pub const fn pixels(self) -> f32 {
+        match self {
+            IconSize::Caption => 16.0,
+            IconSize::Body => 20.0,
+            IconSize::Title => 24.0,
+            IconSize::Display => 32.0,
+        }
+    }
+}
+
+impl From<IconSize> for Pixels {
+    fn from(size: IconSize) -> Self {
+        Pixels(size.pixels())
+    }
+}
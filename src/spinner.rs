@@ -0,0 +1,188 @@
+//! A loading indicator cycling through the frames of a spinner, like `nerd::spinner_frames()`.
+//!
+//! Every frame is a glyph of an icon font, so spinning is just drawing other text. It looks at
+//! home in monospace UIs and needs no rotation or canvas.
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text::{self, Text};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::tree::{self, Tree};
+use iced_core::window;
+use iced_core::{
+    Clipboard, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shell, Size, Widget,
+    alignment,
+};
+
+/// Creates a [`Spinner`] cycling through `frames` drawn with `font`.
+///
+/// ```ignore
+/// spinner(nerd::spinner_frames(), NERD_FONT)
+/// ```
+pub fn spinner(frames: &'static [char], font: Font) -> Spinner {
+    Spinner::new(frames, font)
+}
+
+/// A widget showing the frames of a spinner one after another, redrawing itself on a timer.
+#[derive(Debug, Clone, Copy)]
+pub struct Spinner {
+    frames: &'static [char],
+    font: Font,
+    shaping: text::Shaping,
+    size: f32,
+    interval: Duration,
+    color: Option<Color>,
+}
+
+impl Spinner {
+    /// Creates a [`Spinner`] cycling through `frames` drawn with `font`, at 16 pixels and ten
+    /// frames a second.
+    pub fn new(frames: &'static [char], font: Font) -> Self {
+        Self {
+            frames,
+            font,
+            shaping: text::Shaping::Basic,
+            size: 16.0,
+            interval: Duration::from_millis(100),
+            color: None,
+        }
+    }
+
+    /// Sets the size of the spinner, which takes a square of it.
+    #[must_use]
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the shaping of the frames, `Icon::shaping()` of their module, basic by default.
+    #[must_use]
+    pub fn shaping(mut self, shaping: text::Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Sets how long every frame is shown, 100 milliseconds by default.
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Sets the color of the spinner, the text color of the parent widget by default.
+    #[must_use]
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// When the spinner was first drawn and the frame it shows.
+#[derive(Debug, Default)]
+struct State {
+    started: Option<Instant>,
+    frame: usize,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Spinner
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(Size::new(self.size, self.size))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+        if self.frames.len() < 2 {
+            return;
+        }
+
+        // Frames follow the time since the first one, so slow redraws skip frames instead of
+        // slowing the spinner down.
+        let state = tree.state.downcast_mut::<State>();
+        let started = *state.started.get_or_insert(*now);
+        let elapsed = (*now - started).as_nanos();
+        let interval = self.interval.as_nanos();
+        state.frame = (elapsed / interval % self.frames.len() as u128) as usize;
+
+        let until_next = interval - elapsed % interval;
+        shell.request_redraw_at(*now + Duration::from_nanos(until_next as u64));
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(&frame) = self
+            .frames
+            .get(tree.state.downcast_ref::<State>().frame)
+            .or(self.frames.first())
+        else {
+            return;
+        };
+        let bounds = layout.bounds();
+
+        renderer.fill_text(
+            Text {
+                content: frame.to_string(),
+                bounds: bounds.size(),
+                size: Pixels(self.size),
+                line_height: text::LineHeight::Relative(1.0),
+                font: self.font,
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                shaping: self.shaping,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(bounds.center_x(), bounds.center_y()),
+            self.color.unwrap_or(style.text_color),
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spinner> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(spinner: Spinner) -> Self {
+        Element::new(spinner)
+    }
+}
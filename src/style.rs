@@ -0,0 +1,82 @@
+//! Switching icons between their filled and outlined versions, e.g. for the selected tab.
+
+/// Whether icons are drawn filled or outlined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IconStyle {
+    /// Filled icons, like `house_fill`.
+    Fill,
+    /// Outlined icons, like `house`.
+    #[default]
+    Outline,
+}
+
+impl IconStyle {
+    /// The style of a tab, toggle or menu entry, filled when `selected`.
+    #[must_use]
+    pub const fn selected(selected: bool) -> Self {
+        if selected {
+            IconStyle::Fill
+        } else {
+            IconStyle::Outline
+        }
+    }
+}
+
+/// Icons with filled and outlined versions, paired by name like `house` and `house_fill` or
+/// `home` and `home_outline`.
+///
+/// Every generated `Icon` has the `fill` and `outline` methods, the icons of the bundled fonts
+/// implement it with them. For a font of your own, implement it the same way.
+///
+/// ```ignore
+/// bootstrap::Icon::House.styled(IconStyle::selected(active)).text()
+/// ```
+pub trait Styled: Copy {
+    /// The filled version of the icon, the icon itself without one.
+    fn fill(self) -> Self;
+
+    /// The outlined version of the icon, the icon itself without one.
+    fn outline(self) -> Self;
+
+    /// The version of the icon in `style`.
+    fn styled(self, style: IconStyle) -> Self {
+        match style {
+            IconStyle::Fill => self.fill(),
+            IconStyle::Outline => self.outline(),
+        }
+    }
+}
+
+/// The version of `icon` in `style`, so a style kept in the app's state switches every icon
+/// drawn with it at once.
+pub fn styled<I: Styled>(icon: I, style: IconStyle) -> I {
+    icon.styled(style)
+}
+
+macro_rules! styled {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl Styled for crate::$module::Icon {
+                fn fill(self) -> Self {
+                    self.fill()
+                }
+
+                fn outline(self) -> Self {
+                    self.outline()
+                }
+            }
+        )*
+    };
+}
+
+styled! {
+    "bootstrap" bootstrap,
+    "codicon" codicon,
+    "devicon" devicon,
+    "fontawesome" fontawesome,
+    "lucide" lucide,
+    "nerd" nerd,
+    "octicons" octicons,
+    "pomicons" pomicons,
+}
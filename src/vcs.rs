@@ -0,0 +1,76 @@
+//! Icons of file statuses in git and other version control, for the file lists of git clients.
+//!
+//! `vcs::status_icon(GitStatus::Modified)` draws the icon of the first of Octicons, Codicons and
+//! the Nerd Font that is enabled, the same icons GitHub and VS Code show for those statuses.
+
+#[cfg(any(feature = "octicons", feature = "codicon", feature = "nerd"))]
+use iced_core::{Font, text};
+#[cfg(any(feature = "octicons", feature = "codicon", feature = "nerd"))]
+use iced_widget::text::{Catalog, Text};
+
+use crate::FontIcon;
+
+/// The status of a file in the working tree or index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GitStatus {
+    /// The contents changed.
+    Modified,
+    /// The file is new.
+    Added,
+    /// The file was removed.
+    Deleted,
+    /// The file was moved or renamed.
+    Renamed,
+    /// A merge left conflicts in the file.
+    Conflicted,
+    /// The file isn't tracked.
+    Untracked,
+}
+
+/// Icons with the status of files under version control, one for every [`GitStatus`], which the
+/// `vcs` option generates as `Icon::VCS`.
+pub trait VcsIcons: FontIcon {
+    /// The icons of every [`GitStatus`], in the order of its variants.
+    fn vcs_icons() -> [Self; 6];
+
+    /// The icon of `status`.
+    fn status(status: GitStatus) -> Self {
+        Self::vcs_icons()[status as usize]
+    }
+}
+
+macro_rules! vcs_icons {
+    ($($feature:literal $module:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl VcsIcons for crate::$module::Icon {
+                fn vcs_icons() -> [Self; 6] {
+                    Self::VCS
+                }
+            }
+        )*
+    };
+}
+
+vcs_icons! {
+    "codicon" codicon,
+    "nerd" nerd,
+    "octicons" octicons,
+}
+
+// The first enabled font with statuses supplies all of them.
+#[cfg(feature = "octicons")]
+type Resolved = crate::octicons::Icon;
+#[cfg(all(feature = "codicon", not(feature = "octicons")))]
+type Resolved = crate::codicon::Icon;
+#[cfg(all(feature = "nerd", not(any(feature = "octicons", feature = "codicon"))))]
+type Resolved = crate::nerd::Icon;
+
+/// The icon of `status` from the first enabled font with version control statuses.
+#[cfg(any(feature = "octicons", feature = "codicon", feature = "nerd"))]
+#[must_use]
+pub fn status_icon<'a, Theme: Catalog + 'a, Renderer: text::Renderer<Font = Font>>(
+    status: GitStatus,
+) -> Text<'a, Theme, Renderer> {
+    Resolved::status(status).text()
+}
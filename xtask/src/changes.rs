@@ -0,0 +1,161 @@
+//! What changed between two versions of the snapshots in `snapshots/`, for the release notes and
+//! the files in `changes/` the bundled modules turn into their `CHANGES` constant.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The icons of a snapshot, codepoints in hex and the names of their functions.
+pub type Icons = BTreeMap<String, String>;
+
+/// How an icon changed, with codepoints in hex like in the snapshots.
+pub enum Change<'a> {
+    Added {
+        codepoint: &'a str,
+        name: &'a str,
+    },
+    Removed {
+        codepoint: &'a str,
+        name: &'a str,
+    },
+    Renamed {
+        codepoint: &'a str,
+        from: &'a str,
+        to: &'a str,
+    },
+    Moved {
+        name: &'a str,
+        from: &'a str,
+        to: &'a str,
+    },
+}
+
+/// The icons of a snapshot file's contents.
+pub fn parse(contents: &str) -> Icons {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (codepoint, name) = line.split_once('\t')?;
+            Some((codepoint.to_string(), name.to_string()))
+        })
+        .collect()
+}
+
+/// The icons of every snapshot in `dir`, by snapshot file name.
+pub fn snapshots(dir: &Path) -> BTreeMap<String, Icons> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return BTreeMap::new();
+    };
+
+    entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let contents = std::fs::read_to_string(&path).ok()?;
+            Some((
+                path.file_stem()?.to_string_lossy().into_owned(),
+                parse(&contents),
+            ))
+        })
+        .collect()
+}
+
+/// How the icons changed from `before` to `after`. Names are unique within a snapshot, so a name
+/// at another codepoint is the same icon moved, and a new name at the codepoint of a name that is
+/// gone is the icon renamed.
+pub fn diff<'a>(before: &'a Icons, after: &'a Icons) -> Vec<Change<'a>> {
+    let by_name = |icons: &'a Icons| -> BTreeMap<&'a str, &'a str> {
+        icons
+            .iter()
+            .map(|(codepoint, name)| (name.as_str(), codepoint.as_str()))
+            .collect()
+    };
+    let (old_names, new_names) = (by_name(before), by_name(after));
+    let mut changes = Vec::new();
+
+    for (codepoint, name) in after {
+        match old_names.get(name.as_str()) {
+            Some(old) if old == codepoint => {}
+            Some(old) => changes.push(Change::Moved {
+                name,
+                from: old,
+                to: codepoint,
+            }),
+            None => match before.get(codepoint) {
+                Some(old) if !new_names.contains_key(old.as_str()) => {
+                    changes.push(Change::Renamed {
+                        codepoint,
+                        from: old,
+                        to: name,
+                    });
+                }
+                _ => changes.push(Change::Added { codepoint, name }),
+            },
+        }
+    }
+    for (codepoint, name) in before {
+        let renamed = after
+            .get(codepoint)
+            .is_some_and(|new| !old_names.contains_key(new.as_str()));
+        if !new_names.contains_key(name.as_str()) && !renamed {
+            changes.push(Change::Removed { codepoint, name });
+        }
+    }
+
+    changes
+}
+
+/// The changes of every font as Markdown, for the release notes.
+pub fn changelog(before: &BTreeMap<String, Icons>, after: &BTreeMap<String, Icons>) -> String {
+    let empty = Icons::new();
+    let mut changelog = String::new();
+
+    for (font, icons) in after {
+        let lines: Vec<String> = diff(before.get(font).unwrap_or(&empty), icons)
+            .into_iter()
+            .map(|change| match change {
+                Change::Added { codepoint, name } => format!("- Added `{name}` (U+{codepoint})"),
+                Change::Removed { codepoint, name } => {
+                    format!("- Removed `{name}` (U+{codepoint})")
+                }
+                Change::Renamed {
+                    codepoint,
+                    from,
+                    to,
+                } => format!(
+                    "- Renamed `{from}` to `{to}` (U+{codepoint}), add `{from} = \"{to}\"` to \
+                     `deprecated/{font}.toml`"
+                ),
+                Change::Moved { name, from, to } => {
+                    format!("- Moved `{name}` from U+{from} to U+{to}")
+                }
+            })
+            .collect();
+
+        if !lines.is_empty() {
+            changelog.push_str(&format!("### {font}\n{}\n\n", lines.join("\n")));
+        }
+    }
+
+    changelog
+}
+
+/// The contents of the `changes/` file of `changes`, in the format of the `changes` option.
+pub fn file(changes: &[Change<'_>]) -> String {
+    let mut file = String::from(
+        "# How the icons changed with the last update of the font, written by `cargo xtask changes`.\n",
+    );
+    for change in changes {
+        let line = match change {
+            Change::Added { codepoint, name } => format!("added\t{codepoint}\t{name}"),
+            Change::Removed { codepoint, name } => format!("removed\t{codepoint}\t{name}"),
+            Change::Renamed {
+                codepoint,
+                from,
+                to,
+            } => format!("renamed\t{codepoint}\t{from}\t{to}"),
+            Change::Moved { name, from, to } => format!("moved\t{name}\t{from}\t{to}"),
+        };
+        file.push_str(&line);
+        file.push('\n');
+    }
+    file
+}
@@ -0,0 +1,149 @@
+//! Downloading the pinned upstream releases of the fonts listed in `fonts/sources.txt`.
+//!
+//! Downloads go through `curl`, archives are unpacked with `unzip` or `tar`. A download has to match
+//! its pinned SHA-256, new pins are only written with `--pin` after looking at what was fetched.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::sha256;
+
+/// A line of `fonts/sources.txt`.
+pub struct Source {
+    /// The font in `fonts/`.
+    pub font: String,
+    pub url: String,
+    /// The font's path in the downloaded archive, `None` when the download is the font.
+    pub member: Option<String>,
+    pub sha256: Option<String>,
+}
+
+/// Reads `fonts/sources.txt`. Fonts whose URL is `-` aren't fetched and are left out.
+pub fn sources(contents: &str) -> Result<Vec<Source>, String> {
+    let mut sources = Vec::new();
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [font, url, member, sha256] = fields[..] else {
+            return Err(format!(
+                "sources.txt line {}: expected `font url member sha256`",
+                number + 1
+            ));
+        };
+        if url == "-" {
+            continue;
+        }
+
+        let optional = |field: &str| (field != "-").then(|| field.to_string());
+        sources.push(Source {
+            font: font.to_string(),
+            url: url.to_string(),
+            member: optional(member),
+            sha256: optional(sha256),
+        });
+    }
+
+    Ok(sources)
+}
+
+/// Downloads `source` and returns the font, or an error when the download doesn't match the pin.
+/// Unpinned downloads are only accepted with `pin`, then the new checksum is returned too.
+pub fn fetch(source: &Source, pin: bool) -> Result<(Vec<u8>, Option<String>), String> {
+    let download = std::env::temp_dir().join(format!(
+        "iced_fonts-{}-{}",
+        std::process::id(),
+        source.url.rsplit('/').next().unwrap_or("download")
+    ));
+
+    run(Command::new("curl")
+        .args([
+            "--fail",
+            "--silent",
+            "--show-error",
+            "--location",
+            "--output",
+        ])
+        .arg(&download)
+        .arg(&source.url))?;
+
+    let data = std::fs::read(&download).map_err(|e| format!("{}: {e}", download.display()))?;
+    let checksum = sha256::hex(&data);
+    let new_pin = match &source.sha256 {
+        Some(pinned) if *pinned == checksum => None,
+        Some(pinned) => {
+            let _ = std::fs::remove_file(&download);
+            return Err(format!(
+                "{}: the download's SHA-256 is {checksum}, but {pinned} is pinned",
+                source.url
+            ));
+        }
+        None if pin => Some(checksum),
+        None => {
+            let _ = std::fs::remove_file(&download);
+            return Err(format!(
+                "{}: no SHA-256 pinned, the download's is {checksum}. Check the release and run \
+                 `cargo xtask fetch --pin {}` to pin it",
+                source.url, source.font
+            ));
+        }
+    };
+
+    let font = match &source.member {
+        None => Ok(data),
+        Some(member) => extract(&download, &source.url, member),
+    };
+    let _ = std::fs::remove_file(&download);
+
+    Ok((font?, new_pin))
+}
+
+fn extract(archive: &Path, url: &str, member: &str) -> Result<Vec<u8>, String> {
+    let mut command = if url.ends_with(".zip") {
+        let mut command = Command::new("unzip");
+        command.arg("-p").arg(archive).arg(member);
+        command
+    } else {
+        let mut command = Command::new("tar");
+        command.arg("-xOf").arg(archive).arg(member);
+        command
+    };
+
+    run(&mut command)
+}
+
+/// Runs `command` and returns its output, with its errors when it fails.
+fn run(command: &mut Command) -> Result<Vec<u8>, String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let output = command
+        .output()
+        .map_err(|e| format!("failed to run `{program}`: {e}"))?;
+
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(format!(
+            "`{program}` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Writes `new_pin` as the checksum of `font` into the contents of `fonts/sources.txt`.
+pub fn pin(contents: &str, font: &str, new_pin: &str) -> String {
+    contents
+        .lines()
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                [name, url, member, _] if name == font && !line.starts_with('#') => {
+                    format!("{name} {url} {member} {new_pin}\n")
+                }
+                _ => format!("{line}\n"),
+            }
+        })
+        .collect()
+}
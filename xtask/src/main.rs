@@ -0,0 +1,232 @@
+//! Maintenance tasks for this repository, run them with `cargo xtask <task>`.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+mod changes;
+mod fetch;
+mod sfnt;
+mod sha256;
+mod strip;
+
+const USAGE: &str = "\
+Usage: cargo xtask <task>
+
+Tasks:
+    strip [--dry-run] [FONT...]    Remove tables iced doesn't need from the vendored fonts.
+                                   Defaults to every font in `fonts/`.
+    fetch [--pin] [FONT...]        Download the pinned releases listed in `fonts/sources.txt`,
+                                   strip them, update the snapshots and print the changed icons.
+                                   `--pin` accepts and pins downloads without a checksum.
+    changes [--since REV] [FONT...]
+                                   Compare the snapshots with the ones of REV, the latest tag by
+                                   default, and write the changes to `changes/` for the `CHANGES`
+                                   constants of the modules.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.split_first() {
+        Some((task, args)) if task == "strip" => strip_fonts(args),
+        Some((task, args)) if task == "fetch" => fetch_fonts(args),
+        Some((task, args)) if task == "changes" => write_changes(args),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn strip_fonts(args: &[String]) -> Result<(), String> {
+    let dry_run = args.iter().any(|arg| arg == "--dry-run");
+    let mut fonts: Vec<PathBuf> = args
+        .iter()
+        .filter(|arg| !arg.starts_with("--"))
+        .map(PathBuf::from)
+        .collect();
+
+    if fonts.is_empty() {
+        fonts = vendored_fonts()?;
+    }
+
+    for path in fonts {
+        let data = std::fs::read(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let (stripped, removed) =
+            strip::strip(&data).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        println!(
+            "{}: {} -> {} bytes, removed [{}]",
+            path.display(),
+            data.len(),
+            stripped.len(),
+            removed.join(", ")
+        );
+
+        if !dry_run {
+            std::fs::write(&path, stripped).map_err(|e| format!("{}: {e}", path.display()))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn fetch_fonts(args: &[String]) -> Result<(), String> {
+    let pin = args.iter().any(|arg| arg == "--pin");
+    let fonts: Vec<&String> = args.iter().filter(|arg| !arg.starts_with("--")).collect();
+
+    let sources_path = workspace_root().join("fonts/sources.txt");
+    let mut contents = std::fs::read_to_string(&sources_path)
+        .map_err(|e| format!("{}: {e}", sources_path.display()))?;
+    let sources: Vec<fetch::Source> = fetch::sources(&contents)?
+        .into_iter()
+        .filter(|source| fonts.is_empty() || fonts.contains(&&source.font))
+        .collect();
+    if sources.is_empty() {
+        return Err("no font to fetch, see `fonts/sources.txt`".into());
+    }
+
+    for source in &sources {
+        let (font, new_pin) = fetch::fetch(source, pin)?;
+        // Vendored fonts are stripped, like `cargo xtask strip` does.
+        let (stripped, removed) =
+            strip::strip(&font).map_err(|e| format!("{}: {e}", source.font))?;
+        let path = workspace_root().join("fonts").join(&source.font);
+        std::fs::write(&path, &stripped).map_err(|e| format!("{}: {e}", path.display()))?;
+        println!(
+            "{}: {} bytes from {}, {} bytes stripped of [{}]",
+            source.font,
+            font.len(),
+            source.url,
+            stripped.len(),
+            removed.join(", ")
+        );
+
+        if let Some(new_pin) = new_pin {
+            contents = fetch::pin(&contents, &source.font, &new_pin);
+            std::fs::write(&sources_path, &contents)
+                .map_err(|e| format!("{}: {e}", sources_path.display()))?;
+            println!("{}: pinned {new_pin}", source.font);
+        }
+    }
+
+    // The snapshots are what the fonts generate, comparing them shows what an update changed.
+    let snapshots = workspace_root().join("snapshots");
+    let before = changes::snapshots(&snapshots);
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .args(["test", "--features", "full"])
+        .env("ICED_FONTS_SNAPSHOT", "overwrite")
+        .current_dir(workspace_root())
+        .status()
+        .map_err(|e| format!("failed to run `cargo test`: {e}"))?;
+    if !status.success() {
+        return Err(
+            "`cargo test --features full` failed, the `expect` counts in `src/lib.rs` may need \
+             updating"
+                .into(),
+        );
+    }
+
+    let changelog = changes::changelog(&before, &changes::snapshots(&snapshots));
+    if changelog.is_empty() {
+        println!("no icons changed");
+    } else {
+        println!("\n{changelog}");
+        println!("run `cargo xtask changes` to update `changes/` for the release");
+    }
+
+    Ok(())
+}
+
+fn write_changes(args: &[String]) -> Result<(), String> {
+    let mut since = None;
+    let mut fonts = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--since" => since = Some(args.next().ok_or("`--since` needs a revision")?.clone()),
+            _ => fonts.push(arg.clone()),
+        }
+    }
+
+    // The changes since the last release are the ones people upgrading get.
+    let since = match since {
+        Some(since) => since,
+        None => git(&["describe", "--tags", "--abbrev=0"]).unwrap_or_else(|_| "HEAD".into()),
+    };
+
+    let after: BTreeMap<String, changes::Icons> =
+        changes::snapshots(&workspace_root().join("snapshots"))
+            .into_iter()
+            .filter(|(font, _)| fonts.is_empty() || fonts.contains(font))
+            .collect();
+    if after.is_empty() {
+        return Err("no snapshot to compare, see `snapshots/`".into());
+    }
+
+    // Fonts without a snapshot at `since` are new, all of their icons are added.
+    let before: BTreeMap<String, changes::Icons> = after
+        .keys()
+        .map(|font| {
+            let snapshot = git(&["show", &format!("{since}:snapshots/{font}.txt")]);
+            (font.clone(), changes::parse(&snapshot.unwrap_or_default()))
+        })
+        .collect();
+
+    let dir = workspace_root().join("changes");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    for (font, icons) in &after {
+        let path = dir.join(format!("{font}.txt"));
+        let contents = changes::file(&changes::diff(&before[font], icons));
+        std::fs::write(&path, contents).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+
+    let changelog = changes::changelog(&before, &after);
+    if changelog.is_empty() {
+        println!("no icons changed since `{since}`");
+    } else {
+        println!("{changelog}");
+    }
+
+    Ok(())
+}
+
+/// The output of `git` with `args` in the workspace.
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(workspace_root())
+        .output()
+        .map_err(|e| format!("failed to run `git`: {e}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn vendored_fonts() -> Result<Vec<PathBuf>, String> {
+    let dir = workspace_root().join("fonts");
+    let mut fonts: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .map_err(|e| format!("{}: {e}", dir.display()))?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "ttf" || extension == "otf")
+        })
+        .collect();
+
+    fonts.sort();
+    Ok(fonts)
+}
@@ -0,0 +1,133 @@
+//! Just enough of the sfnt container format (the wrapper around TrueType and OpenType fonts) to
+//! take a font apart into its tables and put it back together.
+
+/// A single table of a font, e.g. `cmap` or `glyf`.
+pub struct Table {
+    pub tag: [u8; 4],
+    pub data: Vec<u8>,
+}
+
+/// A font split into its tables.
+pub struct Font {
+    sfnt_version: u32,
+    pub tables: Vec<Table>,
+}
+
+impl Font {
+    pub fn parse(data: &[u8]) -> Result<Self, String> {
+        let sfnt_version = read_u32(data, 0).ok_or("font is too short")?;
+        if sfnt_version == u32::from_be_bytes(*b"ttcf") {
+            return Err("font collections are not supported".into());
+        }
+
+        let num_tables = read_u16(data, 4).ok_or("font is too short")?;
+        let tables = (0..usize::from(num_tables))
+            .map(|i| {
+                let record = 12 + i * 16;
+                let tag = data
+                    .get(record..record + 4)
+                    .ok_or("table directory is truncated")?;
+                let offset = read_u32(data, record + 8).ok_or("table directory is truncated")?;
+                let length = read_u32(data, record + 12).ok_or("table directory is truncated")?;
+
+                let start = offset as usize;
+                let data = data
+                    .get(start..start + length as usize)
+                    .ok_or_else(|| format!("table {} is out of bounds", tag_name(tag)))?;
+
+                Ok(Table {
+                    tag: tag.try_into().unwrap(),
+                    data: data.to_vec(),
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self {
+            sfnt_version,
+            tables,
+        })
+    }
+
+    pub fn table_mut(&mut self, tag: &[u8; 4]) -> Option<&mut Table> {
+        self.tables.iter_mut().find(|table| &table.tag == tag)
+    }
+
+    /// Writes the font back out, recomputing offsets and checksums.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut tables: Vec<&Table> = self.tables.iter().collect();
+        tables.sort_by_key(|table| table.tag);
+
+        let num_tables = tables.len() as u16;
+        let entry_selector = num_tables.max(1).ilog2() as u16;
+        let search_range = (1 << entry_selector) * 16;
+        let range_shift = num_tables * 16 - search_range;
+
+        let mut out = Vec::new();
+        out.extend(self.sfnt_version.to_be_bytes());
+        out.extend(num_tables.to_be_bytes());
+        out.extend(search_range.to_be_bytes());
+        out.extend(entry_selector.to_be_bytes());
+        out.extend(range_shift.to_be_bytes());
+
+        let mut offset = 12 + tables.len() * 16;
+        let mut head_offset = None;
+        for table in &tables {
+            let mut data = table.data.clone();
+            if &table.tag == b"head" {
+                head_offset = Some(offset);
+                // `checkSumAdjustment` has to be zero while computing checksums.
+                data[8..12].fill(0);
+            }
+
+            out.extend(table.tag);
+            out.extend(checksum(&data).to_be_bytes());
+            out.extend((offset as u32).to_be_bytes());
+            out.extend((data.len() as u32).to_be_bytes());
+            offset += padded_len(data.len());
+        }
+
+        for table in &tables {
+            let start = out.len();
+            out.extend(&table.data);
+            if &table.tag == b"head" {
+                out[start + 8..start + 12].fill(0);
+            }
+            out.resize(start + padded_len(table.data.len()), 0);
+        }
+
+        if let Some(head_offset) = head_offset {
+            let adjustment = 0xB1B0_AFBA_u32.wrapping_sub(checksum(&out));
+            out[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+        }
+
+        out
+    }
+}
+
+pub fn tag_name(tag: &[u8]) -> String {
+    String::from_utf8_lossy(tag).trim_end().to_string()
+}
+
+pub fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+pub fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn padded_len(len: usize) -> usize {
+    len.div_ceil(4) * 4
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
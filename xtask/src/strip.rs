@@ -0,0 +1,259 @@
+//! Removes everything from a font that iced doesn't need to render icons by codepoint.
+
+use ttf_parser::Face;
+
+use crate::sfnt::{self, Font, read_u16, read_u32};
+
+/// Tables needed to look up, lay out and draw glyphs, and the `post` table the macros read glyph
+/// names from. Hinting (`fpgm`, `prep`, `cvt `, ...) and layout tables (`GSUB`, `GPOS`, ...) are
+/// dropped, icons are always addressed by their codepoint.
+const KEEP: &[&[u8; 4]] = &[
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", // required
+    b"glyf", b"loca", b"CFF ", b"CFF2", // outlines
+    b"fvar", b"gvar", b"avar", b"HVAR", b"STAT", // variations
+    b"COLR", b"CPAL", b"CBDT", b"CBLC", b"sbix", b"SVG ", // color
+];
+
+/// Strips `data` and returns the new font along with the tags of the removed tables.
+pub fn strip(data: &[u8]) -> Result<(Vec<u8>, Vec<String>), String> {
+    let mut font = Font::parse(data)?;
+
+    let mut removed = Vec::new();
+    font.tables.retain(|table| {
+        let keep = KEEP.contains(&&table.tag);
+        if !keep {
+            removed.push(sfnt::tag_name(&table.tag));
+        }
+        keep
+    });
+
+    if let Some(cmap) = font.table_mut(b"cmap") {
+        cmap.data = strip_cmap(&cmap.data)?;
+    }
+
+    let stripped = font.to_bytes();
+    verify(data, &stripped)?;
+
+    Ok((stripped, removed))
+}
+
+/// Keeps only the Unicode subtables of the `cmap` table. Fonts without any are left alone.
+fn strip_cmap(data: &[u8]) -> Result<Vec<u8>, String> {
+    let truncated = || String::from("cmap table is truncated");
+
+    let num_tables = read_u16(data, 2).ok_or_else(truncated)?;
+    let mut records = Vec::new();
+    for i in 0..usize::from(num_tables) {
+        let record = 4 + i * 8;
+        let platform = read_u16(data, record).ok_or_else(truncated)?;
+        let encoding = read_u16(data, record + 2).ok_or_else(truncated)?;
+        let offset = read_u32(data, record + 4).ok_or_else(truncated)? as usize;
+
+        let is_unicode = platform == 0 || (platform == 3 && matches!(encoding, 1 | 10));
+        records.push((platform, encoding, offset, is_unicode));
+    }
+
+    if !records.iter().any(|(.., is_unicode)| *is_unicode) {
+        return Ok(data.to_vec());
+    }
+    records.retain(|(.., is_unicode)| *is_unicode);
+
+    let header_len = 4 + records.len() * 8;
+    let mut subtables: Vec<u8> = Vec::new();
+    // Several records can point at the same subtable, they keep sharing it.
+    let mut written: Vec<(usize, usize)> = Vec::new();
+    let mut out = Vec::with_capacity(header_len);
+    out.extend(0u16.to_be_bytes());
+    out.extend((records.len() as u16).to_be_bytes());
+
+    for (platform, encoding, offset, _) in records {
+        let new_offset = match written.iter().find(|(old, _)| *old == offset) {
+            Some((_, new)) => *new,
+            None => {
+                let len = subtable_len(data, offset).ok_or_else(truncated)?;
+                let subtable = data.get(offset..offset + len).ok_or_else(truncated)?;
+
+                let new = header_len + subtables.len();
+                subtables.extend(subtable);
+                written.push((offset, new));
+                new
+            }
+        };
+
+        out.extend(platform.to_be_bytes());
+        out.extend(encoding.to_be_bytes());
+        out.extend((new_offset as u32).to_be_bytes());
+    }
+
+    out.extend(subtables);
+    Ok(out)
+}
+
+fn subtable_len(data: &[u8], offset: usize) -> Option<usize> {
+    match read_u16(data, offset)? {
+        0 | 2 | 4 | 6 => read_u16(data, offset + 2).map(usize::from),
+        8 | 10 | 12 | 13 => read_u32(data, offset + 4).map(|len| len as usize),
+        14 => read_u32(data, offset + 2).map(|len| len as usize),
+        _ => None,
+    }
+}
+
+/// Checks that every codepoint of the original font still resolves to the same glyph, with the
+/// same name and advance.
+fn verify(original: &[u8], stripped: &[u8]) -> Result<(), String> {
+    let original = Face::parse(original, 0).map_err(|e| e.to_string())?;
+    let stripped = Face::parse(stripped, 0).map_err(|e| format!("stripped font: {e}"))?;
+
+    if original.number_of_glyphs() != stripped.number_of_glyphs() {
+        return Err("stripped font has a different number of glyphs".into());
+    }
+
+    let mut codepoints = Vec::new();
+    for subtable in original
+        .tables()
+        .cmap
+        .into_iter()
+        .flat_map(|cmap| cmap.subtables)
+    {
+        if subtable.is_unicode() {
+            subtable.codepoints(|c| codepoints.push(c));
+        }
+    }
+
+    for c in codepoints.into_iter().filter_map(char::from_u32) {
+        let glyph = original.glyph_index(c);
+        if glyph != stripped.glyph_index(c) {
+            return Err(format!("U+{:04X} maps to a different glyph", u32::from(c)));
+        }
+
+        let Some(glyph) = glyph else { continue };
+        if original.glyph_name(glyph) != stripped.glyph_name(glyph)
+            || original.glyph_hor_advance(glyph) != stripped.glyph_hor_advance(glyph)
+            || original.glyph_bounding_box(glyph) != stripped.glyph_bounding_box(glyph)
+        {
+            return Err(format!("U+{:04X} changed its glyph", u32::from(c)));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ttf_parser::{Face, cmap};
+
+    use super::{KEEP, strip, strip_cmap};
+    use crate::sfnt::{Font, tag_name};
+    use crate::{changes, workspace_root};
+
+    /// The bundled fonts with the snapshots of the icons generated from them.
+    const FONTS: &[(&str, &str)] = &[
+        ("bootstrap.ttf", "bootstrap"),
+        ("codicon.ttf", "codicon"),
+        ("devicons.otf", "devicon"),
+        ("FontAwesome.otf", "fontawesome"),
+        ("lucide.ttf", "lucide"),
+        ("nerd.ttf", "nerd"),
+        ("octicons.otf", "octicons"),
+        ("pomicons.otf", "pomicons"),
+    ];
+
+    /// Every bundled font with the codepoints the generated code references.
+    fn fonts() -> Vec<(&'static str, Vec<u8>, Vec<char>)> {
+        let snapshots = changes::snapshots(&workspace_root().join("snapshots"));
+
+        FONTS
+            .iter()
+            .map(|(file, snapshot)| {
+                let data = std::fs::read(workspace_root().join("fonts").join(file)).unwrap();
+                let codepoints: Vec<char> = snapshots[*snapshot]
+                    .keys()
+                    .map(|codepoint| {
+                        u32::from_str_radix(codepoint, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .unwrap()
+                    })
+                    .collect();
+                assert!(!codepoints.is_empty(), "{snapshot} has no icons");
+
+                (*file, data, codepoints)
+            })
+            .collect()
+    }
+
+    fn glyph_index(cmap: &cmap::Table<'_>, c: char) -> Option<ttf_parser::GlyphId> {
+        cmap.subtables
+            .into_iter()
+            .filter(cmap::Subtable::is_unicode)
+            .find_map(|subtable| subtable.glyph_index(u32::from(c)))
+    }
+
+    #[test]
+    fn stripped_cmap_keeps_every_icon() {
+        for (file, data, codepoints) in fonts() {
+            let mut font = Font::parse(&data).unwrap();
+            let original = font.table_mut(b"cmap").unwrap().data.clone();
+            let stripped = strip_cmap(&original).unwrap();
+
+            let original = cmap::Table::parse(&original).unwrap();
+            let stripped = cmap::Table::parse(&stripped).unwrap();
+            assert!(
+                stripped
+                    .subtables
+                    .into_iter()
+                    .all(|subtable| subtable.is_unicode()),
+                "{file} kept a subtable that isn't Unicode"
+            );
+
+            for c in codepoints {
+                let glyph = glyph_index(&stripped, c);
+                assert!(glyph.is_some(), "{file} lost U+{:04X}", u32::from(c));
+                assert_eq!(glyph, glyph_index(&original, c), "{file} remapped {c:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn stripped_fonts_keep_every_icon() {
+        for (file, data, codepoints) in fonts() {
+            let (stripped, _) = strip(&data).unwrap();
+
+            // Exactly the tables of `KEEP` are left.
+            let kept: Vec<[u8; 4]> = Font::parse(&stripped)
+                .unwrap()
+                .tables
+                .iter()
+                .map(|table| table.tag)
+                .collect();
+            for table in Font::parse(&data).unwrap().tables {
+                assert_eq!(
+                    kept.contains(&table.tag),
+                    KEEP.contains(&&table.tag),
+                    "{file}: {}",
+                    tag_name(&table.tag)
+                );
+            }
+
+            let original = Face::parse(&data, 0).unwrap();
+            let stripped = Face::parse(&stripped, 0).unwrap();
+            for c in codepoints {
+                let glyph = stripped.glyph_index(c);
+                assert!(glyph.is_some(), "{file} lost U+{:04X}", u32::from(c));
+                assert_eq!(glyph, original.glyph_index(c), "{file} remapped {c:?}");
+
+                let glyph = glyph.unwrap();
+                assert_eq!(
+                    stripped.glyph_bounding_box(glyph),
+                    original.glyph_bounding_box(glyph),
+                    "{file} changed the outline of {c:?}"
+                );
+                assert_eq!(
+                    stripped.glyph_hor_advance(glyph),
+                    original.glyph_hor_advance(glyph),
+                    "{file} changed the advance of {c:?}"
+                );
+            }
+        }
+    }
+}